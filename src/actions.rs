@@ -2,6 +2,9 @@
 
 //! This module will contain the data structures for applicant actions.
 
+use crate::error::SumsubError;
+use crate::ids::{ActionId, ApplicantId, ImageId};
+use crate::query::Query;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -27,12 +30,31 @@ pub struct PaymentSource {
     pub fixed_info: PaymentSourceFixedInfo,
 }
 
+/// The type of a payment source. Defaults to `Card`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum PaymentSourceType {
+    /// A bank card; the account identifier is the masked card number.
+    #[default]
+    Card,
+    /// A bank account; the account identifier is an IBAN or account number.
+    Bank,
+    /// An e-wallet such as PayPal; the account identifier is the wallet ID or email.
+    #[serde(rename = "ewallet")]
+    EWallet,
+    /// A crypto wallet; the account identifier is the wallet address.
+    Crypto,
+    /// A payment source type not known to this crate.
+    #[serde(other)]
+    Unknown,
+}
+
 /// Represents the fixed info for a payment source.
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct PaymentSourceFixedInfo {
     #[serde(rename = "type")]
-    pub payment_type: String,
+    pub payment_type: PaymentSourceType,
     pub institution_name: String,
     pub full_name: String,
     pub account_identifier: String,
@@ -40,6 +62,74 @@ pub struct PaymentSourceFixedInfo {
     pub email: Option<String>,
 }
 
+impl PaymentSourceFixedInfo {
+    /// Checks that `account_identifier` has a valid format for `payment_type`:
+    ///
+    /// * `Card`: a masked card number of 12 to 19 digits or `*`, ending in 4 digits.
+    /// * `Bank`: an IBAN with a valid checksum, or an account number of 4 to 34
+    ///   letters and digits.
+    /// * `EWallet`: a non-empty identifier without whitespace.
+    /// * `Crypto`: an address of 26 to 128 letters and digits.
+    /// * `Unknown`: a non-empty identifier.
+    ///
+    /// Spaces are ignored in card numbers and IBANs.
+    pub fn validate(&self) -> Result<(), SumsubError> {
+        let id = self.account_identifier.trim();
+        let compact: String = id.chars().filter(|c| *c != ' ').collect();
+        let valid = match self.payment_type {
+            PaymentSourceType::Card => {
+                (12..=19).contains(&compact.len())
+                    && compact.chars().all(|c| c.is_ascii_digit() || c == '*')
+                    && compact.chars().rev().take(4).all(|c| c.is_ascii_digit())
+            }
+            PaymentSourceType::Bank => {
+                if compact.get(..2).is_some_and(|p| p.bytes().all(|b| b.is_ascii_alphabetic())) {
+                    is_valid_iban(&compact)
+                } else {
+                    (4..=34).contains(&compact.len()) && compact.chars().all(|c| c.is_ascii_alphanumeric())
+                }
+            }
+            PaymentSourceType::EWallet => !id.is_empty() && !id.chars().any(char::is_whitespace),
+            PaymentSourceType::Crypto => {
+                (26..=128).contains(&id.len()) && id.chars().all(|c| c.is_ascii_alphanumeric())
+            }
+            PaymentSourceType::Unknown => !id.is_empty(),
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(SumsubError::Validation(format!(
+                "invalid account identifier for payment source type {:?}",
+                self.payment_type
+            )))
+        }
+    }
+}
+
+/// Checks the format and the mod-97 checksum of an IBAN without spaces.
+fn is_valid_iban(iban: &str) -> bool {
+    if !(15..=34).contains(&iban.len())
+        || !iban.chars().all(|c| c.is_ascii_alphanumeric())
+        || !iban[2..4].chars().all(|c| c.is_ascii_digit())
+    {
+        return false;
+    }
+    let rearranged = iban[4..].chars().chain(iban[..4].chars());
+    let mut remainder = 0u32;
+    for c in rearranged {
+        let value = match c.to_digit(36) {
+            Some(value) => value,
+            None => return false,
+        };
+        remainder = if value < 10 {
+            (remainder * 10 + value) % 97
+        } else {
+            (remainder * 100 + value) % 97
+        };
+    }
+    remainder == 1
+}
+
 /// Represents a questionnaire for an applicant action.
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
@@ -69,11 +159,11 @@ pub struct Item {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplicantAction {
-    pub id: String,
+    pub id: ActionId,
     pub created_at: String,
     pub client_id: String,
     pub external_action_id: String,
-    pub applicant_id: String,
+    pub applicant_id: ApplicantId,
     #[serde(rename = "type")]
     pub action_type: String,
     pub review: ActionReview,
@@ -81,6 +171,9 @@ pub struct ApplicantAction {
     pub checks: Option<Vec<Check>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub required_id_docs: Option<RequiredIdDocs>,
+    /// The questionnaires submitted for the action.
+    #[serde(default)]
+    pub questionnaires: Vec<Questionnaire>,
 }
 
 /// Represents a check performed within an action.
@@ -137,11 +230,11 @@ pub struct ReviewResult {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct RequestActionCheckResponse {
-    pub id: String,
+    pub id: ActionId,
     pub created_at: String,
     pub client_id: String,
     pub external_action_id: String,
-    pub applicant_id: String,
+    pub applicant_id: ApplicantId,
     pub review: ActionReview,
 }
 
@@ -153,6 +246,27 @@ pub struct GetApplicantActionsResponse {
     pub total_items: u32,
 }
 
+/// Filters for listing applicant actions.
+#[derive(Debug, Clone, Default)]
+pub struct ActionFilter {
+    /// Only include actions with this review status (e.g., `pending`, `completed`).
+    pub review_status: Option<String>,
+    /// Only include actions created at or after this time (`YYYY-MM-DD HH:MM:SS`).
+    pub created_from: Option<String>,
+    /// Only include actions created before this time (`YYYY-MM-DD HH:MM:SS`).
+    pub created_to: Option<String>,
+}
+
+impl ActionFilter {
+    /// Appends the filter parameters to `query`.
+    pub(crate) fn apply(&self, query: Query) -> Query {
+        query
+            .opt_param("reviewStatus", self.review_status.as_ref())
+            .opt_param("createdAtFrom", self.created_from.as_ref())
+            .opt_param("createdAtTo", self.created_to.as_ref())
+    }
+}
+
 #[derive(Serialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct AddActionImageMetadata<'a> {
@@ -173,7 +287,7 @@ pub struct AddActionImageMetadata<'a> {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ActionImage {
-    pub image_id: String,
+    pub image_id: ImageId,
     pub added_at: String,
     pub id_doc_type: String,
     pub id_doc_sub_type: String,
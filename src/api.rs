@@ -0,0 +1,2826 @@
+// src/api.rs
+
+//! This module contains the `SumsubApi` trait, which abstracts the `Client`
+//! so that services can be unit tested without an HTTP server.
+
+use crate::actions::*;
+use crate::applicants::*;
+use crate::checks::*;
+use crate::client::Client;
+use crate::error::SumsubError;
+use crate::ids::{ActionId, ApplicantId, ExternalUserId, ImageId, TxnId};
+use crate::kyb::*;
+use crate::misc::*;
+use crate::models::*;
+use crate::transactions::*;
+use crate::travel_rule::*;
+use async_trait::async_trait;
+
+/// The operations of the Sumsub API, implemented by [`Client`].
+///
+/// Depend on this trait (e.g., as `Arc<dyn SumsubApi>`) instead of `Client`
+/// to substitute a fake in tests. With the `mockall` feature, a
+/// `MockSumsubApi` is generated with [`mockall`](https://docs.rs/mockall).
+///
+/// The trait covers every method of `Client` that calls the API, except:
+///
+/// * [`Client::batch`], which is generic over the operation; call the
+///   trait methods from your own loop instead.
+/// * [`Client::applicant_actions_stream`]; page with
+///   [`SumsubApi::get_applicant_actions_page`] instead.
+/// * The methods of the `raw-api` feature and the deprecated
+///   [`Client::get_non_doc_data`], which take or return untyped requests
+///   and JSON.
+///
+/// Methods taking `impl AsRef<Path>` on `Client` take a `&Path` here.
+///
+/// # Example
+///
+/// ```no_run
+/// use sumsub_api::api::SumsubApi;
+/// use sumsub_api::error::SumsubError;
+/// use sumsub_api::ids::ApplicantId;
+///
+/// async fn is_verified(api: &dyn SumsubApi, applicant_id: &ApplicantId) -> Result<bool, SumsubError> {
+///     Ok(api.get_applicant_status(applicant_id).await?.is_approved())
+/// }
+/// ```
+#[cfg_attr(feature = "mockall", mockall::automock)]
+#[async_trait]
+pub trait SumsubApi: Send + Sync {
+    /// Creates a new applicant.
+    ///
+    /// See [`Client::create_applicant`].
+    async fn create_applicant(
+        &self,
+        request: CreateApplicantRequest,
+        level_name: &str,
+    ) -> Result<Applicant, SumsubError>;
+
+    /// Creates a new applicant, or returns the existing applicant if one with
+    /// the same `externalUserId` already exists.
+    ///
+    /// See [`Client::create_or_get_applicant`].
+    async fn create_or_get_applicant(
+        &self,
+        request: CreateApplicantRequest,
+        level_name: &str,
+    ) -> Result<Applicant, SumsubError>;
+
+    /// Gets applicant data.
+    ///
+    /// See [`Client::get_applicant_data`].
+    async fn get_applicant_data(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Applicant, SumsubError>;
+
+    /// Gets applicant data together with the diagnostics of the response.
+    ///
+    /// See [`Client::get_applicant_data_with_diagnostics`].
+    async fn get_applicant_data_with_diagnostics(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::response::ApiResponse<Applicant>, SumsubError>;
+
+    /// Gets all applicants created or changed since `since`
+    /// (`YYYY-MM-DD HH:MM:SS`), following pages until the last one.
+    ///
+    /// See [`Client::sync_applicants_since`].
+    async fn sync_applicants_since(
+        &self,
+        since: &str,
+    ) -> Result<crate::sync::SyncBatch<Applicant>, SumsubError>;
+
+    /// Retrieves the latest TIN check result for an applicant.
+    ///
+    /// See [`Client::get_latest_tin_check_result`].
+    async fn get_latest_tin_check_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<TinCheckResult, SumsubError>;
+
+    /// Retrieves the latest similar search result for an applicant.
+    ///
+    /// See [`Client::get_latest_similar_search_result`].
+    async fn get_latest_similar_search_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<SimilarSearchResult, SumsubError>;
+
+    /// Retrieves the latest PoA check result for an applicant.
+    ///
+    /// See [`Client::get_latest_poa_check_result`].
+    async fn get_latest_poa_check_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<PoaCheckResult, SumsubError>;
+
+    /// Retrieves the latest bank card check result for an applicant.
+    ///
+    /// See [`Client::get_latest_bank_card_check_result`].
+    async fn get_latest_bank_card_check_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<BankCardCheckResult, SumsubError>;
+
+    /// Retrieves the latest email confirmation check result for an applicant.
+    ///
+    /// See [`Client::get_latest_email_confirmation_check_result`].
+    async fn get_latest_email_confirmation_check_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<EmailConfirmationCheckResult, SumsubError>;
+
+    /// Retrieves the latest phone confirmation check result for an applicant.
+    ///
+    /// See [`Client::get_latest_phone_confirmation_check_result`].
+    async fn get_latest_phone_confirmation_check_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<PhoneConfirmationCheckResult, SumsubError>;
+
+    /// Retrieves the latest IP check result for an applicant.
+    ///
+    /// See [`Client::get_latest_ip_check_result`].
+    async fn get_latest_ip_check_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<IpCheckResult, SumsubError>;
+
+    /// Retrieves the latest NFC check result for an applicant.
+    ///
+    /// See [`Client::get_latest_nfc_check_result`].
+    async fn get_latest_nfc_check_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<NfcCheckResult, SumsubError>;
+
+    /// Retrieves the latest face match result for an applicant, including the
+    /// similarity score between the selfie and the document photo.
+    ///
+    /// See [`Client::get_latest_face_match_result`].
+    async fn get_latest_face_match_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<FaceMatchCheckResult, SumsubError>;
+
+    /// Retrieves the latest liveness check result for an applicant, including
+    /// the liveness score and detected spoofing attempts.
+    ///
+    /// See [`Client::get_latest_liveness_result`].
+    async fn get_latest_liveness_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<LivenessCheckResult, SumsubError>;
+
+    /// Gets audit trail events.
+    ///
+    /// See [`Client::get_audit_trail_events`].
+    async fn get_audit_trail_events(&self) -> Result<Vec<AuditTrailEvent>, SumsubError>;
+
+    /// Gets a single page of audit trail events.
+    ///
+    /// See [`Client::get_audit_trail_events_page`].
+    async fn get_audit_trail_events_page(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<AuditTrailEvent>, SumsubError>;
+
+    /// Lists the members of the team (agents), e.g., to attribute review
+    /// decisions and notes to specific reviewers in audit reports.
+    ///
+    /// See [`Client::get_team_members`].
+    async fn get_team_members(&self) -> Result<Vec<crate::misc::TeamMember>, SumsubError>;
+
+    /// Gets the API health status.
+    ///
+    /// See [`Client::get_api_health_status`].
+    async fn get_api_health_status(&self) -> Result<ApiHealthStatus, SumsubError>;
+
+    /// Creates a new applicant action.
+    ///
+    /// See [`Client::create_applicant_action`].
+    async fn create_applicant_action(
+        &self,
+        applicant_id: &ApplicantId,
+        level_name: &str,
+        request: CreateApplicantActionRequest,
+    ) -> Result<ApplicantAction, SumsubError>;
+
+    /// Requests a check for an applicant action.
+    ///
+    /// See [`Client::request_action_check`].
+    async fn request_action_check(
+        &self,
+        action_id: &ActionId,
+    ) -> Result<RequestActionCheckResponse, SumsubError>;
+
+    /// Gets a list of applicant actions.
+    ///
+    /// See [`Client::get_applicant_actions`].
+    async fn get_applicant_actions(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<GetApplicantActionsResponse, SumsubError>;
+
+    /// Gets a single page of actions for an applicant, matching `filter`.
+    ///
+    /// See [`Client::get_applicant_actions_page`].
+    async fn get_applicant_actions_page(
+        &self,
+        applicant_id: &ApplicantId,
+        filter: &crate::actions::ActionFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Result<GetApplicantActionsResponse, SumsubError>;
+
+    /// Gets information about a specific applicant action.
+    ///
+    /// See [`Client::get_action_information`].
+    async fn get_action_information(
+        &self,
+        action_id: &ActionId,
+    ) -> Result<ApplicantAction, SumsubError>;
+
+    /// Gets an applicant action by the `externalActionId` it was created with.
+    ///
+    /// See [`Client::get_action_by_external_id`].
+    async fn get_action_by_external_id(
+        &self,
+        external_action_id: &str,
+    ) -> Result<ApplicantAction, SumsubError>;
+
+    /// Gets the questionnaires submitted for an applicant action.
+    ///
+    /// See [`Client::get_applicant_action_questionnaires`].
+    async fn get_applicant_action_questionnaires(
+        &self,
+        action_id: &ActionId,
+    ) -> Result<Vec<Questionnaire>, SumsubError>;
+
+    /// Adds a questionnaire to an applicant action.
+    ///
+    /// See [`Client::add_applicant_action_questionnaire`].
+    async fn add_applicant_action_questionnaire(
+        &self,
+        action_id: &ActionId,
+        questionnaire: Questionnaire,
+    ) -> Result<Questionnaire, SumsubError>;
+
+    /// Links a beneficiary to a company.
+    ///
+    /// See [`Client::link_beneficiary`].
+    async fn link_beneficiary(
+        &self,
+        applicant_id: &ApplicantId,
+        request: LinkBeneficiaryRequest,
+    ) -> Result<(), SumsubError>;
+
+    /// Unlinks a beneficiary from a company.
+    ///
+    /// See [`Client::unlink_beneficiary`].
+    async fn unlink_beneficiary(
+        &self,
+        applicant_id: &ApplicantId,
+        beneficiary_id: &str,
+    ) -> Result<(), SumsubError>;
+
+    /// Gets the beneficiaries linked to a company applicant.
+    ///
+    /// See [`Client::get_company_beneficiaries`].
+    async fn get_company_beneficiaries(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<crate::kyb::LinkedBeneficiary>, SumsubError>;
+
+    /// Gets the verification state of every beneficiary linked to a company
+    /// applicant.
+    ///
+    /// See [`Client::get_beneficiary_readiness`].
+    async fn get_beneficiary_readiness(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::kyb::BeneficiaryReadinessReport, SumsubError>;
+
+    /// Changes the extracted company data.
+    ///
+    /// See [`Client::change_extracted_company_data`].
+    async fn change_extracted_company_data(
+        &self,
+        applicant_id: &ApplicantId,
+        company_info: CompanyInfo,
+    ) -> Result<CompanyInfo, SumsubError>;
+
+    /// Changes the provided company data.
+    ///
+    /// See [`Client::change_provided_company_data`].
+    async fn change_provided_company_data(
+        &self,
+        applicant_id: &ApplicantId,
+        fixed_info: FixedInfo,
+    ) -> Result<(), SumsubError>;
+
+    /// Gets additional company check data.
+    ///
+    /// See [`Client::get_additional_company_check_data`].
+    async fn get_additional_company_check_data(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<GetAdditionalCompanyCheckDataResponse, SumsubError>;
+
+    /// Starts AML screening for every officer and significant person found
+    /// by the latest company check of a company applicant, for enhanced due
+    /// diligence.
+    ///
+    /// See [`Client::screen_company_persons`].
+    async fn screen_company_persons(
+        &self,
+        applicant_id: &ApplicantId,
+        level_name: &str,
+    ) -> Result<Vec<crate::kyb::PersonScreening>, SumsubError>;
+
+    /// Collects the AML hits of persons screened by
+    /// [`Client::screen_company_persons`]. Call this once their AML checks
+    /// have completed; until then, the hits may be missing or stale.
+    ///
+    /// See [`Client::get_person_screening_report`].
+    async fn get_person_screening_report(
+        &self,
+        screenings: &[crate::kyb::PersonScreening],
+    ) -> Result<crate::kyb::PersonScreeningReport, SumsubError>;
+
+    /// Searches public company registries, e.g., to autocomplete legal entity
+    /// data in an onboarding form before creating a company applicant.
+    ///
+    /// See [`Client::search_company_registry`].
+    async fn search_company_registry(
+        &self,
+        query: &crate::kyb::CompanySearchQuery,
+    ) -> Result<Vec<crate::kyb::CompanySearchResult>, SumsubError>;
+
+    /// Submits a transaction for an existing applicant.
+    ///
+    /// See [`Client::submit_transaction`].
+    async fn submit_transaction(
+        &self,
+        applicant_id: &ApplicantId,
+        request: SubmitTransactionRequest,
+    ) -> Result<SubmitTransactionResponse, SumsubError>;
+
+    /// Submits a transaction for a non-existing applicant.
+    ///
+    /// See [`Client::submit_transaction_for_non_existing_applicant`].
+    async fn submit_transaction_for_non_existing_applicant(
+        &self,
+        request: SubmitTransactionRequest,
+    ) -> Result<SubmitTransactionResponse, SumsubError>;
+
+    /// Deletes a transaction.
+    ///
+    /// See [`Client::delete_transaction`].
+    async fn delete_transaction(
+        &self,
+        txn_id: &TxnId,
+    ) -> Result<DeleteTransactionResponse, SumsubError>;
+
+    /// Imports transactions in bulk.
+    ///
+    /// See [`Client::bulk_transaction_import`].
+    async fn bulk_transaction_import(
+        &self,
+        requests: Vec<BulkTransactionImportRequest>,
+    ) -> Result<BulkTransactionImportResponse, SumsubError>;
+
+    /// Imports transactions in bulk, keeping the warnings returned for
+    /// partially imported batches.
+    ///
+    /// See [`Client::bulk_transaction_import_with_diagnostics`].
+    async fn bulk_transaction_import_with_diagnostics(
+        &self,
+        requests: Vec<BulkTransactionImportRequest>,
+    ) -> Result<crate::response::ApiResponse<BulkTransactionImportResponse>, SumsubError>;
+
+    /// Initiates the SDK for a Travel Rule transaction.
+    ///
+    /// See [`Client::initiate_sdk_for_travel_rule_transaction`].
+    async fn initiate_sdk_for_travel_rule_transaction(
+        &self,
+        request: InitiateSdkRequest,
+    ) -> Result<InitiateSdkResponse, SumsubError>;
+
+    /// Patches a transaction with a chain transaction ID.
+    ///
+    /// See [`Client::patch_transaction_with_chain_transaction_id`].
+    async fn patch_transaction_with_chain_transaction_id(
+        &self,
+        txn_id: &TxnId,
+        request: PatchTransactionRequest,
+    ) -> Result<SubmitTransactionResponse, SumsubError>;
+
+    /// Confirms or rejects ownership of a transaction.
+    ///
+    /// See [`Client::confirm_or_reject_transaction_ownership`].
+    async fn confirm_or_reject_transaction_ownership(
+        &self,
+        txn_id: &TxnId,
+        status: OwnershipStatus,
+    ) -> Result<SubmitTransactionResponse, SumsubError>;
+
+    /// Confirms wallet ownership.
+    ///
+    /// See [`Client::confirm_wallet_ownership`].
+    async fn confirm_wallet_ownership(
+        &self,
+        txn_id: &TxnId,
+        request: ConfirmWalletOwnershipRequest,
+    ) -> Result<SubmitTransactionResponse, SumsubError>;
+
+    /// Imports wallet addresses in bulk.
+    ///
+    /// See [`Client::import_wallet_addresses`].
+    async fn import_wallet_addresses(
+        &self,
+        requests: Vec<ImportWalletAddressesRequest>,
+    ) -> Result<ImportWalletAddressesResponse, SumsubError>;
+
+    /// Like [`Client::import_wallet_addresses`], but first checks the format
+    /// of every address (see [`crate::travel_rule::ImportWalletAddressesRequest::validate`]).
+    ///
+    /// See [`Client::import_wallet_addresses_checked`].
+    async fn import_wallet_addresses_checked(
+        &self,
+        requests: Vec<ImportWalletAddressesRequest>,
+    ) -> Result<ImportWalletAddressesResponse, SumsubError>;
+
+    /// Gets transaction data.
+    ///
+    /// See [`Client::get_transaction_data`].
+    async fn get_transaction_data(
+        &self,
+        txn_id: &TxnId,
+    ) -> Result<SubmitTransactionResponse, SumsubError>;
+
+    /// Gets all transactions for an applicant.
+    ///
+    /// See [`Client::get_all_transactions_for_applicant`].
+    async fn get_all_transactions_for_applicant(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<SubmitTransactionResponse>, SumsubError>;
+
+    /// Gets the aggregated transaction monitoring state of an applicant: the
+    /// current risk score, the transactions on hold, and the triggered rules.
+    ///
+    /// See [`Client::get_applicant_kyt_summary`].
+    async fn get_applicant_kyt_summary(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::transactions::KytApplicantSummary, SumsubError>;
+
+    /// Gets all transactions created or changed since `since`
+    /// (`YYYY-MM-DD HH:MM:SS`), following pages until the last one.
+    ///
+    /// See [`Client::sync_transactions_since`].
+    async fn sync_transactions_since(
+        &self,
+        since: &str,
+    ) -> Result<crate::sync::SyncBatch<SubmitTransactionResponse>, SumsubError>;
+
+    /// Sets the block status for a transaction.
+    ///
+    /// See [`Client::set_transaction_block`].
+    async fn set_transaction_block(
+        &self,
+        txn_id: &TxnId,
+        request: SetTransactionBlockRequest,
+    ) -> Result<SubmitTransactionResponse, SumsubError>;
+
+    /// Moves an applicant to a different verification level.
+    ///
+    /// See [`Client::move_applicant_to_level`].
+    async fn move_applicant_to_level(
+        &self,
+        applicant_id: &ApplicantId,
+        level_name: &str,
+    ) -> Result<(), SumsubError>;
+
+    /// Updates fixed information for an applicant.
+    ///
+    /// See [`Client::update_applicant_fixed_info`].
+    async fn update_applicant_fixed_info(
+        &self,
+        applicant_id: &ApplicantId,
+        fixed_info: FixedInfo,
+    ) -> Result<(), SumsubError>;
+
+    /// Updates the fixed information of an applicant to match `fixed_info`,
+    /// sending only the fields that changed.
+    ///
+    /// See [`Client::ensure_fixed_info`].
+    async fn ensure_fixed_info(
+        &self,
+        applicant_id: &ApplicantId,
+        fixed_info: FixedInfo,
+    ) -> Result<bool, SumsubError>;
+
+    /// Retrieves the review status for an applicant.
+    ///
+    /// See [`Client::get_applicant_status`].
+    async fn get_applicant_status(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<ApplicantStatus, SumsubError>;
+
+    /// Retrieves moderation states for an applicant to clarify rejections.
+    ///
+    /// See [`Client::get_applicant_moderation_states`].
+    async fn get_applicant_moderation_states(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<ModerationState>, SumsubError>;
+
+    /// Retrieves a single page of moderation states for an applicant.
+    ///
+    /// See [`Client::get_applicant_moderation_states_page`].
+    async fn get_applicant_moderation_states_page(
+        &self,
+        applicant_id: &ApplicantId,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<ModerationState>, SumsubError>;
+
+    /// Retrieves all moderation states for an applicant by following pages
+    /// until the last one, sorted by creation time (oldest first).
+    ///
+    /// See [`Client::get_all_applicant_moderation_states`].
+    async fn get_all_applicant_moderation_states(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<ModerationState>, SumsubError>;
+
+    /// Requests a re-check for an applicant, returning the API's
+    /// acknowledgement.
+    ///
+    /// See [`Client::request_applicant_recheck`].
+    async fn request_applicant_recheck(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<OperationResult, SumsubError>;
+
+    /// Adds an applicant to the blocklist, returning the updated applicant.
+    ///
+    /// See [`Client::add_applicant_to_blocklist`].
+    async fn add_applicant_to_blocklist(
+        &self,
+        applicant_id: &ApplicantId,
+        note: String,
+    ) -> Result<Applicant, SumsubError>;
+
+    /// Creates a share token for an applicant.
+    ///
+    /// See [`Client::create_share_token`].
+    async fn create_share_token<'a>(
+        &self,
+        request: ShareTokenRequest<'a>,
+    ) -> Result<ShareTokenResponse, SumsubError>;
+
+    /// Imports a shared applicant.
+    ///
+    /// See [`Client::import_shared_applicant`].
+    async fn import_shared_applicant(
+        &self,
+        token: &str,
+    ) -> Result<ImportApplicantResponse, SumsubError>;
+
+    /// Resets a single verification step for an applicant.
+    ///
+    /// See [`Client::reset_applicant_step`].
+    async fn reset_applicant_step(
+        &self,
+        applicant_id: &ApplicantId,
+        id_doc_set_type: &str,
+    ) -> Result<(), SumsubError>;
+
+    /// Resets an applicant entirely.
+    ///
+    /// See [`Client::reset_applicant`].
+    async fn reset_applicant(&self, applicant_id: &ApplicantId) -> Result<(), SumsubError>;
+
+    /// Ingests a completed applicant profile.
+    ///
+    /// See [`Client::ingest_completed_applicant`].
+    async fn ingest_completed_applicant(
+        &self,
+        request: IngestCompletedRequest,
+    ) -> Result<(), SumsubError>;
+
+    /// Ingests completed applicant profiles in bulk, e.g., when migrating
+    /// historical KYC records.
+    ///
+    /// See [`Client::ingest_completed_applicants`].
+    async fn ingest_completed_applicants(
+        &self,
+        requests: Vec<IngestCompletedRequest>,
+        options: BulkIngestOptions,
+    ) -> Vec<IngestOutcome>;
+
+    /// Updates top-level applicant data (e.g., email, phone).
+    ///
+    /// See [`Client::update_applicant_top_level_data`].
+    async fn update_applicant_top_level_data(
+        &self,
+        applicant_id: &ApplicantId,
+        request: UpdateApplicantRequest,
+    ) -> Result<Applicant, SumsubError>;
+
+    /// Starts a specific check for an applicant, returning the API's
+    /// acknowledgement.
+    ///
+    /// See [`Client::start_check`].
+    async fn start_check<'a>(
+        &self,
+        request: StartCheckRequest<'a>,
+    ) -> Result<OperationResult, SumsubError>;
+
+    /// Generates an external WebSDK link.
+    ///
+    /// See [`Client::generate_external_websdk_link`].
+    async fn generate_external_websdk_link<'a>(
+        &self,
+        level_name: Option<&'a str>,
+        external_user_id: Option<&'a ExternalUserId>,
+        ttl_in_secs: Option<u64>,
+    ) -> Result<GenerateWebsdkLinkResponse, SumsubError>;
+
+    /// Generates an access token for a new applicant for the WebSDK.
+    ///
+    /// See [`Client::generate_token_for_new_applicant`].
+    async fn generate_token_for_new_applicant<'a>(
+        &self,
+        level_name: Option<&'a str>,
+        external_user_id: Option<&'a ExternalUserId>,
+        ttl_in_secs: Option<u64>,
+    ) -> Result<NewApplicantAccessTokenResponse, SumsubError>;
+
+    /// Generates an access token for an existing applicant for the WebSDK.
+    ///
+    /// See [`Client::generate_token_for_existing_applicant`].
+    async fn generate_token_for_existing_applicant(
+        &self,
+        applicant_id: &ApplicantId,
+        level_name: &str,
+    ) -> Result<String, SumsubError>;
+
+    /// Retrieves similar applicants by text and face.
+    ///
+    /// See [`Client::get_similar_applicants_by_text_and_face`].
+    async fn get_similar_applicants_by_text_and_face(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<SimilarByTextAndFaceResult, SumsubError>;
+
+    /// Retrieves applicant events/logs.
+    ///
+    /// See [`Client::get_applicant_events`].
+    async fn get_applicant_events(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<ApplicantEvent>, SumsubError>;
+
+    /// Gets a single page of events for an applicant, matching `filter`.
+    ///
+    /// See [`Client::get_applicant_events_page`].
+    async fn get_applicant_events_page(
+        &self,
+        applicant_id: &ApplicantId,
+        filter: &crate::applicants::EventFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<ApplicantEvent>, SumsubError>;
+
+    /// Gets all events for an applicant matching `filter` by following pages
+    /// until the last one.
+    ///
+    /// See [`Client::get_all_applicant_events`].
+    async fn get_all_applicant_events(
+        &self,
+        applicant_id: &ApplicantId,
+        filter: &crate::applicants::EventFilter,
+    ) -> Result<Vec<ApplicantEvent>, SumsubError>;
+
+    /// Sends a verification email to the applicant.
+    ///
+    /// See [`Client::send_verification_email`].
+    async fn send_verification_email<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        request: SendVerificationMessageRequest<'a>,
+    ) -> Result<(), SumsubError>;
+
+    /// Retrieves the liveness video.
+    ///
+    /// See [`Client::get_liveness_video`].
+    async fn get_liveness_video(&self, applicant_id: &ApplicantId) -> Result<Vec<u8>, SumsubError>;
+
+    /// Streams the liveness video instead of loading it into memory, e.g., to
+    /// pipe it to a file or object storage.
+    ///
+    /// See [`Client::get_liveness_video_stream`].
+    async fn get_liveness_video_stream(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::response::ByteStream, SumsubError>;
+
+    /// Gets a temporary download link for the liveness video instead of its
+    /// bytes, e.g., to let a browser fetch it directly from storage.
+    ///
+    /// See [`Client::get_liveness_video_link`].
+    async fn get_liveness_video_link(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Option<String>, SumsubError>;
+
+    /// Retrieves a PDF report of the verification.
+    ///
+    /// See [`Client::get_verification_pdf_report`].
+    async fn get_verification_pdf_report(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<u8>, SumsubError>;
+
+    /// Streams the PDF report of the verification instead of loading it into
+    /// memory. See [`Client::get_verification_pdf_report`].
+    ///
+    /// See [`Client::get_verification_pdf_report_stream`].
+    async fn get_verification_pdf_report_stream(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::response::ByteStream, SumsubError>;
+
+    /// Retrieves a PDF report of the verification, keeping the URL it was
+    /// finally downloaded from (e.g., a storage URL the API redirected to).
+    ///
+    /// See [`Client::get_verification_pdf_report_with_diagnostics`].
+    async fn get_verification_pdf_report_with_diagnostics(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::response::ApiResponse<Vec<u8>>, SumsubError>;
+
+    /// Collects the applicant data, agreement, level consents, notes, and
+    /// audit trail events of an applicant into a single export, e.g., to
+    /// answer a data subject access request.
+    ///
+    /// See [`Client::export_data_subject_record`].
+    async fn export_data_subject_record(
+        &self,
+        applicant_id: &ApplicantId,
+        max_audit_trail_pages: u32,
+    ) -> Result<crate::export::DataSubjectExport, SumsubError>;
+
+    /// Exports everything Sumsub holds about an applicant into `dest_dir`,
+    /// e.g., for legal hold requests.
+    ///
+    /// See [`Client::export_applicant_bundle`].
+    async fn export_applicant_bundle(
+        &self,
+        applicant_id: &ApplicantId,
+        dest_dir: &std::path::Path,
+    ) -> Result<crate::export::ApplicantBundle, SumsubError>;
+
+    /// Changes applicant data in the `info` field.
+    ///
+    /// See [`Client::change_applicant_data`].
+    async fn change_applicant_data(
+        &self,
+        applicant_id: &ApplicantId,
+        info: crate::models::Info,
+    ) -> Result<crate::models::Applicant, SumsubError>;
+
+    /// Retrieves the list of available verification levels.
+    ///
+    /// See [`Client::get_available_levels`].
+    async fn get_available_levels(&self) -> Result<Vec<AvailableLevel>, SumsubError>;
+
+    /// Sends a verification SMS to the applicant's phone.
+    ///
+    /// See [`Client::send_verification_phone_sms`].
+    async fn send_verification_phone_sms<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        request: SendVerificationMessageRequest<'a>,
+    ) -> Result<(), SumsubError>;
+
+    /// Retrieves a ZIP archive report of the verification.
+    ///
+    /// See [`Client::get_verification_zip_report`].
+    async fn get_verification_zip_report(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<u8>, SumsubError>;
+
+    /// Streams a ZIP archive report of the verification instead of loading
+    /// it into memory. See [`Client::get_verification_zip_report`] and
+    /// `archive::ReportEntries` for reading its entries.
+    ///
+    /// See [`Client::get_verification_zip_report_stream`].
+    async fn get_verification_zip_report_stream(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::response::ByteStream, SumsubError>;
+
+    /// Adds a verification document to an applicant.
+    ///
+    /// See [`Client::add_verification_document`].
+    async fn add_verification_document<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        metadata: crate::applicants::AddDocumentMetadata<'a>,
+        content: Vec<u8>,
+        file_name: &str,
+        mime_type: &str,
+    ) -> Result<(), SumsubError>;
+
+    /// Copies an applicant profile.
+    ///
+    /// See [`Client::copy_applicant_profile`].
+    async fn copy_applicant_profile(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::models::Applicant, SumsubError>;
+
+    /// Simulates a review response in the Sandbox environment.
+    ///
+    /// See [`Client::simulate_review_response`].
+    async fn simulate_review_response<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        request: crate::applicants::SimulateReviewRequest<'a>,
+    ) -> Result<(), SumsubError>;
+
+    /// Simulates the rejection of a single document set in the Sandbox
+    /// environment, e.g., a proof of address, leaving the other steps as
+    /// they are so that the WebSDK asks only for that document set again.
+    ///
+    /// See [`Client::simulate_doc_set_rejection`].
+    async fn simulate_doc_set_rejection<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        doc_set: &crate::applicants::IdDocSetType,
+        reject_labels: &[&'a str],
+    ) -> Result<(), SumsubError>;
+
+    /// Creates a fully-populated applicant in the Sandbox environment and
+    /// drives it to the given outcome.
+    ///
+    /// See [`Client::create_sandbox_applicant`].
+    async fn create_sandbox_applicant(
+        &self,
+        request: CreateApplicantRequest,
+        level_name: &str,
+        outcome: crate::sandbox::SandboxOutcome,
+    ) -> Result<Applicant, SumsubError>;
+
+    /// Runs an AML check for an applicant, returning the API's
+    /// acknowledgement.
+    ///
+    /// See [`Client::run_aml_check`].
+    async fn run_aml_check(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<OperationResult, SumsubError>;
+
+    /// Gets AML case data for an applicant.
+    ///
+    /// See [`Client::get_aml_case_data`].
+    async fn get_aml_case_data(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::applicants::AmlData, SumsubError>;
+
+    /// Downloads the AML screening report for an applicant, e.g., to keep as
+    /// evidence of screening in compliance files.
+    ///
+    /// See [`Client::get_aml_report`].
+    async fn get_aml_report(
+        &self,
+        applicant_id: &ApplicantId,
+        format: crate::applicants::AmlReportFormat,
+    ) -> Result<Vec<u8>, SumsubError>;
+
+    /// Updates the review status of an AML hit.
+    ///
+    /// See [`Client::update_aml_hit_review`].
+    async fn update_aml_hit_review<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        hit_id: &str,
+        request: crate::applicants::UpdateAmlHitReviewRequest<'a>,
+    ) -> Result<(), SumsubError>;
+
+    /// Marks an image as inactive.
+    ///
+    /// See [`Client::mark_image_as_inactive`].
+    async fn mark_image_as_inactive(
+        &self,
+        applicant_id: &ApplicantId,
+        image_id: &ImageId,
+    ) -> Result<(), SumsubError>;
+
+    /// Sets how an image should be displayed (rotation and crop area) without
+    /// modifying the original.
+    ///
+    /// See [`Client::set_image_transform`].
+    async fn set_image_transform(
+        &self,
+        applicant_id: &ApplicantId,
+        image_id: &ImageId,
+        transform: &crate::applicants::ImageTransform,
+    ) -> Result<(), SumsubError>;
+
+    /// Deactivates a category of data (e.g., selfies or proof of address
+    /// documents) of an applicant without deleting the whole profile.
+    ///
+    /// See [`Client::deactivate_applicant_data`].
+    async fn deactivate_applicant_data(
+        &self,
+        applicant_id: &ApplicantId,
+        category: crate::applicants::DataCategory,
+    ) -> Result<Vec<crate::applicants::ImageDeactivation>, SumsubError>;
+
+    /// Marks every image of a document set (e.g., an outdated proof of address)
+    /// as inactive. Returns one outcome per image, like
+    /// [`Client::deactivate_applicant_data`].
+    ///
+    /// See [`Client::deactivate_doc_set`].
+    async fn deactivate_doc_set(
+        &self,
+        applicant_id: &ApplicantId,
+        doc_set: &crate::applicants::IdDocSetType,
+    ) -> Result<Vec<crate::applicants::ImageDeactivation>, SumsubError>;
+
+    /// Resets a verification step so that the applicant is asked to submit
+    /// the document set again.
+    ///
+    /// See [`Client::rerequest_doc_set`].
+    async fn rerequest_doc_set(
+        &self,
+        applicant_id: &ApplicantId,
+        doc_set: &crate::applicants::IdDocSetType,
+    ) -> Result<(), SumsubError>;
+
+    /// Deactivates an applicant profile.
+    ///
+    /// See [`Client::deactivate_applicant_profile`].
+    async fn deactivate_applicant_profile<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        moderation_comment: Option<&'a str>,
+    ) -> Result<(), SumsubError>;
+
+    /// Gets the applicant tags configured in the account.
+    ///
+    /// See [`Client::get_applicant_tag_dictionary`].
+    async fn get_applicant_tag_dictionary(&self) -> Result<Vec<String>, SumsubError>;
+
+    /// Gets the transaction tags configured in the account.
+    ///
+    /// See [`Client::get_transaction_tag_dictionary`].
+    async fn get_transaction_tag_dictionary(&self) -> Result<Vec<String>, SumsubError>;
+
+    /// Adds tags to an applicant.
+    ///
+    /// See [`Client::add_applicant_tags`].
+    async fn add_applicant_tags<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        tags: Vec<&'a str>,
+    ) -> Result<(), SumsubError>;
+
+    /// Adds and overwrites tags for an applicant.
+    ///
+    /// See [`Client::add_and_overwrite_applicant_tags`].
+    async fn add_and_overwrite_applicant_tags<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        tags: Vec<&'a str>,
+    ) -> Result<(), SumsubError>;
+
+    /// Removes tags from an applicant.
+    ///
+    /// See [`Client::remove_applicant_tags`].
+    async fn remove_applicant_tags<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        tags: Vec<&'a str>,
+    ) -> Result<(), SumsubError>;
+
+    /// Adds accepted consents for an applicant.
+    ///
+    /// See [`Client::add_applicant_consents`].
+    async fn add_applicant_consents<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        consents: Vec<&'a str>,
+    ) -> Result<(), SumsubError>;
+
+    /// Gets the applicant-facing consents for a given level.
+    ///
+    /// See [`Client::get_applicant_facing_consents`].
+    async fn get_applicant_facing_consents(
+        &self,
+        level_name: &str,
+    ) -> Result<crate::applicants::ApplicantFacingConsentsResponse, SumsubError>;
+
+    /// Gets the agreements (terms of service, privacy policy versions) configured for a level.
+    ///
+    /// See [`Client::get_level_agreements`].
+    async fn get_level_agreements(
+        &self,
+        level_name: &str,
+    ) -> Result<crate::applicants::LevelAgreementsResponse, SumsubError>;
+
+    /// Gets the agreements accepted by an applicant.
+    ///
+    /// See [`Client::get_applicant_agreement`].
+    async fn get_applicant_agreement(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::applicants::ApplicantAgreement, SumsubError>;
+
+    /// Records the acceptance of agreements by an applicant.
+    ///
+    /// See [`Client::record_applicant_agreement`].
+    async fn record_applicant_agreement<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        request: crate::applicants::RecordAgreementRequest<'a>,
+    ) -> Result<crate::applicants::ApplicantAgreement, SumsubError>;
+
+    /// Gets notes for an applicant.
+    ///
+    /// See [`Client::get_applicant_notes`].
+    async fn get_applicant_notes(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<crate::applicants::Note>, SumsubError>;
+
+    /// Gets a single page of notes for an applicant, matching `filter`.
+    ///
+    /// See [`Client::get_applicant_notes_page`].
+    async fn get_applicant_notes_page(
+        &self,
+        applicant_id: &ApplicantId,
+        filter: &crate::applicants::NoteFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<crate::applicants::Note>, SumsubError>;
+
+    /// Gets all notes for an applicant matching `filter` by following pages
+    /// until the last one.
+    ///
+    /// See [`Client::get_all_applicant_notes`].
+    async fn get_all_applicant_notes(
+        &self,
+        applicant_id: &ApplicantId,
+        filter: &crate::applicants::NoteFilter,
+    ) -> Result<Vec<crate::applicants::Note>, SumsubError>;
+
+    /// Adds a note to an applicant.
+    ///
+    /// See [`Client::add_applicant_note`].
+    async fn add_applicant_note(
+        &self,
+        applicant_id: &ApplicantId,
+        note: &str,
+    ) -> Result<crate::applicants::Note, SumsubError>;
+
+    /// Edits an applicant note.
+    ///
+    /// See [`Client::edit_applicant_note`].
+    async fn edit_applicant_note(
+        &self,
+        applicant_id: &ApplicantId,
+        note_id: &str,
+        note: &str,
+    ) -> Result<crate::applicants::Note, SumsubError>;
+
+    /// Removes an applicant note.
+    ///
+    /// See [`Client::remove_applicant_note`].
+    async fn remove_applicant_note(
+        &self,
+        applicant_id: &ApplicantId,
+        note_id: &str,
+    ) -> Result<(), SumsubError>;
+
+    /// Adds an attachment to an applicant note.
+    ///
+    /// See [`Client::add_note_attachment`].
+    async fn add_note_attachment(
+        &self,
+        applicant_id: &ApplicantId,
+        note_id: &str,
+        content: Vec<u8>,
+        file_name: &str,
+        mime_type: &str,
+    ) -> Result<crate::applicants::Note, SumsubError>;
+
+    /// Downloads an attachment from a note.
+    ///
+    /// See [`Client::download_note_attachment`].
+    async fn download_note_attachment(
+        &self,
+        applicant_id: &ApplicantId,
+        note_id: &str,
+        attachment_id: &str,
+    ) -> Result<Vec<u8>, SumsubError>;
+
+    /// Removes an attachment from a note.
+    ///
+    /// See [`Client::remove_note_attachment`].
+    async fn remove_note_attachment(
+        &self,
+        applicant_id: &ApplicantId,
+        note_id: &str,
+        attachment_id: &str,
+    ) -> Result<(), SumsubError>;
+
+    /// Gets applicant data by external user ID.
+    ///
+    /// See [`Client::get_applicant_data_by_external_user_id`].
+    async fn get_applicant_data_by_external_user_id(
+        &self,
+        external_user_id: &ExternalUserId,
+    ) -> Result<crate::models::Applicant, SumsubError>;
+
+    /// Gets the status of verification steps for an applicant.
+    ///
+    /// See [`Client::get_verification_steps_status`].
+    async fn get_verification_steps_status(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<std::collections::HashMap<String, crate::applicants::VerificationStepStatus>, SumsubError>;
+
+    /// Gets the status of verification steps for an applicant, including the
+    /// image IDs of each step and their individual review results.
+    ///
+    /// See [`Client::get_required_docs_status_detailed`].
+    async fn get_required_docs_status_detailed(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<std::collections::HashMap<String, Option<crate::applicants::RequiredDocStepStatus>>, SumsubError>;
+
+    /// Asks Sumsub to deliver past webhooks again, e.g., to recover events
+    /// missed while the webhook receiver was down.
+    ///
+    /// See [`Client::resend_webhooks`].
+    async fn resend_webhooks(
+        &self,
+        request: &crate::webhooks::WebhookResendRequest,
+    ) -> Result<OperationResult, SumsubError>;
+
+    /// Fetches the applicant, its moderation states, and the latest check
+    /// results of each verification step for an `applicantReviewed` webhook.
+    ///
+    /// See [`Client::get_review_context`].
+    async fn get_review_context(
+        &self,
+        payload: &crate::webhooks::ApplicantReviewedPayload,
+    ) -> Result<crate::webhooks::ReviewContext, SumsubError>;
+
+    /// Fetches everything needed for a manual review screen: the applicant's
+    /// status, moderation states, images, notes, and the latest results of
+    /// `check_types`.
+    ///
+    /// See [`Client::get_review_bundle`].
+    async fn get_review_bundle(
+        &self,
+        applicant_id: &ApplicantId,
+        check_types: &[CheckType],
+    ) -> Result<crate::applicants::ReviewBundle, SumsubError>;
+
+    /// Gets the review history for an applicant.
+    ///
+    /// See [`Client::get_applicant_review_history`].
+    async fn get_applicant_review_history(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<crate::applicants::ReviewHistoryRecord>, SumsubError>;
+
+    /// Gets a document image.
+    ///
+    /// See [`Client::get_document_image`].
+    async fn get_document_image(
+        &self,
+        applicant_id: &ApplicantId,
+        inspection_id: &str,
+        image_id: &ImageId,
+    ) -> Result<Vec<u8>, SumsubError>;
+
+    /// Downloads a document image and checks its size and hash against the
+    /// metadata in `image` (see [`Client::get_document_images_info`]).
+    ///
+    /// See [`Client::get_document_image_verified`].
+    async fn get_document_image_verified(
+        &self,
+        applicant_id: &ApplicantId,
+        image: &crate::applicants::ImageInfo,
+    ) -> Result<(Vec<u8>, crate::integrity::IntegrityReport), SumsubError>;
+
+    /// Streams a document image instead of loading it into memory. See
+    /// [`Client::get_document_image`].
+    ///
+    /// See [`Client::get_document_image_stream`].
+    async fn get_document_image_stream(
+        &self,
+        applicant_id: &ApplicantId,
+        inspection_id: &str,
+        image_id: &ImageId,
+    ) -> Result<crate::response::ByteStream, SumsubError>;
+
+    /// Gets a temporary download link for a document image instead of its
+    /// bytes, e.g., to let a browser fetch it directly from storage.
+    ///
+    /// See [`Client::get_document_image_link`].
+    async fn get_document_image_link(
+        &self,
+        applicant_id: &ApplicantId,
+        inspection_id: &str,
+        image_id: &ImageId,
+    ) -> Result<Option<String>, SumsubError>;
+
+    /// Gets information about document images for an applicant.
+    ///
+    /// See [`Client::get_document_images_info`].
+    async fn get_document_images_info(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<crate::applicants::ImageInfo>, SumsubError>;
+
+    /// Gets the fields extracted by OCR from an applicant's identity documents,
+    /// one result per document image.
+    ///
+    /// See [`Client::get_id_doc_ocr_results`].
+    async fn get_id_doc_ocr_results(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<crate::applicants::IdDocOcrResult>, SumsubError>;
+
+    /// Adds an image to an applicant action.
+    ///
+    /// See [`Client::add_image_to_action`].
+    async fn add_image_to_action<'a>(
+        &self,
+        action_id: &ActionId,
+        metadata: Option<crate::actions::AddActionImageMetadata<'a>>,
+        content: Vec<u8>,
+        file_name: &str,
+        mime_type: &str,
+    ) -> Result<Vec<crate::actions::ActionImage>, SumsubError>;
+
+    /// Gets an image from an applicant action.
+    ///
+    /// See [`Client::get_image_from_action`].
+    async fn get_image_from_action(
+        &self,
+        action_id: &ActionId,
+        image_id: &ImageId,
+    ) -> Result<Vec<u8>, SumsubError>;
+
+    /// Gets a temporary download link for an image from an applicant action
+    /// instead of its bytes.
+    ///
+    /// See [`Client::get_image_from_action_link`].
+    async fn get_image_from_action_link(
+        &self,
+        action_id: &ActionId,
+        image_id: &ImageId,
+    ) -> Result<Option<String>, SumsubError>;
+
+    /// Gets OCR fields from company documents.
+    ///
+    /// See [`Client::get_ocr_fields_from_company_documents`].
+    async fn get_ocr_fields_from_company_documents(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::kyb::CompanyOcrResult, SumsubError>;
+
+    /// Submits applicant data for Non-Doc Verification.
+    ///
+    /// See [`Client::submit_non_doc_data`].
+    async fn submit_non_doc_data(
+        &self,
+        applicant_id: &ApplicantId,
+        data: serde_json::Value,
+    ) -> Result<(), SumsubError>;
+
+    /// Confirms applicant data for Non-Doc Verification.
+    ///
+    /// See [`Client::confirm_non_doc_data`].
+    async fn confirm_non_doc_data(
+        &self,
+        applicant_id: &ApplicantId,
+        consent: &str,
+    ) -> Result<(), SumsubError>;
+
+    /// Approves or rejects a transaction.
+    ///
+    /// See [`Client::review_transaction`].
+    async fn review_transaction<'a>(
+        &self,
+        txn_id: &TxnId,
+        action: crate::transactions::TransactionReviewAction,
+        moderation_comment: Option<&'a str>,
+    ) -> Result<crate::transactions::SubmitTransactionResponse, SumsubError>;
+
+    /// Rescores a transaction.
+    ///
+    /// See [`Client::rescore_transaction`].
+    async fn rescore_transaction(
+        &self,
+        txn_id: &TxnId,
+    ) -> Result<crate::transactions::SubmitTransactionResponse, SumsubError>;
+
+    /// Changes transaction properties.
+    ///
+    /// See [`Client::change_transaction_properties`].
+    async fn change_transaction_properties(
+        &self,
+        txn_id: &TxnId,
+        properties: serde_json::Value,
+    ) -> Result<crate::transactions::SubmitTransactionResponse, SumsubError>;
+
+    /// Finds specific transactions using an expression.
+    ///
+    /// See [`Client::find_transactions`].
+    async fn find_transactions(
+        &self,
+        expression: &str,
+    ) -> Result<crate::transactions::FindTransactionsResponse, SumsubError>;
+
+    /// Gets the list of available currencies for transaction monitoring.
+    ///
+    /// See [`Client::get_available_currencies`].
+    async fn get_available_currencies(
+        &self,
+    ) -> Result<crate::transactions::AvailableCurrenciesResponse, SumsubError>;
+
+    /// Adds tags to a transaction.
+    ///
+    /// See [`Client::add_transaction_tags`].
+    async fn add_transaction_tags<'a>(
+        &self,
+        txn_id: &TxnId,
+        tags: Vec<&'a str>,
+    ) -> Result<(), SumsubError>;
+
+    /// Gets tags for a transaction.
+    ///
+    /// See [`Client::get_transaction_tags`].
+    async fn get_transaction_tags(
+        &self,
+        txn_id: &TxnId,
+    ) -> Result<crate::transactions::GetTransactionTagsResponse, SumsubError>;
+
+    /// Removes tags from a transaction.
+    ///
+    /// See [`Client::remove_transaction_tags`].
+    async fn remove_transaction_tags<'a>(
+        &self,
+        txn_id: &TxnId,
+        tags: Vec<&'a str>,
+    ) -> Result<(), SumsubError>;
+
+    /// Adds a note to a transaction.
+    ///
+    /// See [`Client::add_transaction_note`].
+    async fn add_transaction_note(
+        &self,
+        txn_id: &TxnId,
+        note: &str,
+    ) -> Result<crate::transactions::TransactionNote, SumsubError>;
+
+    /// Gets notes for a transaction.
+    ///
+    /// See [`Client::get_transaction_notes`].
+    async fn get_transaction_notes(
+        &self,
+        txn_id: &TxnId,
+    ) -> Result<Vec<crate::transactions::TransactionNote>, SumsubError>;
+
+    /// Edits a transaction note.
+    ///
+    /// See [`Client::edit_transaction_note`].
+    async fn edit_transaction_note(
+        &self,
+        txn_id: &TxnId,
+        note_id: &str,
+        note: &str,
+    ) -> Result<crate::transactions::TransactionNote, SumsubError>;
+
+    /// Removes a transaction note.
+    ///
+    /// See [`Client::remove_transaction_note`].
+    async fn remove_transaction_note(
+        &self,
+        txn_id: &TxnId,
+        note_id: &str,
+    ) -> Result<(), SumsubError>;
+
+    /// Adds a payment method.
+    ///
+    /// See [`Client::add_payment_method`].
+    async fn add_payment_method(
+        &self,
+        payment_method: crate::transactions::PaymentMethod,
+    ) -> Result<crate::transactions::PaymentMethod, SumsubError>;
+
+    /// Updates a wallet address.
+    ///
+    /// See [`Client::update_wallet_address`].
+    async fn update_wallet_address(
+        &self,
+        address: &str,
+        request: crate::travel_rule::UpdateWalletAddressRequest,
+    ) -> Result<(), SumsubError>;
+
+    /// Gets the list of available VASPs.
+    ///
+    /// See [`Client::get_available_vasps`].
+    async fn get_available_vasps(&self) -> Result<crate::travel_rule::VaspsResponse, SumsubError>;
+
+    /// Gets a single page of Travel Rule transfers matching `filter`, e.g.,
+    /// to reconcile the transfers still awaiting counterparty data.
+    ///
+    /// See [`Client::list_travel_rule_transfers_page`].
+    async fn list_travel_rule_transfers_page(
+        &self,
+        filter: &crate::travel_rule::TransferFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<crate::travel_rule::TravelRuleTransfer>, SumsubError>;
+
+    /// Gets all Travel Rule transfers matching `filter` by following pages
+    /// until the last one.
+    ///
+    /// See [`Client::list_all_travel_rule_transfers`].
+    async fn list_all_travel_rule_transfers(
+        &self,
+        filter: &crate::travel_rule::TransferFilter,
+    ) -> Result<Vec<crate::travel_rule::TravelRuleTransfer>, SumsubError>;
+
+    /// Generates a Device Intelligence access token.
+    ///
+    /// See [`Client::generate_device_intelligence_token`].
+    async fn generate_device_intelligence_token<'a>(
+        &self,
+        request: crate::device_intelligence::DeviceTokenRequest<'a>,
+    ) -> Result<crate::device_intelligence::DeviceIntelligenceToken, SumsubError>;
+
+    /// Sends an applicant platform event with captured device information.
+    ///
+    /// See [`Client::send_platform_event`].
+    async fn send_platform_event<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        event: crate::device_intelligence::PlatformEvent<'a>,
+    ) -> Result<(), SumsubError>;
+
+    /// Sends a burst of platform events for an applicant.
+    ///
+    /// See [`Client::send_platform_events`].
+    async fn send_platform_events<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        events: &[crate::device_intelligence::PlatformEvent<'a>],
+    ) -> Vec<Result<(), SumsubError>>;
+
+    /// Sends financial transaction data with captured device information.
+    ///
+    /// See [`Client::send_financial_transaction_with_device`].
+    async fn send_financial_transaction_with_device(
+        &self,
+        txn_id: &TxnId,
+        fingerprint: &str,
+    ) -> Result<(), SumsubError>;
+
+    /// Sends device information captured for the counterparty of a
+    /// transaction, e.g., when both sides of a marketplace transaction are
+    /// your users.
+    ///
+    /// See [`Client::send_counterparty_device`].
+    async fn send_counterparty_device<'a>(
+        &self,
+        txn_id: &TxnId,
+        device: crate::device_intelligence::CounterpartyDevice<'a>,
+    ) -> Result<(), SumsubError>;
+
+    /// Imports an applicant profile from a zip archive.
+    ///
+    /// See [`Client::import_applicant_profile_from_archive`].
+    async fn import_applicant_profile_from_archive(
+        &self,
+        content: Vec<u8>,
+        file_name: &str,
+    ) -> Result<(), SumsubError>;
+
+    /// Imports an applicant profile from a zip archive on disk, retrying once
+    /// if the upload fails with a transient error (see
+    /// [`SumsubError::is_retryable`]).
+    ///
+    /// See [`Client::import_applicant_profile_from_archive_file`].
+    async fn import_applicant_profile_from_archive_file(
+        &self,
+        path: &std::path::Path,
+    ) -> Result<(), SumsubError>;
+}
+
+#[async_trait]
+impl SumsubApi for Client {
+    async fn create_applicant(
+        &self,
+        request: CreateApplicantRequest,
+        level_name: &str,
+    ) -> Result<Applicant, SumsubError> {
+        Client::create_applicant(self, request, level_name).await
+    }
+
+    async fn create_or_get_applicant(
+        &self,
+        request: CreateApplicantRequest,
+        level_name: &str,
+    ) -> Result<Applicant, SumsubError> {
+        Client::create_or_get_applicant(self, request, level_name).await
+    }
+
+    async fn get_applicant_data(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Applicant, SumsubError> {
+        Client::get_applicant_data(self, applicant_id).await
+    }
+
+    async fn get_applicant_data_with_diagnostics(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::response::ApiResponse<Applicant>, SumsubError> {
+        Client::get_applicant_data_with_diagnostics(self, applicant_id).await
+    }
+
+    async fn sync_applicants_since(
+        &self,
+        since: &str,
+    ) -> Result<crate::sync::SyncBatch<Applicant>, SumsubError> {
+        Client::sync_applicants_since(self, since).await
+    }
+
+    async fn get_latest_tin_check_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<TinCheckResult, SumsubError> {
+        Client::get_latest_tin_check_result(self, applicant_id).await
+    }
+
+    async fn get_latest_similar_search_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<SimilarSearchResult, SumsubError> {
+        Client::get_latest_similar_search_result(self, applicant_id).await
+    }
+
+    async fn get_latest_poa_check_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<PoaCheckResult, SumsubError> {
+        Client::get_latest_poa_check_result(self, applicant_id).await
+    }
+
+    async fn get_latest_bank_card_check_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<BankCardCheckResult, SumsubError> {
+        Client::get_latest_bank_card_check_result(self, applicant_id).await
+    }
+
+    async fn get_latest_email_confirmation_check_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<EmailConfirmationCheckResult, SumsubError> {
+        Client::get_latest_email_confirmation_check_result(self, applicant_id).await
+    }
+
+    async fn get_latest_phone_confirmation_check_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<PhoneConfirmationCheckResult, SumsubError> {
+        Client::get_latest_phone_confirmation_check_result(self, applicant_id).await
+    }
+
+    async fn get_latest_ip_check_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<IpCheckResult, SumsubError> {
+        Client::get_latest_ip_check_result(self, applicant_id).await
+    }
+
+    async fn get_latest_nfc_check_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<NfcCheckResult, SumsubError> {
+        Client::get_latest_nfc_check_result(self, applicant_id).await
+    }
+
+    async fn get_latest_face_match_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<FaceMatchCheckResult, SumsubError> {
+        Client::get_latest_face_match_result(self, applicant_id).await
+    }
+
+    async fn get_latest_liveness_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<LivenessCheckResult, SumsubError> {
+        Client::get_latest_liveness_result(self, applicant_id).await
+    }
+
+    async fn get_audit_trail_events(&self) -> Result<Vec<AuditTrailEvent>, SumsubError> {
+        Client::get_audit_trail_events(self).await
+    }
+
+    async fn get_audit_trail_events_page(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<AuditTrailEvent>, SumsubError> {
+        Client::get_audit_trail_events_page(self, offset, limit).await
+    }
+
+    async fn get_team_members(&self) -> Result<Vec<crate::misc::TeamMember>, SumsubError> {
+        Client::get_team_members(self).await
+    }
+
+    async fn get_api_health_status(&self) -> Result<ApiHealthStatus, SumsubError> {
+        Client::get_api_health_status(self).await
+    }
+
+    async fn create_applicant_action(
+        &self,
+        applicant_id: &ApplicantId,
+        level_name: &str,
+        request: CreateApplicantActionRequest,
+    ) -> Result<ApplicantAction, SumsubError> {
+        Client::create_applicant_action(self, applicant_id, level_name, request).await
+    }
+
+    async fn request_action_check(
+        &self,
+        action_id: &ActionId,
+    ) -> Result<RequestActionCheckResponse, SumsubError> {
+        Client::request_action_check(self, action_id).await
+    }
+
+    async fn get_applicant_actions(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<GetApplicantActionsResponse, SumsubError> {
+        Client::get_applicant_actions(self, applicant_id).await
+    }
+
+    async fn get_applicant_actions_page(
+        &self,
+        applicant_id: &ApplicantId,
+        filter: &crate::actions::ActionFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Result<GetApplicantActionsResponse, SumsubError> {
+        Client::get_applicant_actions_page(self, applicant_id, filter, offset, limit).await
+    }
+
+    async fn get_action_information(
+        &self,
+        action_id: &ActionId,
+    ) -> Result<ApplicantAction, SumsubError> {
+        Client::get_action_information(self, action_id).await
+    }
+
+    async fn get_action_by_external_id(
+        &self,
+        external_action_id: &str,
+    ) -> Result<ApplicantAction, SumsubError> {
+        Client::get_action_by_external_id(self, external_action_id).await
+    }
+
+    async fn get_applicant_action_questionnaires(
+        &self,
+        action_id: &ActionId,
+    ) -> Result<Vec<Questionnaire>, SumsubError> {
+        Client::get_applicant_action_questionnaires(self, action_id).await
+    }
+
+    async fn add_applicant_action_questionnaire(
+        &self,
+        action_id: &ActionId,
+        questionnaire: Questionnaire,
+    ) -> Result<Questionnaire, SumsubError> {
+        Client::add_applicant_action_questionnaire(self, action_id, questionnaire).await
+    }
+
+    async fn link_beneficiary(
+        &self,
+        applicant_id: &ApplicantId,
+        request: LinkBeneficiaryRequest,
+    ) -> Result<(), SumsubError> {
+        Client::link_beneficiary(self, applicant_id, request).await
+    }
+
+    async fn unlink_beneficiary(
+        &self,
+        applicant_id: &ApplicantId,
+        beneficiary_id: &str,
+    ) -> Result<(), SumsubError> {
+        Client::unlink_beneficiary(self, applicant_id, beneficiary_id).await
+    }
+
+    async fn get_company_beneficiaries(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<crate::kyb::LinkedBeneficiary>, SumsubError> {
+        Client::get_company_beneficiaries(self, applicant_id).await
+    }
+
+    async fn get_beneficiary_readiness(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::kyb::BeneficiaryReadinessReport, SumsubError> {
+        Client::get_beneficiary_readiness(self, applicant_id).await
+    }
+
+    async fn change_extracted_company_data(
+        &self,
+        applicant_id: &ApplicantId,
+        company_info: CompanyInfo,
+    ) -> Result<CompanyInfo, SumsubError> {
+        Client::change_extracted_company_data(self, applicant_id, company_info).await
+    }
+
+    async fn change_provided_company_data(
+        &self,
+        applicant_id: &ApplicantId,
+        fixed_info: FixedInfo,
+    ) -> Result<(), SumsubError> {
+        Client::change_provided_company_data(self, applicant_id, fixed_info).await
+    }
+
+    async fn get_additional_company_check_data(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<GetAdditionalCompanyCheckDataResponse, SumsubError> {
+        Client::get_additional_company_check_data(self, applicant_id).await
+    }
+
+    async fn screen_company_persons(
+        &self,
+        applicant_id: &ApplicantId,
+        level_name: &str,
+    ) -> Result<Vec<crate::kyb::PersonScreening>, SumsubError> {
+        Client::screen_company_persons(self, applicant_id, level_name).await
+    }
+
+    async fn get_person_screening_report(
+        &self,
+        screenings: &[crate::kyb::PersonScreening],
+    ) -> Result<crate::kyb::PersonScreeningReport, SumsubError> {
+        Client::get_person_screening_report(self, screenings).await
+    }
+
+    async fn search_company_registry(
+        &self,
+        query: &crate::kyb::CompanySearchQuery,
+    ) -> Result<Vec<crate::kyb::CompanySearchResult>, SumsubError> {
+        Client::search_company_registry(self, query).await
+    }
+
+    async fn submit_transaction(
+        &self,
+        applicant_id: &ApplicantId,
+        request: SubmitTransactionRequest,
+    ) -> Result<SubmitTransactionResponse, SumsubError> {
+        Client::submit_transaction(self, applicant_id, request).await
+    }
+
+    async fn submit_transaction_for_non_existing_applicant(
+        &self,
+        request: SubmitTransactionRequest,
+    ) -> Result<SubmitTransactionResponse, SumsubError> {
+        Client::submit_transaction_for_non_existing_applicant(self, request).await
+    }
+
+    async fn delete_transaction(
+        &self,
+        txn_id: &TxnId,
+    ) -> Result<DeleteTransactionResponse, SumsubError> {
+        Client::delete_transaction(self, txn_id).await
+    }
+
+    async fn bulk_transaction_import(
+        &self,
+        requests: Vec<BulkTransactionImportRequest>,
+    ) -> Result<BulkTransactionImportResponse, SumsubError> {
+        Client::bulk_transaction_import(self, requests).await
+    }
+
+    async fn bulk_transaction_import_with_diagnostics(
+        &self,
+        requests: Vec<BulkTransactionImportRequest>,
+    ) -> Result<crate::response::ApiResponse<BulkTransactionImportResponse>, SumsubError> {
+        Client::bulk_transaction_import_with_diagnostics(self, requests).await
+    }
+
+    async fn initiate_sdk_for_travel_rule_transaction(
+        &self,
+        request: InitiateSdkRequest,
+    ) -> Result<InitiateSdkResponse, SumsubError> {
+        Client::initiate_sdk_for_travel_rule_transaction(self, request).await
+    }
+
+    async fn patch_transaction_with_chain_transaction_id(
+        &self,
+        txn_id: &TxnId,
+        request: PatchTransactionRequest,
+    ) -> Result<SubmitTransactionResponse, SumsubError> {
+        Client::patch_transaction_with_chain_transaction_id(self, txn_id, request).await
+    }
+
+    async fn confirm_or_reject_transaction_ownership(
+        &self,
+        txn_id: &TxnId,
+        status: OwnershipStatus,
+    ) -> Result<SubmitTransactionResponse, SumsubError> {
+        Client::confirm_or_reject_transaction_ownership(self, txn_id, status).await
+    }
+
+    async fn confirm_wallet_ownership(
+        &self,
+        txn_id: &TxnId,
+        request: ConfirmWalletOwnershipRequest,
+    ) -> Result<SubmitTransactionResponse, SumsubError> {
+        Client::confirm_wallet_ownership(self, txn_id, request).await
+    }
+
+    async fn import_wallet_addresses(
+        &self,
+        requests: Vec<ImportWalletAddressesRequest>,
+    ) -> Result<ImportWalletAddressesResponse, SumsubError> {
+        Client::import_wallet_addresses(self, requests).await
+    }
+
+    async fn import_wallet_addresses_checked(
+        &self,
+        requests: Vec<ImportWalletAddressesRequest>,
+    ) -> Result<ImportWalletAddressesResponse, SumsubError> {
+        Client::import_wallet_addresses_checked(self, requests).await
+    }
+
+    async fn get_transaction_data(
+        &self,
+        txn_id: &TxnId,
+    ) -> Result<SubmitTransactionResponse, SumsubError> {
+        Client::get_transaction_data(self, txn_id).await
+    }
+
+    async fn get_all_transactions_for_applicant(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<SubmitTransactionResponse>, SumsubError> {
+        Client::get_all_transactions_for_applicant(self, applicant_id).await
+    }
+
+    async fn get_applicant_kyt_summary(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::transactions::KytApplicantSummary, SumsubError> {
+        Client::get_applicant_kyt_summary(self, applicant_id).await
+    }
+
+    async fn sync_transactions_since(
+        &self,
+        since: &str,
+    ) -> Result<crate::sync::SyncBatch<SubmitTransactionResponse>, SumsubError> {
+        Client::sync_transactions_since(self, since).await
+    }
+
+    async fn set_transaction_block(
+        &self,
+        txn_id: &TxnId,
+        request: SetTransactionBlockRequest,
+    ) -> Result<SubmitTransactionResponse, SumsubError> {
+        Client::set_transaction_block(self, txn_id, request).await
+    }
+
+    async fn move_applicant_to_level(
+        &self,
+        applicant_id: &ApplicantId,
+        level_name: &str,
+    ) -> Result<(), SumsubError> {
+        Client::move_applicant_to_level(self, applicant_id, level_name).await
+    }
+
+    async fn update_applicant_fixed_info(
+        &self,
+        applicant_id: &ApplicantId,
+        fixed_info: FixedInfo,
+    ) -> Result<(), SumsubError> {
+        Client::update_applicant_fixed_info(self, applicant_id, fixed_info).await
+    }
+
+    async fn ensure_fixed_info(
+        &self,
+        applicant_id: &ApplicantId,
+        fixed_info: FixedInfo,
+    ) -> Result<bool, SumsubError> {
+        Client::ensure_fixed_info(self, applicant_id, fixed_info).await
+    }
+
+    async fn get_applicant_status(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<ApplicantStatus, SumsubError> {
+        Client::get_applicant_status(self, applicant_id).await
+    }
+
+    async fn get_applicant_moderation_states(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<ModerationState>, SumsubError> {
+        Client::get_applicant_moderation_states(self, applicant_id).await
+    }
+
+    async fn get_applicant_moderation_states_page(
+        &self,
+        applicant_id: &ApplicantId,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<ModerationState>, SumsubError> {
+        Client::get_applicant_moderation_states_page(self, applicant_id, offset, limit).await
+    }
+
+    async fn get_all_applicant_moderation_states(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<ModerationState>, SumsubError> {
+        Client::get_all_applicant_moderation_states(self, applicant_id).await
+    }
+
+    async fn request_applicant_recheck(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<OperationResult, SumsubError> {
+        Client::request_applicant_recheck(self, applicant_id).await
+    }
+
+    async fn add_applicant_to_blocklist(
+        &self,
+        applicant_id: &ApplicantId,
+        note: String,
+    ) -> Result<Applicant, SumsubError> {
+        Client::add_applicant_to_blocklist(self, applicant_id, note).await
+    }
+
+    async fn create_share_token<'a>(
+        &self,
+        request: ShareTokenRequest<'a>,
+    ) -> Result<ShareTokenResponse, SumsubError> {
+        Client::create_share_token(self, request).await
+    }
+
+    async fn import_shared_applicant(
+        &self,
+        token: &str,
+    ) -> Result<ImportApplicantResponse, SumsubError> {
+        Client::import_shared_applicant(self, token).await
+    }
+
+    async fn reset_applicant_step(
+        &self,
+        applicant_id: &ApplicantId,
+        id_doc_set_type: &str,
+    ) -> Result<(), SumsubError> {
+        Client::reset_applicant_step(self, applicant_id, id_doc_set_type).await
+    }
+
+    async fn reset_applicant(&self, applicant_id: &ApplicantId) -> Result<(), SumsubError> {
+        Client::reset_applicant(self, applicant_id).await
+    }
+
+    async fn ingest_completed_applicant(
+        &self,
+        request: IngestCompletedRequest,
+    ) -> Result<(), SumsubError> {
+        Client::ingest_completed_applicant(self, request).await
+    }
+
+    async fn ingest_completed_applicants(
+        &self,
+        requests: Vec<IngestCompletedRequest>,
+        options: BulkIngestOptions,
+    ) -> Vec<IngestOutcome> {
+        Client::ingest_completed_applicants(self, requests, options).await
+    }
+
+    async fn update_applicant_top_level_data(
+        &self,
+        applicant_id: &ApplicantId,
+        request: UpdateApplicantRequest,
+    ) -> Result<Applicant, SumsubError> {
+        Client::update_applicant_top_level_data(self, applicant_id, request).await
+    }
+
+    async fn start_check<'a>(
+        &self,
+        request: StartCheckRequest<'a>,
+    ) -> Result<OperationResult, SumsubError> {
+        Client::start_check(self, request).await
+    }
+
+    async fn generate_external_websdk_link<'a>(
+        &self,
+        level_name: Option<&'a str>,
+        external_user_id: Option<&'a ExternalUserId>,
+        ttl_in_secs: Option<u64>,
+    ) -> Result<GenerateWebsdkLinkResponse, SumsubError> {
+        Client::generate_external_websdk_link(self, level_name, external_user_id, ttl_in_secs).await
+    }
+
+    async fn generate_token_for_new_applicant<'a>(
+        &self,
+        level_name: Option<&'a str>,
+        external_user_id: Option<&'a ExternalUserId>,
+        ttl_in_secs: Option<u64>,
+    ) -> Result<NewApplicantAccessTokenResponse, SumsubError> {
+        Client::generate_token_for_new_applicant(
+            self,
+            level_name,
+            external_user_id,
+            ttl_in_secs,
+        )
+        .await
+    }
+
+    async fn generate_token_for_existing_applicant(
+        &self,
+        applicant_id: &ApplicantId,
+        level_name: &str,
+    ) -> Result<String, SumsubError> {
+        Client::generate_token_for_existing_applicant(self, applicant_id, level_name).await
+    }
+
+    async fn get_similar_applicants_by_text_and_face(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<SimilarByTextAndFaceResult, SumsubError> {
+        Client::get_similar_applicants_by_text_and_face(self, applicant_id).await
+    }
+
+    async fn get_applicant_events(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<ApplicantEvent>, SumsubError> {
+        Client::get_applicant_events(self, applicant_id).await
+    }
+
+    async fn get_applicant_events_page(
+        &self,
+        applicant_id: &ApplicantId,
+        filter: &crate::applicants::EventFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<ApplicantEvent>, SumsubError> {
+        Client::get_applicant_events_page(self, applicant_id, filter, offset, limit).await
+    }
+
+    async fn get_all_applicant_events(
+        &self,
+        applicant_id: &ApplicantId,
+        filter: &crate::applicants::EventFilter,
+    ) -> Result<Vec<ApplicantEvent>, SumsubError> {
+        Client::get_all_applicant_events(self, applicant_id, filter).await
+    }
+
+    async fn send_verification_email<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        request: SendVerificationMessageRequest<'a>,
+    ) -> Result<(), SumsubError> {
+        Client::send_verification_email(self, applicant_id, request).await
+    }
+
+    async fn get_liveness_video(&self, applicant_id: &ApplicantId) -> Result<Vec<u8>, SumsubError> {
+        Client::get_liveness_video(self, applicant_id).await
+    }
+
+    async fn get_liveness_video_stream(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::response::ByteStream, SumsubError> {
+        Client::get_liveness_video_stream(self, applicant_id).await
+    }
+
+    async fn get_liveness_video_link(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Option<String>, SumsubError> {
+        Client::get_liveness_video_link(self, applicant_id).await
+    }
+
+    async fn get_verification_pdf_report(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<u8>, SumsubError> {
+        Client::get_verification_pdf_report(self, applicant_id).await
+    }
+
+    async fn get_verification_pdf_report_stream(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::response::ByteStream, SumsubError> {
+        Client::get_verification_pdf_report_stream(self, applicant_id).await
+    }
+
+    async fn get_verification_pdf_report_with_diagnostics(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::response::ApiResponse<Vec<u8>>, SumsubError> {
+        Client::get_verification_pdf_report_with_diagnostics(self, applicant_id).await
+    }
+
+    async fn export_data_subject_record(
+        &self,
+        applicant_id: &ApplicantId,
+        max_audit_trail_pages: u32,
+    ) -> Result<crate::export::DataSubjectExport, SumsubError> {
+        Client::export_data_subject_record(self, applicant_id, max_audit_trail_pages).await
+    }
+
+    async fn export_applicant_bundle(
+        &self,
+        applicant_id: &ApplicantId,
+        dest_dir: &std::path::Path,
+    ) -> Result<crate::export::ApplicantBundle, SumsubError> {
+        Client::export_applicant_bundle(self, applicant_id, dest_dir).await
+    }
+
+    async fn change_applicant_data(
+        &self,
+        applicant_id: &ApplicantId,
+        info: crate::models::Info,
+    ) -> Result<crate::models::Applicant, SumsubError> {
+        Client::change_applicant_data(self, applicant_id, info).await
+    }
+
+    async fn get_available_levels(&self) -> Result<Vec<AvailableLevel>, SumsubError> {
+        Client::get_available_levels(self).await
+    }
+
+    async fn send_verification_phone_sms<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        request: SendVerificationMessageRequest<'a>,
+    ) -> Result<(), SumsubError> {
+        Client::send_verification_phone_sms(self, applicant_id, request).await
+    }
+
+    async fn get_verification_zip_report(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<u8>, SumsubError> {
+        Client::get_verification_zip_report(self, applicant_id).await
+    }
+
+    async fn get_verification_zip_report_stream(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::response::ByteStream, SumsubError> {
+        Client::get_verification_zip_report_stream(self, applicant_id).await
+    }
+
+    async fn add_verification_document<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        metadata: crate::applicants::AddDocumentMetadata<'a>,
+        content: Vec<u8>,
+        file_name: &str,
+        mime_type: &str,
+    ) -> Result<(), SumsubError> {
+        Client::add_verification_document(
+            self,
+            applicant_id,
+            metadata,
+            content,
+            file_name,
+            mime_type,
+        )
+        .await
+    }
+
+    async fn copy_applicant_profile(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::models::Applicant, SumsubError> {
+        Client::copy_applicant_profile(self, applicant_id).await
+    }
+
+    async fn simulate_review_response<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        request: crate::applicants::SimulateReviewRequest<'a>,
+    ) -> Result<(), SumsubError> {
+        Client::simulate_review_response(self, applicant_id, request).await
+    }
+
+    async fn simulate_doc_set_rejection<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        doc_set: &crate::applicants::IdDocSetType,
+        reject_labels: &[&'a str],
+    ) -> Result<(), SumsubError> {
+        Client::simulate_doc_set_rejection(self, applicant_id, doc_set, reject_labels).await
+    }
+
+    async fn create_sandbox_applicant(
+        &self,
+        request: CreateApplicantRequest,
+        level_name: &str,
+        outcome: crate::sandbox::SandboxOutcome,
+    ) -> Result<Applicant, SumsubError> {
+        Client::create_sandbox_applicant(self, request, level_name, outcome).await
+    }
+
+    async fn run_aml_check(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<OperationResult, SumsubError> {
+        Client::run_aml_check(self, applicant_id).await
+    }
+
+    async fn get_aml_case_data(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::applicants::AmlData, SumsubError> {
+        Client::get_aml_case_data(self, applicant_id).await
+    }
+
+    async fn get_aml_report(
+        &self,
+        applicant_id: &ApplicantId,
+        format: crate::applicants::AmlReportFormat,
+    ) -> Result<Vec<u8>, SumsubError> {
+        Client::get_aml_report(self, applicant_id, format).await
+    }
+
+    async fn update_aml_hit_review<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        hit_id: &str,
+        request: crate::applicants::UpdateAmlHitReviewRequest<'a>,
+    ) -> Result<(), SumsubError> {
+        Client::update_aml_hit_review(self, applicant_id, hit_id, request).await
+    }
+
+    async fn mark_image_as_inactive(
+        &self,
+        applicant_id: &ApplicantId,
+        image_id: &ImageId,
+    ) -> Result<(), SumsubError> {
+        Client::mark_image_as_inactive(self, applicant_id, image_id).await
+    }
+
+    async fn set_image_transform(
+        &self,
+        applicant_id: &ApplicantId,
+        image_id: &ImageId,
+        transform: &crate::applicants::ImageTransform,
+    ) -> Result<(), SumsubError> {
+        Client::set_image_transform(self, applicant_id, image_id, transform).await
+    }
+
+    async fn deactivate_applicant_data(
+        &self,
+        applicant_id: &ApplicantId,
+        category: crate::applicants::DataCategory,
+    ) -> Result<Vec<crate::applicants::ImageDeactivation>, SumsubError> {
+        Client::deactivate_applicant_data(self, applicant_id, category).await
+    }
+
+    async fn deactivate_doc_set(
+        &self,
+        applicant_id: &ApplicantId,
+        doc_set: &crate::applicants::IdDocSetType,
+    ) -> Result<Vec<crate::applicants::ImageDeactivation>, SumsubError> {
+        Client::deactivate_doc_set(self, applicant_id, doc_set).await
+    }
+
+    async fn rerequest_doc_set(
+        &self,
+        applicant_id: &ApplicantId,
+        doc_set: &crate::applicants::IdDocSetType,
+    ) -> Result<(), SumsubError> {
+        Client::rerequest_doc_set(self, applicant_id, doc_set).await
+    }
+
+    async fn deactivate_applicant_profile<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        moderation_comment: Option<&'a str>,
+    ) -> Result<(), SumsubError> {
+        Client::deactivate_applicant_profile(self, applicant_id, moderation_comment).await
+    }
+
+    async fn get_applicant_tag_dictionary(&self) -> Result<Vec<String>, SumsubError> {
+        Client::get_applicant_tag_dictionary(self).await
+    }
+
+    async fn get_transaction_tag_dictionary(&self) -> Result<Vec<String>, SumsubError> {
+        Client::get_transaction_tag_dictionary(self).await
+    }
+
+    async fn add_applicant_tags<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        tags: Vec<&'a str>,
+    ) -> Result<(), SumsubError> {
+        Client::add_applicant_tags(self, applicant_id, tags).await
+    }
+
+    async fn add_and_overwrite_applicant_tags<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        tags: Vec<&'a str>,
+    ) -> Result<(), SumsubError> {
+        Client::add_and_overwrite_applicant_tags(self, applicant_id, tags).await
+    }
+
+    async fn remove_applicant_tags<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        tags: Vec<&'a str>,
+    ) -> Result<(), SumsubError> {
+        Client::remove_applicant_tags(self, applicant_id, tags).await
+    }
+
+    async fn add_applicant_consents<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        consents: Vec<&'a str>,
+    ) -> Result<(), SumsubError> {
+        Client::add_applicant_consents(self, applicant_id, consents).await
+    }
+
+    async fn get_applicant_facing_consents(
+        &self,
+        level_name: &str,
+    ) -> Result<crate::applicants::ApplicantFacingConsentsResponse, SumsubError> {
+        Client::get_applicant_facing_consents(self, level_name).await
+    }
+
+    async fn get_level_agreements(
+        &self,
+        level_name: &str,
+    ) -> Result<crate::applicants::LevelAgreementsResponse, SumsubError> {
+        Client::get_level_agreements(self, level_name).await
+    }
+
+    async fn get_applicant_agreement(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::applicants::ApplicantAgreement, SumsubError> {
+        Client::get_applicant_agreement(self, applicant_id).await
+    }
+
+    async fn record_applicant_agreement<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        request: crate::applicants::RecordAgreementRequest<'a>,
+    ) -> Result<crate::applicants::ApplicantAgreement, SumsubError> {
+        Client::record_applicant_agreement(self, applicant_id, request).await
+    }
+
+    async fn get_applicant_notes(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<crate::applicants::Note>, SumsubError> {
+        Client::get_applicant_notes(self, applicant_id).await
+    }
+
+    async fn get_applicant_notes_page(
+        &self,
+        applicant_id: &ApplicantId,
+        filter: &crate::applicants::NoteFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<crate::applicants::Note>, SumsubError> {
+        Client::get_applicant_notes_page(self, applicant_id, filter, offset, limit).await
+    }
+
+    async fn get_all_applicant_notes(
+        &self,
+        applicant_id: &ApplicantId,
+        filter: &crate::applicants::NoteFilter,
+    ) -> Result<Vec<crate::applicants::Note>, SumsubError> {
+        Client::get_all_applicant_notes(self, applicant_id, filter).await
+    }
+
+    async fn add_applicant_note(
+        &self,
+        applicant_id: &ApplicantId,
+        note: &str,
+    ) -> Result<crate::applicants::Note, SumsubError> {
+        Client::add_applicant_note(self, applicant_id, note).await
+    }
+
+    async fn edit_applicant_note(
+        &self,
+        applicant_id: &ApplicantId,
+        note_id: &str,
+        note: &str,
+    ) -> Result<crate::applicants::Note, SumsubError> {
+        Client::edit_applicant_note(self, applicant_id, note_id, note).await
+    }
+
+    async fn remove_applicant_note(
+        &self,
+        applicant_id: &ApplicantId,
+        note_id: &str,
+    ) -> Result<(), SumsubError> {
+        Client::remove_applicant_note(self, applicant_id, note_id).await
+    }
+
+    async fn add_note_attachment(
+        &self,
+        applicant_id: &ApplicantId,
+        note_id: &str,
+        content: Vec<u8>,
+        file_name: &str,
+        mime_type: &str,
+    ) -> Result<crate::applicants::Note, SumsubError> {
+        Client::add_note_attachment(
+            self,
+            applicant_id,
+            note_id,
+            content,
+            file_name,
+            mime_type,
+        )
+        .await
+    }
+
+    async fn download_note_attachment(
+        &self,
+        applicant_id: &ApplicantId,
+        note_id: &str,
+        attachment_id: &str,
+    ) -> Result<Vec<u8>, SumsubError> {
+        Client::download_note_attachment(self, applicant_id, note_id, attachment_id).await
+    }
+
+    async fn remove_note_attachment(
+        &self,
+        applicant_id: &ApplicantId,
+        note_id: &str,
+        attachment_id: &str,
+    ) -> Result<(), SumsubError> {
+        Client::remove_note_attachment(self, applicant_id, note_id, attachment_id).await
+    }
+
+    async fn get_applicant_data_by_external_user_id(
+        &self,
+        external_user_id: &ExternalUserId,
+    ) -> Result<crate::models::Applicant, SumsubError> {
+        Client::get_applicant_data_by_external_user_id(self, external_user_id).await
+    }
+
+    async fn get_verification_steps_status(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<std::collections::HashMap<String, crate::applicants::VerificationStepStatus>, SumsubError> {
+        Client::get_verification_steps_status(self, applicant_id).await
+    }
+
+    async fn get_required_docs_status_detailed(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<std::collections::HashMap<String, Option<crate::applicants::RequiredDocStepStatus>>, SumsubError> {
+        Client::get_required_docs_status_detailed(self, applicant_id).await
+    }
+
+    async fn resend_webhooks(
+        &self,
+        request: &crate::webhooks::WebhookResendRequest,
+    ) -> Result<OperationResult, SumsubError> {
+        Client::resend_webhooks(self, request).await
+    }
+
+    async fn get_review_context(
+        &self,
+        payload: &crate::webhooks::ApplicantReviewedPayload,
+    ) -> Result<crate::webhooks::ReviewContext, SumsubError> {
+        Client::get_review_context(self, payload).await
+    }
+
+    async fn get_review_bundle(
+        &self,
+        applicant_id: &ApplicantId,
+        check_types: &[CheckType],
+    ) -> Result<crate::applicants::ReviewBundle, SumsubError> {
+        Client::get_review_bundle(self, applicant_id, check_types).await
+    }
+
+    async fn get_applicant_review_history(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<crate::applicants::ReviewHistoryRecord>, SumsubError> {
+        Client::get_applicant_review_history(self, applicant_id).await
+    }
+
+    async fn get_document_image(
+        &self,
+        applicant_id: &ApplicantId,
+        inspection_id: &str,
+        image_id: &ImageId,
+    ) -> Result<Vec<u8>, SumsubError> {
+        Client::get_document_image(self, applicant_id, inspection_id, image_id).await
+    }
+
+    async fn get_document_image_verified(
+        &self,
+        applicant_id: &ApplicantId,
+        image: &crate::applicants::ImageInfo,
+    ) -> Result<(Vec<u8>, crate::integrity::IntegrityReport), SumsubError> {
+        Client::get_document_image_verified(self, applicant_id, image).await
+    }
+
+    async fn get_document_image_stream(
+        &self,
+        applicant_id: &ApplicantId,
+        inspection_id: &str,
+        image_id: &ImageId,
+    ) -> Result<crate::response::ByteStream, SumsubError> {
+        Client::get_document_image_stream(self, applicant_id, inspection_id, image_id).await
+    }
+
+    async fn get_document_image_link(
+        &self,
+        applicant_id: &ApplicantId,
+        inspection_id: &str,
+        image_id: &ImageId,
+    ) -> Result<Option<String>, SumsubError> {
+        Client::get_document_image_link(self, applicant_id, inspection_id, image_id).await
+    }
+
+    async fn get_document_images_info(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<crate::applicants::ImageInfo>, SumsubError> {
+        Client::get_document_images_info(self, applicant_id).await
+    }
+
+    async fn get_id_doc_ocr_results(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<crate::applicants::IdDocOcrResult>, SumsubError> {
+        Client::get_id_doc_ocr_results(self, applicant_id).await
+    }
+
+    async fn add_image_to_action<'a>(
+        &self,
+        action_id: &ActionId,
+        metadata: Option<crate::actions::AddActionImageMetadata<'a>>,
+        content: Vec<u8>,
+        file_name: &str,
+        mime_type: &str,
+    ) -> Result<Vec<crate::actions::ActionImage>, SumsubError> {
+        Client::add_image_to_action(self, action_id, metadata, content, file_name, mime_type).await
+    }
+
+    async fn get_image_from_action(
+        &self,
+        action_id: &ActionId,
+        image_id: &ImageId,
+    ) -> Result<Vec<u8>, SumsubError> {
+        Client::get_image_from_action(self, action_id, image_id).await
+    }
+
+    async fn get_image_from_action_link(
+        &self,
+        action_id: &ActionId,
+        image_id: &ImageId,
+    ) -> Result<Option<String>, SumsubError> {
+        Client::get_image_from_action_link(self, action_id, image_id).await
+    }
+
+    async fn get_ocr_fields_from_company_documents(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::kyb::CompanyOcrResult, SumsubError> {
+        Client::get_ocr_fields_from_company_documents(self, applicant_id).await
+    }
+
+    async fn submit_non_doc_data(
+        &self,
+        applicant_id: &ApplicantId,
+        data: serde_json::Value,
+    ) -> Result<(), SumsubError> {
+        Client::submit_non_doc_data(self, applicant_id, data).await
+    }
+
+    async fn confirm_non_doc_data(
+        &self,
+        applicant_id: &ApplicantId,
+        consent: &str,
+    ) -> Result<(), SumsubError> {
+        Client::confirm_non_doc_data(self, applicant_id, consent).await
+    }
+
+    async fn review_transaction<'a>(
+        &self,
+        txn_id: &TxnId,
+        action: crate::transactions::TransactionReviewAction,
+        moderation_comment: Option<&'a str>,
+    ) -> Result<crate::transactions::SubmitTransactionResponse, SumsubError> {
+        Client::review_transaction(self, txn_id, action, moderation_comment).await
+    }
+
+    async fn rescore_transaction(
+        &self,
+        txn_id: &TxnId,
+    ) -> Result<crate::transactions::SubmitTransactionResponse, SumsubError> {
+        Client::rescore_transaction(self, txn_id).await
+    }
+
+    async fn change_transaction_properties(
+        &self,
+        txn_id: &TxnId,
+        properties: serde_json::Value,
+    ) -> Result<crate::transactions::SubmitTransactionResponse, SumsubError> {
+        Client::change_transaction_properties(self, txn_id, properties).await
+    }
+
+    async fn find_transactions(
+        &self,
+        expression: &str,
+    ) -> Result<crate::transactions::FindTransactionsResponse, SumsubError> {
+        Client::find_transactions(self, expression).await
+    }
+
+    async fn get_available_currencies(
+        &self,
+    ) -> Result<crate::transactions::AvailableCurrenciesResponse, SumsubError> {
+        Client::get_available_currencies(self).await
+    }
+
+    async fn add_transaction_tags<'a>(
+        &self,
+        txn_id: &TxnId,
+        tags: Vec<&'a str>,
+    ) -> Result<(), SumsubError> {
+        Client::add_transaction_tags(self, txn_id, tags).await
+    }
+
+    async fn get_transaction_tags(
+        &self,
+        txn_id: &TxnId,
+    ) -> Result<crate::transactions::GetTransactionTagsResponse, SumsubError> {
+        Client::get_transaction_tags(self, txn_id).await
+    }
+
+    async fn remove_transaction_tags<'a>(
+        &self,
+        txn_id: &TxnId,
+        tags: Vec<&'a str>,
+    ) -> Result<(), SumsubError> {
+        Client::remove_transaction_tags(self, txn_id, tags).await
+    }
+
+    async fn add_transaction_note(
+        &self,
+        txn_id: &TxnId,
+        note: &str,
+    ) -> Result<crate::transactions::TransactionNote, SumsubError> {
+        Client::add_transaction_note(self, txn_id, note).await
+    }
+
+    async fn get_transaction_notes(
+        &self,
+        txn_id: &TxnId,
+    ) -> Result<Vec<crate::transactions::TransactionNote>, SumsubError> {
+        Client::get_transaction_notes(self, txn_id).await
+    }
+
+    async fn edit_transaction_note(
+        &self,
+        txn_id: &TxnId,
+        note_id: &str,
+        note: &str,
+    ) -> Result<crate::transactions::TransactionNote, SumsubError> {
+        Client::edit_transaction_note(self, txn_id, note_id, note).await
+    }
+
+    async fn remove_transaction_note(
+        &self,
+        txn_id: &TxnId,
+        note_id: &str,
+    ) -> Result<(), SumsubError> {
+        Client::remove_transaction_note(self, txn_id, note_id).await
+    }
+
+    async fn add_payment_method(
+        &self,
+        payment_method: crate::transactions::PaymentMethod,
+    ) -> Result<crate::transactions::PaymentMethod, SumsubError> {
+        Client::add_payment_method(self, payment_method).await
+    }
+
+    async fn update_wallet_address(
+        &self,
+        address: &str,
+        request: crate::travel_rule::UpdateWalletAddressRequest,
+    ) -> Result<(), SumsubError> {
+        Client::update_wallet_address(self, address, request).await
+    }
+
+    async fn get_available_vasps(&self) -> Result<crate::travel_rule::VaspsResponse, SumsubError> {
+        Client::get_available_vasps(self).await
+    }
+
+    async fn list_travel_rule_transfers_page(
+        &self,
+        filter: &crate::travel_rule::TransferFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<crate::travel_rule::TravelRuleTransfer>, SumsubError> {
+        Client::list_travel_rule_transfers_page(self, filter, offset, limit).await
+    }
+
+    async fn list_all_travel_rule_transfers(
+        &self,
+        filter: &crate::travel_rule::TransferFilter,
+    ) -> Result<Vec<crate::travel_rule::TravelRuleTransfer>, SumsubError> {
+        Client::list_all_travel_rule_transfers(self, filter).await
+    }
+
+    async fn generate_device_intelligence_token<'a>(
+        &self,
+        request: crate::device_intelligence::DeviceTokenRequest<'a>,
+    ) -> Result<crate::device_intelligence::DeviceIntelligenceToken, SumsubError> {
+        Client::generate_device_intelligence_token(self, request).await
+    }
+
+    async fn send_platform_event<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        event: crate::device_intelligence::PlatformEvent<'a>,
+    ) -> Result<(), SumsubError> {
+        Client::send_platform_event(self, applicant_id, event).await
+    }
+
+    async fn send_platform_events<'a>(
+        &self,
+        applicant_id: &ApplicantId,
+        events: &[crate::device_intelligence::PlatformEvent<'a>],
+    ) -> Vec<Result<(), SumsubError>> {
+        Client::send_platform_events(self, applicant_id, events).await
+    }
+
+    async fn send_financial_transaction_with_device(
+        &self,
+        txn_id: &TxnId,
+        fingerprint: &str,
+    ) -> Result<(), SumsubError> {
+        Client::send_financial_transaction_with_device(self, txn_id, fingerprint).await
+    }
+
+    async fn send_counterparty_device<'a>(
+        &self,
+        txn_id: &TxnId,
+        device: crate::device_intelligence::CounterpartyDevice<'a>,
+    ) -> Result<(), SumsubError> {
+        Client::send_counterparty_device(self, txn_id, device).await
+    }
+
+    async fn import_applicant_profile_from_archive(
+        &self,
+        content: Vec<u8>,
+        file_name: &str,
+    ) -> Result<(), SumsubError> {
+        Client::import_applicant_profile_from_archive(self, content, file_name).await
+    }
+
+    async fn import_applicant_profile_from_archive_file(
+        &self,
+        path: &std::path::Path,
+    ) -> Result<(), SumsubError> {
+        Client::import_applicant_profile_from_archive_file(self, path).await
+    }
+}
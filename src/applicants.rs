@@ -5,6 +5,8 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use crate::actions::Questionnaire;
+use crate::ids::{ApplicantId, ExternalUserId, ImageId};
+use crate::query::Query;
 
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -19,6 +21,80 @@ pub struct ApplicantStatus {
     pub reject_labels: Option<Vec<String>>,
 }
 
+impl ApplicantStatus {
+    /// Returns `true` if the review is completed and the applicant was approved.
+    pub fn is_approved(&self) -> bool {
+        self.completed_result().is_some_and(ReviewResult::is_approved)
+    }
+
+    /// Returns `true` if the review is completed and the applicant was
+    /// rejected without the option to resubmit documents.
+    pub fn is_final_reject(&self) -> bool {
+        self.completed_result().is_some_and(ReviewResult::is_final_reject)
+    }
+
+    /// Returns `true` if the review is completed and the applicant was
+    /// rejected but may resubmit documents.
+    pub fn retry_allowed(&self) -> bool {
+        self.completed_result().is_some_and(ReviewResult::retry_allowed)
+    }
+
+    fn completed_result(&self) -> Option<&ReviewResult> {
+        self.review_result.as_ref().filter(|_| self.review_status == "completed")
+    }
+}
+
+/// The verification state of an applicant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VerificationState {
+    /// The applicant has not submitted all required documents yet.
+    AwaitingApplicant,
+    /// The applicant's documents are being reviewed.
+    Pending,
+    /// The applicant was approved.
+    Approved,
+    /// The applicant was rejected.
+    Rejected {
+        reject_labels: Vec<String>,
+        /// `true` if the applicant may resubmit documents.
+        can_retry: bool,
+    },
+}
+
+impl VerificationState {
+    /// Returns `true` if a final decision (approval or rejection) was made.
+    pub fn is_decided(&self) -> bool {
+        matches!(self, VerificationState::Approved | VerificationState::Rejected { .. })
+    }
+
+    pub(crate) fn from_review(
+        review_status: &str,
+        review_answer: Option<&str>,
+        reject_labels: Option<&[String]>,
+        review_reject_type: Option<&str>,
+    ) -> Self {
+        match (review_status, review_answer) {
+            ("completed", Some("GREEN")) => VerificationState::Approved,
+            ("completed", Some(_)) => VerificationState::Rejected {
+                reject_labels: reject_labels.map(<[String]>::to_vec).unwrap_or_default(),
+                can_retry: review_reject_type == Some("RETRY"),
+            },
+            ("init", _) => VerificationState::AwaitingApplicant,
+            _ => VerificationState::Pending,
+        }
+    }
+
+    pub(crate) fn from_status(status: &ApplicantStatus) -> Self {
+        let result = status.review_result.as_ref();
+        Self::from_review(
+            &status.review_status,
+            result.map(|r| r.review_answer.as_str()),
+            result.and_then(|r| r.reject_labels.as_deref()),
+            result.and_then(|r| r.review_reject_type.as_deref()),
+        )
+    }
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ReviewResult {
@@ -33,6 +109,27 @@ pub struct ReviewResult {
     pub client_comment: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reject_labels: Option<Vec<String>>,
+    /// The agent who made the decision, if it was made manually.
+    pub reviewed_by: Option<Agent>,
+}
+
+impl ReviewResult {
+    /// Returns `true` if the applicant was approved (`GREEN`).
+    pub fn is_approved(&self) -> bool {
+        self.review_answer == "GREEN"
+    }
+
+    /// Returns `true` if the applicant was rejected (`RED`) and may not resubmit
+    /// documents (`FINAL`).
+    pub fn is_final_reject(&self) -> bool {
+        self.review_answer == "RED" && self.review_reject_type.as_deref() == Some("FINAL")
+    }
+
+    /// Returns `true` if the applicant was rejected (`RED`) but may resubmit
+    /// documents (`RETRY`).
+    pub fn retry_allowed(&self) -> bool {
+        self.review_answer == "RED" && self.review_reject_type.as_deref() == Some("RETRY")
+    }
 }
 
 #[derive(Deserialize, Debug)]
@@ -41,9 +138,9 @@ pub struct ModerationState {
     pub created_at: String,
     pub client_id: String,
     pub inspection_id: String,
-    pub applicant_id: String,
+    pub applicant_id: ApplicantId,
     pub level_name: String,
-    pub external_user_id: Option<String>,
+    pub external_user_id: Option<ExternalUserId>,
     pub info: Option<serde_json::Value>, // Can be complex, using Value for now
     pub moderation: Option<ModerationDetails>,
 }
@@ -67,11 +164,30 @@ pub struct BlacklistRequest {
     pub note: String,
 }
 
+/// The acknowledgement of an operation that Sumsub performs asynchronously,
+/// e.g., a re-check or an AML check.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationResult {
+    /// `1` if the operation was accepted. Absent if the API sent no body.
+    pub ok: Option<i32>,
+    /// Any further fields of the response, e.g., the IDs of queued checks.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+impl OperationResult {
+    /// Returns `true` unless the API explicitly reported a failure.
+    pub fn is_ok(&self) -> bool {
+        self.ok.is_none_or(|ok| ok == 1)
+    }
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ShareTokenRequest<'a> {
     pub level_name: &'a str,
-    pub external_user_id: &'a str,
+    pub external_user_id: &'a ExternalUserId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ttl_in_secs: Option<u64>,
 }
@@ -91,7 +207,7 @@ pub struct ImportApplicantRequest<'a> {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ImportApplicantResponse {
-    pub applicant_id: String,
+    pub applicant_id: ApplicantId,
     pub inspection_id: String,
 }
 
@@ -104,10 +220,43 @@ pub struct IngestCompletedRequest {
     pub doc_sets: Option<Vec<IngestDocSet>>,
 }
 
+/// Options for `Client::ingest_completed_applicants`.
+#[derive(Debug, Clone)]
+pub struct BulkIngestOptions {
+    /// The number of records submitted concurrently.
+    pub chunk_size: usize,
+    /// The number of times a record is retried after a transient error.
+    /// Defaults to 0, so that a record is never ingested twice.
+    pub max_retries: u32,
+    /// The delay before the first retry; doubled after each attempt.
+    pub retry_delay: std::time::Duration,
+}
+
+impl Default for BulkIngestOptions {
+    fn default() -> Self {
+        Self {
+            chunk_size: 10,
+            max_retries: 0,
+            retry_delay: std::time::Duration::from_millis(500),
+        }
+    }
+}
+
+/// The outcome of ingesting a single record with `Client::ingest_completed_applicants`.
+#[derive(Debug)]
+pub struct IngestOutcome {
+    /// The `externalUserId` of the ingested applicant.
+    pub external_user_id: ExternalUserId,
+    /// The number of attempts made, including the first one.
+    pub attempts: u32,
+    /// The result of the last attempt.
+    pub result: Result<(), crate::error::SumsubError>,
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct IngestApplicant {
-    pub external_user_id: String,
+    pub external_user_id: ExternalUserId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -162,6 +311,29 @@ pub struct ApplicantEvent {
     pub data: serde_json::Value,
 }
 
+/// Filters for listing applicant events.
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    /// Only include events created at or after this time (`YYYY-MM-DD HH:MM:SS`).
+    pub since: Option<String>,
+    /// Only include events created before this time (`YYYY-MM-DD HH:MM:SS`).
+    pub until: Option<String>,
+    /// Only include events of these types (e.g., `applicantReviewed`). All
+    /// types are included if empty.
+    pub event_types: Vec<String>,
+}
+
+impl EventFilter {
+    /// Appends the filter parameters to `query`.
+    pub(crate) fn apply(&self, query: Query) -> Query {
+        let event_types = (!self.event_types.is_empty()).then(|| self.event_types.join(","));
+        query
+            .opt_param("createdAtFrom", self.since.as_ref())
+            .opt_param("createdAtTo", self.until.as_ref())
+            .opt_param("eventTypes", event_types)
+    }
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ChangeApplicantDataRequest {
@@ -215,13 +387,13 @@ pub struct AddConsentsRequest<'a> {
     pub accepted: Vec<&'a str>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplicantFacingConsentsResponse {
     pub consents: Vec<ApplicantFacingConsent>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplicantFacingConsent {
     pub id: String,
@@ -238,30 +410,60 @@ pub struct ApplicantFacingConsent {
     pub description: String,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Note {
     pub id: String,
     pub created_at: String,
-    pub applicant_id: String,
+    pub applicant_id: ApplicantId,
     pub agent: Agent,
     pub note: String,
     pub attachments: Vec<Attachment>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Agent {
     pub client_id: String,
     pub email: String,
+    /// The ID of the agent (team member).
+    pub id: Option<String>,
+    pub name: Option<String>,
+    /// The role of the agent in the team, e.g., `compliance_officer`.
+    pub role: Option<String>,
 }
 
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Attachment {
     pub id: String,
     pub file_name: String,
     pub created_at: String,
+    /// The MIME type of the attachment, e.g., `application/pdf`.
+    pub content_type: Option<String>,
+    /// The size of the attachment in bytes.
+    pub size: Option<u64>,
+}
+
+/// Filters for listing applicant notes.
+#[derive(Debug, Clone, Default)]
+pub struct NoteFilter {
+    /// Only include notes created at or after this time (`YYYY-MM-DD HH:MM:SS`).
+    pub created_from: Option<String>,
+    /// Only include notes created before this time (`YYYY-MM-DD HH:MM:SS`).
+    pub created_to: Option<String>,
+    /// Only include notes written by the agent with this email.
+    pub agent_email: Option<String>,
+}
+
+impl NoteFilter {
+    /// Appends the filter parameters to `query`.
+    pub(crate) fn apply(&self, query: Query) -> Query {
+        query
+            .opt_param("createdAtFrom", self.created_from.as_ref())
+            .opt_param("createdAtTo", self.created_to.as_ref())
+            .opt_param("agentEmail", self.agent_email.as_ref())
+    }
 }
 
 #[derive(Serialize, Debug)]
@@ -283,6 +485,20 @@ pub struct VerificationStepStatus {
     pub check_type: String,
 }
 
+/// Everything a manual review screen needs about an applicant, as fetched by
+/// [`Client::get_review_bundle`](crate::client::Client::get_review_bundle).
+#[derive(Debug)]
+pub struct ReviewBundle {
+    pub status: ApplicantStatus,
+    /// All moderation states of the applicant, oldest first.
+    pub moderation_states: Vec<ModerationState>,
+    pub images: Vec<ImageInfo>,
+    /// The latest result of each requested check type. Check types the
+    /// applicant has no result for are omitted.
+    pub check_results: HashMap<crate::checks::CheckType, serde_json::Value>,
+    pub notes: Vec<Note>,
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ReviewHistoryRecord {
@@ -294,10 +510,50 @@ pub struct ReviewHistoryRecord {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ImageInfo {
-    pub image_id: String,
+    pub image_id: ImageId,
     pub inspection_id: String,
     pub id_doc_type: String,
     pub added_at: String,
+    /// The display orientation and crop of the image, if set.
+    pub transform: Option<ImageTransform>,
+    /// The size and hash of the uploaded file, if reported.
+    pub file_metadata: Option<FileMetadata>,
+}
+
+/// Metadata of an uploaded file, used to check the integrity of downloads
+/// (see [`crate::integrity`]).
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FileMetadata {
+    pub file_name: Option<String>,
+    /// The MIME type, e.g., `image/jpeg`.
+    pub file_type: Option<String>,
+    /// The size in bytes.
+    pub file_size: Option<u64>,
+    /// The hex-encoded SHA-256 hash of the content.
+    pub sha256: Option<String>,
+}
+
+/// How an image should be displayed: its rotation and crop area.
+///
+/// The original image is not modified.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageTransform {
+    /// Clockwise rotation in degrees: 0, 90, 180, or 270.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rotation: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crop: Option<CropArea>,
+}
+
+/// A crop area in pixels, relative to the top-left corner of the original image.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CropArea {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
 }
 
 #[derive(Deserialize, Debug)]
@@ -306,6 +562,25 @@ pub struct AmlData {
     pub applicant: AmlApplicant,
 }
 
+/// The format of an AML screening report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmlReportFormat {
+    /// A PDF document, as shown in the dashboard.
+    Pdf,
+    /// A JSON export of the screening and its hits.
+    Json,
+}
+
+impl AmlReportFormat {
+    /// The file extension of the report, e.g., `pdf`.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            AmlReportFormat::Pdf => "pdf",
+            AmlReportFormat::Json => "json",
+        }
+    }
+}
+
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct AmlApplicant {
@@ -352,7 +627,7 @@ pub struct AmlReview {
 #[serde(rename_all = "camelCase")]
 pub struct AmlMatchInfo {
     pub match_types: Vec<String>,
-    pub match_strength: f64,
+    pub match_strength: crate::models::Score,
 }
 
 #[derive(Serialize, Debug)]
@@ -373,3 +648,223 @@ pub struct DeactivateApplicantReview<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub moderation_comment: Option<&'a str>,
 }
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelAgreementsResponse {
+    pub agreements: Vec<LevelAgreement>,
+}
+
+/// Represents a terms of service or privacy policy document attached to a level.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelAgreement {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub agreement_type: String,
+    pub version: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    pub required: bool,
+    pub created_at: String,
+}
+
+/// Represents the agreements accepted by an applicant.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplicantAgreement {
+    pub created_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accepted_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    pub targets: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub record_ids: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordAgreementRequest<'a> {
+    pub targets: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accepted_at: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<&'a str>,
+}
+
+/// Represents the status of a single verification step, including per-image review results.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RequiredDocStepStatus {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review_result: Option<ReviewResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_doc_type: Option<String>,
+    #[serde(default)]
+    pub image_ids: Vec<ImageId>,
+    /// Review results keyed by image ID.
+    #[serde(default)]
+    pub image_review_results: HashMap<String, ReviewResult>,
+    #[serde(default)]
+    pub forbidden: bool,
+}
+
+impl RequiredDocStepStatus {
+    /// Returns the review result of a single image of this step, if it has been reviewed.
+    pub fn image_review_result(&self, image_id: &ImageId) -> Option<&ReviewResult> {
+        self.image_review_results.get(image_id.as_str())
+    }
+
+    /// Returns the IDs of the images of this step that were rejected and need to be retaken.
+    pub fn rejected_image_ids(&self) -> Vec<ImageId> {
+        self.image_ids
+            .iter()
+            .filter(|id| {
+                self.image_review_result(id)
+                    .is_some_and(|result| result.review_answer == "RED")
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+/// A document set of a verification level, e.g., the proof of address step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdDocSetType {
+    Identity,
+    Selfie,
+    ProofOfResidence,
+    /// Any other document set, by its `idDocSetType`.
+    Custom(String),
+}
+
+impl IdDocSetType {
+    /// Returns the `idDocSetType` used by the API.
+    pub fn as_str(&self) -> &str {
+        match self {
+            IdDocSetType::Identity => "IDENTITY",
+            IdDocSetType::Selfie => "SELFIE",
+            IdDocSetType::ProofOfResidence => "PROOF_OF_RESIDENCE",
+            IdDocSetType::Custom(doc_set_type) => doc_set_type,
+        }
+    }
+}
+
+/// A category of applicant data that can be deactivated without deleting the whole profile.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataCategory {
+    /// Selfies and liveness captures.
+    Selfie,
+    /// Proof of address documents such as utility bills and bank statements.
+    ProofOfAddress,
+    /// Identity documents such as passports, ID cards, and driver's licenses.
+    IdentityDocument,
+    /// Any other document type, matched by its `idDocType`.
+    Custom(String),
+}
+
+impl DataCategory {
+    /// Returns `true` if an image with the given `idDocType` belongs to this category.
+    pub fn matches(&self, id_doc_type: &str) -> bool {
+        match self {
+            DataCategory::Selfie => matches!(id_doc_type, "SELFIE" | "VIDEO_SELFIE"),
+            DataCategory::ProofOfAddress => matches!(
+                id_doc_type,
+                "UTILITY_BILL" | "UTILITY_BILL2" | "BANK_STATEMENT" | "PROOF_OF_RESIDENCE"
+            ),
+            DataCategory::IdentityDocument => matches!(
+                id_doc_type,
+                "PASSPORT" | "ID_CARD" | "DRIVERS" | "RESIDENCE_PERMIT"
+            ),
+            DataCategory::Custom(doc_type) => doc_type == id_doc_type,
+        }
+    }
+}
+
+/// The outcome of deactivating a single image, as returned by
+/// [`Client::deactivate_applicant_data`](crate::client::Client::deactivate_applicant_data).
+#[derive(Debug)]
+pub struct ImageDeactivation {
+    pub image_id: ImageId,
+    pub id_doc_type: String,
+    pub result: Result<(), crate::error::SumsubError>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct IdDocOcrResponse {
+    pub items: Vec<IdDocOcrResult>,
+}
+
+/// The data extracted by OCR from a single identity document image.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct IdDocOcrResult {
+    pub image_id: ImageId,
+    pub id_doc_type: String,
+    pub country: Option<String>,
+    pub fields: IdDocFields,
+    /// The raw machine-readable zone, if the document has one.
+    pub mrz: Option<MrzLines>,
+}
+
+/// The fields extracted from an identity document.
+///
+/// Well-known fields are typed; any other extracted fields are kept in `extras`.
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct IdDocFields {
+    pub first_name: Option<String>,
+    pub middle_name: Option<String>,
+    pub last_name: Option<String>,
+    /// The date of birth in `YYYY-MM-DD` format.
+    pub dob: Option<String>,
+    pub place_of_birth: Option<String>,
+    pub gender: Option<String>,
+    pub nationality: Option<String>,
+    pub number: Option<String>,
+    pub issued_date: Option<String>,
+    pub valid_until: Option<String>,
+    #[serde(flatten)]
+    pub extras: HashMap<String, serde_json::Value>,
+}
+
+impl IdDocFields {
+    /// Compares the extracted fields with the data provided by the applicant
+    /// and returns the names of the fields that differ.
+    ///
+    /// Names are compared case-insensitively. Fields missing on either side
+    /// are not reported.
+    pub fn mismatches(&self, provided: &crate::models::FixedInfo) -> Vec<&'static str> {
+        let pairs = [
+            ("firstName", &self.first_name, &provided.first_name, true),
+            ("lastName", &self.last_name, &provided.last_name, true),
+            ("dob", &self.dob, &provided.dob, false),
+            ("placeOfBirth", &self.place_of_birth, &provided.place_of_birth, true),
+        ];
+        pairs
+            .into_iter()
+            .filter_map(|(name, extracted, provided, ignore_case)| {
+                let (extracted, provided) = (extracted.as_deref()?, provided.as_deref()?);
+                let equal = if ignore_case {
+                    extracted.trim().eq_ignore_ascii_case(provided.trim())
+                } else {
+                    extracted == provided
+                };
+                (!equal).then_some(name)
+            })
+            .collect()
+    }
+}
+
+/// The lines of a machine-readable zone.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MrzLines {
+    pub line1: Option<String>,
+    pub line2: Option<String>,
+    pub line3: Option<String>,
+}
@@ -0,0 +1,294 @@
+// src/archive.rs
+
+//! This module contains utilities for working with applicant archives, such
+//! as building the archive expected by `Client::import_applicant_profile_from_archive`
+//! and reading the entries of verification ZIP reports.
+
+use crate::applicants::IngestApplicant;
+use crate::error::SumsubError;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::io::{Cursor, Read, Seek, Write};
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+/// The name of the manifest file at the root of the archive.
+const MANIFEST_FILE: &str = "applicant.json";
+
+/// The directory of the archive that holds document files.
+const DOCUMENTS_DIR: &str = "documents";
+
+/// Describes a document file included in an applicant archive.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveDocument {
+    pub id_doc_type: String,
+    pub country: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_doc_sub_type: Option<String>,
+    /// The name of the file inside the `documents` directory of the archive.
+    /// Must be a plain file name, without path separators or `..`.
+    pub file_name: String,
+}
+
+/// The `applicant.json` manifest of an applicant archive.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchiveManifest {
+    pub applicant: IngestApplicant,
+    pub documents: Vec<ArchiveDocument>,
+}
+
+/// Assembles an applicant archive from typed data.
+///
+/// The archive contains an `applicant.json` manifest and one file per
+/// document under `documents/`.
+///
+/// # Example
+///
+/// ```
+/// use sumsub_api::applicants::IngestApplicant;
+/// use sumsub_api::archive::{ArchiveBuilder, ArchiveDocument};
+///
+/// let applicant = IngestApplicant {
+///     external_user_id: "user-123".into(),
+///     email: None,
+///     phone: None,
+///     info: None,
+/// };
+/// let document = ArchiveDocument {
+///     id_doc_type: "PASSPORT".to_string(),
+///     country: "GBR".to_string(),
+///     id_doc_sub_type: None,
+///     file_name: "passport.jpg".to_string(),
+/// };
+/// let zip = ArchiveBuilder::new(applicant)
+///     .document(document, vec![0xFF, 0xD8, 0xFF])
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct ArchiveBuilder {
+    applicant: IngestApplicant,
+    documents: Vec<(ArchiveDocument, Vec<u8>)>,
+}
+
+impl ArchiveBuilder {
+    /// Creates a builder for the archive of the given applicant.
+    pub fn new(applicant: IngestApplicant) -> Self {
+        Self {
+            applicant,
+            documents: Vec::new(),
+        }
+    }
+
+    /// Adds a document file to the archive.
+    pub fn document(mut self, document: ArchiveDocument, content: Vec<u8>) -> Self {
+        self.documents.push((document, content));
+        self
+    }
+
+    /// Builds the archive in memory and returns the zip bytes.
+    pub fn build(self) -> Result<Vec<u8>, SumsubError> {
+        let cursor = self.write_to(Cursor::new(Vec::new()))?;
+        Ok(cursor.into_inner())
+    }
+
+    /// Writes the archive to `writer` (e.g., a file on disk) and returns the writer.
+    pub fn write_to<W: Write + Seek>(self, writer: W) -> Result<W, SumsubError> {
+        let mut file_names = HashSet::new();
+        for (document, _) in &self.documents {
+            let file_name = document.file_name.as_str();
+            if file_name.is_empty()
+                || file_name.contains(['/', '\\'])
+                || file_name.contains("..")
+            {
+                return Err(SumsubError::Archive(format!(
+                    "document file name is not a plain file name: {}",
+                    document.file_name
+                )));
+            }
+            if !file_names.insert(file_name) {
+                return Err(SumsubError::Archive(format!(
+                    "duplicate document file name: {}",
+                    document.file_name
+                )));
+            }
+        }
+
+        let (documents, contents): (Vec<_>, Vec<_>) = self.documents.into_iter().unzip();
+        let manifest = ArchiveManifest {
+            applicant: self.applicant,
+            documents,
+        };
+
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        let mut zip = ZipWriter::new(writer);
+
+        zip.start_file(MANIFEST_FILE, options).map_err(archive_error)?;
+        serde_json::to_writer_pretty(&mut zip, &manifest)?;
+
+        for (document, content) in manifest.documents.iter().zip(contents) {
+            zip.start_file(format!("{}/{}", DOCUMENTS_DIR, document.file_name), options)
+                .map_err(archive_error)?;
+            zip.write_all(&content)?;
+        }
+
+        zip.finish().map_err(archive_error)
+    }
+}
+
+/// An entry of a verification ZIP report (see
+/// `Client::get_verification_zip_report`), typed by its file extension.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReportEntry {
+    /// A JSON file, e.g., the applicant data or check results.
+    Json {
+        name: String,
+        value: serde_json::Value,
+    },
+    /// An image, e.g., a document page or a selfie.
+    Image {
+        name: String,
+        /// The MIME type derived from the extension, e.g., `image/jpeg`.
+        mime_type: &'static str,
+        content: Vec<u8>,
+    },
+    /// A PDF document, e.g., the verification report itself.
+    Pdf { name: String, content: Vec<u8> },
+    /// Any other file.
+    Other { name: String, content: Vec<u8> },
+}
+
+impl ReportEntry {
+    /// The path of the entry inside the archive.
+    pub fn name(&self) -> &str {
+        match self {
+            ReportEntry::Json { name, .. }
+            | ReportEntry::Image { name, .. }
+            | ReportEntry::Pdf { name, .. }
+            | ReportEntry::Other { name, .. } => name,
+        }
+    }
+
+    fn from_file(name: String, content: Vec<u8>) -> Result<Self, SumsubError> {
+        let extension = name
+            .rsplit_once('.')
+            .map(|(_, extension)| extension.to_ascii_lowercase())
+            .unwrap_or_default();
+        let image_type = match extension.as_str() {
+            "jpg" | "jpeg" => Some("image/jpeg"),
+            "png" => Some("image/png"),
+            "gif" => Some("image/gif"),
+            "webp" => Some("image/webp"),
+            "heic" => Some("image/heic"),
+            _ => None,
+        };
+        Ok(match (extension.as_str(), image_type) {
+            ("json", _) => ReportEntry::Json {
+                value: serde_json::from_slice(&content)?,
+                name,
+            },
+            (_, Some(mime_type)) => ReportEntry::Image { name, mime_type, content },
+            ("pdf", _) => ReportEntry::Pdf { name, content },
+            _ => ReportEntry::Other { name, content },
+        })
+    }
+}
+
+/// Iterates the files of a verification ZIP report as typed entries, one
+/// at a time, skipping directories.
+///
+/// # Example
+///
+/// ```no_run
+/// use sumsub_api::archive::{ReportEntries, ReportEntry};
+/// use sumsub_api::client::Client;
+///
+/// # async fn run(client: Client) -> Result<(), sumsub_api::error::SumsubError> {
+/// let zip = client.get_verification_zip_report(&"applicant_id".into()).await?;
+/// for entry in ReportEntries::from_bytes(zip)? {
+///     if let ReportEntry::Image { name, content, .. } = entry? {
+///         std::fs::write(name.replace('/', "_"), content)?;
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ReportEntries<R> {
+    archive: ZipArchive<R>,
+    index: usize,
+}
+
+impl ReportEntries<Cursor<Vec<u8>>> {
+    /// Reads a report held in memory.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, SumsubError> {
+        Self::new(Cursor::new(bytes))
+    }
+
+    /// Reads a report from a stream, e.g., `Client::get_verification_zip_report_stream`.
+    /// The ZIP format keeps its index at the end, so the stream is read fully first.
+    pub async fn from_stream(stream: crate::response::ByteStream) -> Result<Self, SumsubError> {
+        use futures::TryStreamExt;
+
+        let bytes = stream
+            .try_fold(Vec::new(), |mut bytes, chunk| async move {
+                bytes.extend_from_slice(&chunk);
+                Ok(bytes)
+            })
+            .await?;
+        Self::from_bytes(bytes)
+    }
+}
+
+impl<R: Read + Seek> ReportEntries<R> {
+    /// Reads a report from `reader`, e.g., a file on disk.
+    pub fn new(reader: R) -> Result<Self, SumsubError> {
+        Ok(Self {
+            archive: ZipArchive::new(reader).map_err(archive_error)?,
+            index: 0,
+        })
+    }
+
+    /// The number of files and directories in the archive.
+    pub fn len(&self) -> usize {
+        self.archive.len()
+    }
+
+    /// Returns `true` if the archive is empty.
+    pub fn is_empty(&self) -> bool {
+        self.archive.is_empty()
+    }
+}
+
+impl<R: Read + Seek> Iterator for ReportEntries<R> {
+    type Item = Result<ReportEntry, SumsubError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.archive.len() {
+            let index = self.index;
+            self.index += 1;
+            let mut file = match self.archive.by_index(index) {
+                Ok(file) => file,
+                Err(e) => return Some(Err(archive_error(e))),
+            };
+            if file.is_dir() {
+                continue;
+            }
+            let name = file.name().to_string();
+            // The declared size comes from the archive and is not trusted
+            // for pre-allocation.
+            let mut content = Vec::new();
+            if let Err(e) = file.read_to_end(&mut content) {
+                return Some(Err(e.into()));
+            }
+            return Some(ReportEntry::from_file(name, content));
+        }
+        None
+    }
+}
+
+fn archive_error(e: zip::result::ZipError) -> SumsubError {
+    SumsubError::Archive(e.to_string())
+}
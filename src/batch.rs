@@ -0,0 +1,41 @@
+// src/batch.rs
+
+//! This module contains the options and outcomes of `Client::batch`, which
+//! runs many requests with bounded concurrency.
+
+use crate::error::SumsubError;
+use std::time::Duration;
+
+/// Options for `Client::batch`.
+#[derive(Debug, Clone)]
+pub struct BatchOptions {
+    /// The maximum number of items processed concurrently.
+    pub concurrency: usize,
+    /// The number of times an item is retried after a transient error.
+    /// Defaults to 0: `f` may not be safe to repeat, e.g., if it creates
+    /// records.
+    pub max_retries: u32,
+    /// The delay before the first retry; doubled after each attempt.
+    pub retry_delay: Duration,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self {
+            concurrency: 8,
+            max_retries: 0,
+            retry_delay: Duration::from_millis(500),
+        }
+    }
+}
+
+/// The outcome of processing a single item with `Client::batch`.
+#[derive(Debug)]
+pub struct BatchOutcome<I, T> {
+    /// The item that was processed.
+    pub item: I,
+    /// The number of attempts made, including the first one.
+    pub attempts: u32,
+    /// The result of the last attempt.
+    pub result: Result<T, SumsubError>,
+}
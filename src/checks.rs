@@ -2,9 +2,10 @@
 
 //! This module will contain the data structures for the "Checks" section of the Sumsub API.
 
+use crate::ids::ApplicantId;
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CheckType {
     #[serde(rename = "POA")]
     Poa,
@@ -24,21 +25,28 @@ pub enum CheckType {
     IpCheck,
     #[serde(rename = "NFC")]
     Nfc,
-}
-
-impl ToString for CheckType {
-    fn to_string(&self) -> String {
-        match self {
-            CheckType::Poa => "POA".to_string(),
-            CheckType::SimilarSearch => "SIMILAR_SEARCH".to_string(),
-            CheckType::Tin => "TIN".to_string(),
-            CheckType::Company => "COMPANY".to_string(),
-            CheckType::BankCard => "BANK_CARD".to_string(),
-            CheckType::EmailConfirmation => "EMAIL_CONFIRMATION".to_string(),
-            CheckType::PhoneConfirmation => "PHONE_CONFIRMATION".to_string(),
-            CheckType::IpCheck => "IP_CHECK".to_string(),
-            CheckType::Nfc => "NFC".to_string(),
-        }
+    #[serde(rename = "FACE_MATCH")]
+    FaceMatch,
+    #[serde(rename = "LIVENESS")]
+    Liveness,
+}
+
+impl std::fmt::Display for CheckType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            CheckType::Poa => "POA",
+            CheckType::SimilarSearch => "SIMILAR_SEARCH",
+            CheckType::Tin => "TIN",
+            CheckType::Company => "COMPANY",
+            CheckType::BankCard => "BANK_CARD",
+            CheckType::EmailConfirmation => "EMAIL_CONFIRMATION",
+            CheckType::PhoneConfirmation => "PHONE_CONFIRMATION",
+            CheckType::IpCheck => "IP_CHECK",
+            CheckType::Nfc => "NFC",
+            CheckType::FaceMatch => "FACE_MATCH",
+            CheckType::Liveness => "LIVENESS",
+        };
+        f.write_str(s)
     }
 }
 
@@ -90,7 +98,7 @@ pub struct DocQualityMetrics {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct BankCardCheckResult {
-    pub applicant_id: String,
+    pub applicant_id: ApplicantId,
     pub card_holder: String,
     pub card_number_mask: String,
 }
@@ -99,7 +107,7 @@ pub struct BankCardCheckResult {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct EmailConfirmationCheckResult {
-    pub applicant_id: String,
+    pub applicant_id: ApplicantId,
     pub email: String,
     pub confirmed: bool,
 }
@@ -108,7 +116,7 @@ pub struct EmailConfirmationCheckResult {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct PhoneConfirmationCheckResult {
-    pub applicant_id: String,
+    pub applicant_id: ApplicantId,
     pub phone: String,
     pub confirmed: bool,
 }
@@ -117,7 +125,7 @@ pub struct PhoneConfirmationCheckResult {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct IpCheckResult {
-    pub applicant_id: String,
+    pub applicant_id: ApplicantId,
     // ... fields based on documentation
 }
 
@@ -125,16 +133,61 @@ pub struct IpCheckResult {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct NfcCheckResult {
-    pub applicant_id: String,
+    pub applicant_id: ApplicantId,
     pub phone: String,
     pub confirmed: bool,
 }
 
+// For GET /resources/checks/latest?type=FACE_MATCH
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FaceMatchCheckResult {
+    pub applicant_id: ApplicantId,
+    /// "GREEN" if the selfie matches the document photo, "RED" otherwise.
+    pub answer: String,
+    /// The similarity between the selfie and the document photo, from 0.0 to 1.0.
+    pub similarity: f64,
+    /// The similarity threshold applied for the decision.
+    pub threshold: Option<f64>,
+    pub reject_labels: Option<Vec<String>>,
+    /// The image IDs of the compared faces.
+    pub compared_image_ids: Option<Vec<String>>,
+}
+
+impl FaceMatchCheckResult {
+    /// Returns `true` if the faces were found to match.
+    pub fn is_match(&self) -> bool {
+        self.answer == "GREEN"
+    }
+}
+
+// For GET /resources/checks/latest?type=LIVENESS
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LivenessCheckResult {
+    pub applicant_id: ApplicantId,
+    /// "GREEN" if the applicant was found to be a live person, "RED" otherwise.
+    pub answer: String,
+    /// The liveness score, from 0.0 to 1.0.
+    pub score: f64,
+    pub reject_labels: Option<Vec<String>>,
+    /// Detected spoofing attempts (e.g., "PRINTED_PHOTO", "SCREEN_REPLAY").
+    #[serde(default)]
+    pub spoof_attempts: Vec<String>,
+    pub device_platform: Option<String>,
+}
+
+impl LivenessCheckResult {
+    /// Returns `true` if the applicant passed the liveness check.
+    pub fn is_live(&self) -> bool {
+        self.answer == "GREEN"
+    }
+}
 
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct StartCheckRequest<'a> {
-    pub applicant_id: &'a str,
+    pub applicant_id: &'a ApplicantId,
     pub check_type: CheckType,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub address_info: Option<AddressInfo>,
@@ -163,14 +216,14 @@ pub struct AddressInfo {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct SimilarSearchResult {
-    pub applicant_id: String,
+    pub applicant_id: ApplicantId,
     pub similar_applicants: Vec<SimilarApplicant>,
 }
 
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct SimilarApplicant {
-    pub id: String,
+    pub id: ApplicantId,
     pub match_type: String,
     pub review_answer: String,
 }
@@ -179,7 +232,7 @@ pub struct SimilarApplicant {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct TinCheckResult {
-    pub applicant_id: String,
+    pub applicant_id: ApplicantId,
     pub ssn_status: String,
     pub validation_details: Option<String>,
 }
@@ -0,0 +1,71 @@
+// src/circuit_breaker.rs
+
+//! This module contains the circuit breaker used by the `Client` to fail fast
+//! while the Sumsub API is unavailable.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A circuit breaker that opens after `failure_threshold` consecutive
+/// failures and rejects requests for `open_duration`.
+///
+/// Once that time has passed, the circuit is half-open: a single trial
+/// request is let through, and its outcome closes or reopens the circuit.
+/// If the trial never reports back (e.g., it was cancelled), another one is
+/// let through after a further `open_duration`.
+#[derive(Debug)]
+pub(crate) struct CircuitBreaker {
+    failure_threshold: u32,
+    open_duration: Duration,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+enum State {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    HalfOpen { trial_started: Instant },
+}
+
+impl CircuitBreaker {
+    /// Creates a closed circuit. `failure_threshold` must be at least 1.
+    pub(crate) fn new(failure_threshold: u32, open_duration: Duration) -> Self {
+        Self {
+            failure_threshold,
+            open_duration,
+            state: Mutex::new(State::Closed { failures: 0 }),
+        }
+    }
+
+    /// Checks whether a request may be sent. Returns how long until the
+    /// next trial request if the circuit is open.
+    pub(crate) fn acquire(&self) -> Result<(), Duration> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        match *state {
+            State::Closed { .. } => Ok(()),
+            State::Open { until } if now < until => Err(until - now),
+            State::HalfOpen { trial_started } if now < trial_started + self.open_duration => {
+                Err(trial_started + self.open_duration - now)
+            }
+            State::Open { .. } | State::HalfOpen { .. } => {
+                *state = State::HalfOpen { trial_started: now };
+                Ok(())
+            }
+        }
+    }
+
+    /// Records the outcome of a request let through by `acquire`.
+    pub(crate) fn record(&self, success: bool) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        *state = match (&*state, success) {
+            (_, true) => State::Closed { failures: 0 },
+            (State::Closed { failures }, false) if failures + 1 < self.failure_threshold => {
+                State::Closed { failures: failures + 1 }
+            }
+            // A request sent before the circuit opened does not extend it.
+            (State::Open { until }, false) => State::Open { until: *until },
+            (_, false) => State::Open { until: Instant::now() + self.open_duration },
+        };
+    }
+}
@@ -4,14 +4,27 @@
 //! with the Sumsub API. It handles request signing and sending requests to the
 //! API endpoints.
 
-use hmac::{Hmac, Mac};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, ACCEPT_LANGUAGE, CONTENT_TYPE};
 use reqwest::Method;
+use secrecy::{ExposeSecret, SecretString};
 use serde::Serialize;
-use sha2::Sha256;
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use crate::error::SumsubError;
+use crate::hooks::{is_auth_header, HookRequest, HookResponse, Hooks, RequestHook};
+use crate::metrics::{endpoint_name, Metrics, MetricsSink, RequestMetrics};
+use crate::options::RequestOptions;
+use crate::policy::{EndpointClass, EndpointPolicy, Policies};
+use crate::multipart::MultipartForm;
+use crate::query::Query;
+use crate::rate_limit::RateLimiter;
+use crate::circuit_breaker::CircuitBreaker;
+use crate::signing::{sign_request, Clock, SharedClock};
+use crate::tags::{unknown_tags, TagCache, TagKind};
+use crate::ids::{ActionId, ApplicantId, ExternalUserId, ImageId, TxnId};
 use crate::models::{Applicant, CreateApplicantRequest, FixedInfo};
-use crate::misc::{ApiHealthStatus, AuditTrailEvent, NewApplicantAccessTokenResponse, SendVerificationMessageRequest, AvailableLevel};
+use crate::misc::{ApiHealthStatus, AuditTrailEvent, GenerateWebsdkLinkRequest, GenerateWebsdkLinkResponse, NewApplicantAccessTokenResponse, SendVerificationMessageRequest, AvailableLevel};
 use crate::actions::{ApplicantAction, CreateApplicantActionRequest, GetApplicantActionsResponse, Questionnaire, RequestActionCheckResponse};
 use crate::kyb::{CompanyInfo, GetAdditionalCompanyCheckDataResponse, LinkBeneficiaryRequest};
 use crate::transactions::{BulkTransactionImportRequest, BulkTransactionImportResponse, DeleteTransactionResponse, SubmitTransactionRequest, SubmitTransactionResponse};
@@ -21,57 +34,597 @@ use crate::checks::*;
 use serde::Deserialize;
 use urlencoding;
 
-
-type HmacSha256 = Hmac<Sha256>;
-
 const BASE_URL: &str = "https://api.sumsub.com";
 
-/// Signs a request to the Sumsub API.
-///
-/// This is a private function that generates the `X-App-Access-Sig` header
-/// value.
-///
-/// # Arguments
+/// The Sumsub environment a `Client` talks to.
 ///
-/// * `secret_key` - The secret key for the app token.
-/// * `ts` - The timestamp of the request.
-/// * `method` - The HTTP method of the request (e.g., "POST").
-/// * `path` - The path of the request, including the query string.
-/// * `body` - The body of the request.
-///
-/// # Returns
-///
-/// A hex-encoded signature.
-fn sign_request(
-    secret_key: &str,
-    ts: u64,
-    method: &str,
-    path: &str,
-    body: &Option<String>,
-) -> String {
-    let mut mac =
-        HmacSha256::new_from_slice(secret_key.as_bytes()).expect("HMAC can take key of any size");
-    mac.update(ts.to_string().as_bytes());
-    mac.update(method.as_bytes());
-    mac.update(path.as_bytes());
-    if let Some(body) = body {
-        mac.update(body.as_bytes());
-    }
-
-    let result = mac.finalize();
-    let code_bytes = result.into_bytes();
-    hex::encode(code_bytes)
+/// Sandbox and production applicants both live on [`Environment::Production`];
+/// which one a request affects is decided by the app token (`sbx:` tokens
+/// belong to the sandbox).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Environment {
+    /// `https://api.sumsub.com`.
+    #[default]
+    Production,
+    /// Any other base URL, e.g., a proxy or a mock server.
+    Custom(String),
+}
+
+impl Environment {
+    /// The base URL of the API in this environment.
+    pub fn base_url(&self) -> &str {
+        match self {
+            Environment::Production => BASE_URL,
+            Environment::Custom(base_url) => base_url,
+        }
+    }
+}
+
+/// The number of records requested per page by the auto-paging helpers.
+const DEFAULT_PAGE_SIZE: u32 = 100;
+
+/// The number of persons screened concurrently by `screen_company_persons`.
+const PERSON_SCREENING_CONCURRENCY: usize = 8;
+
+/// The number of beneficiary statuses fetched concurrently by
+/// `get_beneficiary_readiness`.
+const BENEFICIARY_STATUS_CONCURRENCY: usize = 8;
+
+/// The number of document images downloaded concurrently by
+/// `export_applicant_bundle`.
+const BUNDLE_IMAGE_CONCURRENCY: usize = 4;
+
+/// The maximum number of redirects followed for a single request.
+const MAX_REDIRECTS: usize = 10;
+
+/// The difference in seconds between the server clock and the corrected
+/// local clock above which a rejected request is re-signed.
+const CLOCK_SKEW_TOLERANCE_SECS: i64 = 5;
+
+/// The body of a request sent by the client.
+enum RequestBody {
+    Empty,
+    Json(String),
+    NdJson(String),
+    Multipart(crate::multipart::MultipartBody),
+}
+
+impl RequestBody {
+    /// Returns the body as text, if it is textual.
+    fn text(&self) -> Option<&str> {
+        match self {
+            RequestBody::Json(body) | RequestBody::NdJson(body) => Some(body),
+            RequestBody::Empty | RequestBody::Multipart(_) => None,
+        }
+    }
+
+    /// Returns the raw bytes the request is signed over.
+    fn bytes(&self) -> Option<&[u8]> {
+        match self {
+            RequestBody::Empty => None,
+            RequestBody::Json(body) | RequestBody::NdJson(body) => Some(body.as_bytes()),
+            RequestBody::Multipart(body) => Some(&body.bytes),
+        }
+    }
+
+    /// Returns a copy of the body for retrying the request.
+    fn try_clone(&self) -> Option<Self> {
+        match self {
+            RequestBody::Empty => Some(RequestBody::Empty),
+            RequestBody::Json(body) => Some(RequestBody::Json(body.clone())),
+            RequestBody::NdJson(body) => Some(RequestBody::NdJson(body.clone())),
+            RequestBody::Multipart(body) => Some(RequestBody::Multipart(body.clone())),
+        }
+    }
+
+    /// Returns the content type to send with the body.
+    fn content_type(&self) -> Option<HeaderValue> {
+        match self {
+            RequestBody::Json(_) => Some(HeaderValue::from_static("application/json")),
+            RequestBody::NdJson(_) => Some(HeaderValue::from_static("application/x-ndjson")),
+            RequestBody::Multipart(body) => Some(body.content_type.clone()),
+            RequestBody::Empty => None,
+        }
+    }
+}
+
+/// Creates the HTTP client used when none is injected. Redirects are not
+/// followed automatically, so that `Client` can decide which headers to send
+/// to the redirect target. With the `rustls` feature, rustls is used even if
+/// the `native-tls` feature is enabled too.
+pub(crate) fn default_http_client() -> reqwest::ClientBuilder {
+    let builder = reqwest::Client::builder().redirect(reqwest::redirect::Policy::none());
+    #[cfg(feature = "rustls")]
+    let builder = builder.use_rustls_tls();
+    builder
+}
+
+/// Checks that a credential is non-empty and can be sent as a header value.
+/// The value itself is never included in the error.
+fn validate_credential(name: &str, value: &str) -> Result<(), SumsubError> {
+    if value.trim().is_empty() {
+        return Err(SumsubError::Configuration(format!("the {} is empty", name)));
+    }
+    if !value.chars().all(|c| c.is_ascii_graphic()) {
+        return Err(SumsubError::Configuration(format!(
+            "the {} contains whitespace or non-ASCII characters",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Reads a required environment variable for `Client::from_env`.
+fn required_env(name: &str) -> Result<String, SumsubError> {
+    std::env::var(name).map_err(|e| SumsubError::Configuration(format!("{}: {}", name, e)))
+}
+
+/// Converts a time into seconds since the Unix epoch.
+fn unix_secs(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_secs() as i64,
+        Err(e) => -(e.duration().as_secs() as i64),
+    }
+}
+
+/// Converts a string into a header value.
+fn header_value(value: &str) -> Result<HeaderValue, SumsubError> {
+    HeaderValue::from_str(value).map_err(|e| SumsubError::InvalidHeader(e.to_string()))
+}
+
+/// Converts a credential into a header value that is redacted in the `Debug`
+/// output of `reqwest` and `hyper`.
+fn sensitive_header_value(value: &str) -> Result<HeaderValue, SumsubError> {
+    let mut value = header_value(value)?;
+    value.set_sensitive(true);
+    Ok(value)
+}
+
+/// Reads the correlation ID header of a response.
+fn correlation_id(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get(crate::response::CORRELATION_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Collects the diagnostics of a response from its URL and headers, before
+/// its body is read.
+fn response_diagnostics(response: &reqwest::Response) -> crate::response::ApiResponse<()> {
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.trim().to_string())
+    };
+    crate::response::ApiResponse {
+        data: (),
+        warnings: Vec::new(),
+        correlation_id: correlation_id(response),
+        url: response.url().to_string(),
+        request_id: header(crate::response::REQUEST_ID_HEADER),
+        rate_limit: crate::response::RateLimitStatus {
+            limit: header("x-ratelimit-limit").and_then(|value| value.parse().ok()),
+            remaining: header("x-ratelimit-remaining").and_then(|value| value.parse().ok()),
+            reset_secs: header("x-ratelimit-reset").and_then(|value| value.parse().ok()),
+        },
+    }
+}
+
+/// Reads the `Retry-After` header of a response, given either in seconds or
+/// as an HTTP date. A date in the past yields a zero duration.
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    let value = response.headers().get(reqwest::header::RETRY_AFTER)?.to_str().ok()?.trim();
+    match value.parse() {
+        Ok(seconds) => Some(Duration::from_secs(seconds)),
+        Err(_) => {
+            let date = httpdate::parse_http_date(value).ok()?;
+            Some(date.duration_since(SystemTime::now()).unwrap_or(Duration::ZERO))
+        }
+    }
+}
+
+/// Returns `true` unless the response declares a non-JSON content type,
+/// e.g., `text/html`. A missing content type is assumed to be JSON.
+fn is_json(response: &reqwest::Response) -> bool {
+    let Some(content_type) = response
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return true;
+    };
+    let essence = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+    essence == "application/json" || essence.ends_with("+json")
+}
+
+/// Reads the body of a failed response into a `SumsubError`. A non-JSON
+/// body yields `Unavailable`, except for rate limiting (429), which keeps
+/// its `Retry-After` delay.
+async fn api_error(response: reqwest::Response) -> SumsubError {
+    let status = response.status().as_u16();
+    let retry_after = retry_after(&response);
+    let json = is_json(&response);
+    let body = response
+        .text()
+        .await
+        .unwrap_or_else(|_| "Could not read error body".to_string());
+    if !json && status != 429 {
+        return SumsubError::unavailable(status, &body);
+    }
+    SumsubError::from_response(status, retry_after, body)
+}
+
+/// Creates a `SumsubError::Decode` from a body that failed to deserialize.
+fn decode_error(bytes: &[u8], source: serde_path_to_error::Error<serde_json::Error>) -> SumsubError {
+    SumsubError::Decode {
+        raw_body: String::from_utf8_lossy(bytes).into_owned(),
+        source,
+    }
+}
+
+/// Reads the body of a successful response that should be JSON, mapping a
+/// non-JSON body to `Unavailable` instead of a deserialization error.
+async fn json_bytes(response: reqwest::Response) -> Result<bytes::Bytes, SumsubError> {
+    let status = response.status().as_u16();
+    let json = is_json(&response);
+    let bytes = response.bytes().await?;
+    if !json {
+        return Err(SumsubError::unavailable(status, &String::from_utf8_lossy(&bytes)));
+    }
+    Ok(bytes)
+}
+
+/// Extracts the ID of the existing applicant from the body of a 409 response
+/// to a create applicant request, e.g.,
+/// `{"description": "Applicant with external user id 'user-1' already exists: 5cb56e8e0a975a35f333cb83", ...}`.
+fn existing_applicant_id(body: &str) -> Option<ApplicantId> {
+    let description = serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|value| value.get("description")?.as_str().map(str::to_string))
+        .unwrap_or_else(|| body.to_string());
+    let (_, rest) = description.split_once("already exists: ")?;
+    let id: String = rest.chars().take_while(char::is_ascii_alphanumeric).collect();
+    (!id.is_empty()).then(|| ApplicantId::from(id))
+}
+
+/// Extracts the applicant ID and transaction ID from a request path, e.g.,
+/// `/resources/applicants/{applicantId}/...` or `/resources/kyt/txns/{txnId}/...`.
+#[cfg(feature = "tracing")]
+fn path_ids(path: &str) -> (Option<&str>, Option<&str>) {
+    let path = path.split('?').next().unwrap_or(path);
+    let segment_after = |prefix: &str| {
+        path.strip_prefix(prefix)
+            .and_then(|rest| rest.split('/').next())
+            .filter(|id| !id.is_empty() && *id != "-" && !id.starts_with("-;"))
+    };
+    let txn_id = segment_after("/resources/kyt/txns/")
+        .filter(|id| !matches!(*id, "search" | "info"));
+    (segment_after("/resources/applicants/"), txn_id)
 }
 
 /// A client for the Sumsub API.
-#[derive(Debug)]
+///
+/// `Client` is cheap to clone: all clones share the same credentials and
+/// connection pool. It is `Send + Sync`, so it can be stored directly in
+/// application state (e.g., axum or actix-web) and shared across handlers
+/// without wrapping it in an `Arc`.
+#[derive(Debug, Clone)]
 pub struct Client {
-    app_token: String,
-    secret_key: String,
+    inner: Arc<ClientInner>,
+    options: RequestOptions,
+}
+
+/// The state shared by all clones of a `Client`.
+///
+/// The credentials are kept in `SecretString`s, which are redacted in `Debug`
+/// output and zeroed when dropped.
+#[derive(Debug, Clone)]
+struct ClientInner {
+    app_token: SecretString,
+    secret_key: SecretString,
     http_client: reqwest::Client,
-    base_url: String,
+    environment: Environment,
+    hooks: Hooks,
+    locale: Option<String>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    default_headers: HeaderMap,
+    timeout: Option<Duration>,
+    metrics: Metrics,
+    policies: Policies,
+    /// Seconds added to the local clock when signing, shared by all clones.
+    clock_offset: Arc<AtomicI64>,
+    clock_resync: bool,
+    clock: SharedClock,
+    validate_tags: bool,
+    tag_cache: TagCache,
+    lenient_decoding: bool,
+    idempotency_keys: bool,
+    default_level_name: Option<String>,
+    default_token_ttl: Option<Duration>,
+}
+
+/// A builder for a [`Client`], created with [`Client::builder`].
+#[derive(Debug)]
+pub struct ClientBuilder {
+    app_token: SecretString,
+    secret_key: SecretString,
+    environment: Environment,
+    hooks: Hooks,
+    locale: Option<String>,
+    rate_limit: Option<(f64, u32)>,
+    circuit_breaker: Option<(u32, Duration)>,
+    http_client: Option<reqwest::Client>,
+    default_headers: HeaderMap,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    metrics: Metrics,
+    policies: Policies,
+    clock_offset: i64,
+    clock_resync: bool,
+    clock: SharedClock,
+    validate_tags: bool,
+    lenient_decoding: bool,
+    idempotency_keys: bool,
+    default_level_name: Option<String>,
+    default_token_ttl: Option<Duration>,
 }
 
+impl ClientBuilder {
+    /// Sets the base URL of the API, e.g., for a proxy or a mock server.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.environment = Environment::Custom(base_url.into());
+        self
+    }
+
+    /// Sets the environment of the API. See [`Environment`].
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.environment = environment;
+        self
+    }
+
+    /// Registers a hook. See [`Client::with_hook`].
+    pub fn hook(mut self, hook: impl RequestHook + 'static) -> Self {
+        self.hooks.push(Arc::new(hook));
+        self
+    }
+
+    /// Sets the locale sent as the `Accept-Language` header. See [`Client::with_locale`].
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Limits the client to `requests_per_second` on average, allowing bursts
+    /// of up to `burst` requests. Requests over the limit wait instead of
+    /// failing with a 429 from Sumsub.
+    ///
+    /// The limit is shared by all clones of the client.
+    pub fn rate_limit(mut self, requests_per_second: f64, burst: u32) -> Self {
+        self.rate_limit = Some((requests_per_second, burst));
+        self
+    }
+
+    /// Enables a circuit breaker: after `failure_threshold` consecutive
+    /// calls failed with a network error or a server error (5xx), calls fail
+    /// immediately with `SumsubError::CircuitOpen` for `open_duration`
+    /// instead of waiting for timeouts. A single trial call is then let
+    /// through, and the circuit closes again if it succeeds.
+    ///
+    /// A call counts once, after its retries. The circuit is shared by all
+    /// clones of the client.
+    pub fn circuit_breaker(mut self, failure_threshold: u32, open_duration: Duration) -> Self {
+        self.circuit_breaker = Some((failure_threshold, open_duration));
+        self
+    }
+
+    /// Uses a pre-configured `reqwest::Client`, e.g., to control proxies, TLS,
+    /// and connection pooling, or to share a pool with the rest of the application.
+    ///
+    /// Configure it with `reqwest::redirect::Policy::none()` to let the client
+    /// follow redirects itself; otherwise `reqwest` forwards the authentication
+    /// headers to the redirect target, even on another host.
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(http_client);
+        self
+    }
+
+    /// Adds a header sent with every request. Authentication headers cannot be overridden.
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.default_headers.insert(name, value);
+        self
+    }
+
+    /// Sets the timeout of every request, from sending it until the response
+    /// body has been read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for establishing connections. Cannot be combined
+    /// with [`ClientBuilder::http_client`]; configure the injected client instead.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the sink receiving the metrics of every request. See [`Client::with_metrics`].
+    pub fn metrics(mut self, sink: impl MetricsSink + 'static) -> Self {
+        self.metrics = Metrics::new(Arc::new(sink));
+        self
+    }
+
+    /// Sets the timeout and retry policy of a class of endpoints. Each client
+    /// method selects its class automatically, so, e.g., report downloads can
+    /// be given a longer timeout than metadata reads.
+    pub fn policy(mut self, class: EndpointClass, policy: EndpointPolicy) -> Self {
+        self.policies.set(class, policy);
+        self
+    }
+
+    /// Sets the number of seconds added to the local clock when signing
+    /// requests, e.g., `-30` if the host clock runs 30 seconds ahead.
+    pub fn clock_offset(mut self, offset_secs: i64) -> Self {
+        self.clock_offset = offset_secs;
+        self
+    }
+
+    /// Sets the clock used to timestamp request signatures, e.g., a
+    /// [`FixedClock`](crate::signing::FixedClock) to get deterministic
+    /// signatures in tests. The clock offset is added to its time.
+    pub fn clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = SharedClock::new(Arc::new(clock));
+        self
+    }
+
+    /// Enables resynchronizing the clock when the API rejects a request as
+    /// unauthorized and its `Date` header shows that the local clock drifted.
+    /// The clock offset is then corrected and the request is signed and sent
+    /// again, once.
+    pub fn clock_resync(mut self, enabled: bool) -> Self {
+        self.clock_resync = enabled;
+        self
+    }
+
+    /// Enables checking tags against the account's tag dictionary before
+    /// adding them to applicants or transactions. Unknown tags are rejected
+    /// with a `SumsubError::Validation` without calling the API.
+    ///
+    /// Each dictionary is fetched on first use and fetched again when a tag
+    /// is not in it, so tags configured later are picked up.
+    pub fn validate_tags(mut self, enabled: bool) -> Self {
+        self.validate_tags = enabled;
+        self
+    }
+
+    /// Enables capturing the raw body of responses that cannot be
+    /// deserialized. A mismatch is then reported as a `SumsubError::Decode`
+    /// with the body and the path of the field that failed, instead of a
+    /// `SumsubError::Serde`, so that changes to the API's models can be
+    /// reported and worked around.
+    pub fn lenient_decoding(mut self, enabled: bool) -> Self {
+        self.lenient_decoding = enabled;
+        self
+    }
+
+    /// Enables sending a new idempotency key (a random UUID) with every call
+    /// that creates an applicant, submits a transaction, or uploads a
+    /// document, unless one is set with [`RequestOptions::idempotency_key`].
+    /// Retries of the call reuse its key.
+    ///
+    /// The key is only sent as a header; it prevents duplicates only if the
+    /// server honours it. The client does not deduplicate calls itself.
+    pub fn idempotency_keys(mut self, enabled: bool) -> Self {
+        self.idempotency_keys = enabled;
+        self
+    }
+
+    /// Sets the level used to issue access tokens and WebSDK links when no
+    /// level is passed to the call.
+    pub fn default_level_name(mut self, level_name: impl Into<String>) -> Self {
+        self.default_level_name = Some(level_name.into());
+        self
+    }
+
+    /// Sets the lifetime of access tokens and WebSDK links when none is
+    /// passed to the call. Without it, the API's default of 10 minutes applies.
+    pub fn default_token_ttl(mut self, ttl: Duration) -> Self {
+        self.default_token_ttl = Some(ttl);
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// Returns a `SumsubError::Configuration` if a credential is empty or
+    /// malformed or the base URL is not an HTTP(S) URL, and a
+    /// `SumsubError::Validation` if the rate limit is not positive, the burst
+    /// is zero, or a connect timeout is set for an injected HTTP client.
+    pub fn build(self) -> Result<Client, SumsubError> {
+        validate_credential("app token", self.app_token.expose_secret())?;
+        validate_credential("secret key", self.secret_key.expose_secret())?;
+        match reqwest::Url::parse(self.environment.base_url()) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") => {}
+            _ => {
+                return Err(SumsubError::Configuration(format!(
+                    "invalid base URL: {:?}",
+                    self.environment.base_url()
+                )));
+            }
+        }
+        let rate_limiter = match self.rate_limit {
+            Some((rate, burst)) if !(rate.is_finite() && rate > 0.0) || burst == 0 => {
+                return Err(SumsubError::Validation(format!(
+                    "invalid rate limit: {} requests per second with a burst of {}",
+                    rate, burst
+                )));
+            }
+            Some((rate, burst)) => Some(Arc::new(RateLimiter::new(rate, burst))),
+            None => None,
+        };
+        let circuit_breaker = match self.circuit_breaker {
+            Some((0, _)) => {
+                return Err(SumsubError::Validation(
+                    "the failure threshold of the circuit breaker must be at least 1".to_string(),
+                ));
+            }
+            Some((failure_threshold, open_duration)) => {
+                Some(Arc::new(CircuitBreaker::new(failure_threshold, open_duration)))
+            }
+            None => None,
+        };
+        let http_client = match (self.http_client, self.connect_timeout) {
+            (Some(_), Some(_)) => {
+                return Err(SumsubError::Validation(
+                    "a connect timeout cannot be applied to an injected HTTP client".to_string(),
+                ));
+            }
+            (Some(http_client), None) => http_client,
+            (None, connect_timeout) => {
+                let mut builder = default_http_client();
+                if let Some(connect_timeout) = connect_timeout {
+                    builder = builder.connect_timeout(connect_timeout);
+                }
+                builder.build()?
+            }
+        };
+        Ok(Client {
+            inner: Arc::new(ClientInner {
+                app_token: self.app_token,
+                secret_key: self.secret_key,
+                http_client,
+                environment: self.environment,
+                hooks: self.hooks,
+                locale: self.locale,
+                rate_limiter,
+                circuit_breaker,
+                default_headers: self.default_headers,
+                timeout: self.timeout,
+                metrics: self.metrics,
+                policies: self.policies,
+                clock_offset: Arc::new(AtomicI64::new(self.clock_offset)),
+                clock_resync: self.clock_resync,
+                clock: self.clock,
+                validate_tags: self.validate_tags,
+                tag_cache: TagCache::default(),
+                lenient_decoding: self.lenient_decoding,
+                idempotency_keys: self.idempotency_keys,
+                default_level_name: self.default_level_name,
+                default_token_ttl: self.default_token_ttl,
+            }),
+            options: RequestOptions::default(),
+        })
+    }
+}
+
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Client>();
+};
+
 impl Client {
     /// Creates a new `Client`.
     ///
@@ -88,21 +641,172 @@ impl Client {
     /// let client = Client::new("YOUR_APP_TOKEN".to_string(), "YOUR_SECRET_KEY".to_string());
     /// ```
     pub fn new(app_token: String, secret_key: String) -> Self {
-        Self {
-            app_token,
-            secret_key,
-            http_client: reqwest::Client::new(),
-            base_url: BASE_URL.to_string(),
-        }
+        Self::new_with_environment(app_token, secret_key, Environment::Production)
     }
 
     /// Creates a new `Client` with a custom base URL for testing.
+    ///
+    /// Equivalent to [`Client::new_with_environment`] with [`Environment::Custom`].
     pub fn new_with_base_url(app_token: String, secret_key: String, base_url: String) -> Self {
+        Self::new_with_environment(app_token, secret_key, Environment::Custom(base_url))
+    }
+
+    /// Creates a new `Client` for the given environment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sumsub_api::client::{Client, Environment};
+    ///
+    /// let client = Client::new_with_environment(
+    ///     "YOUR_APP_TOKEN".to_string(),
+    ///     "YOUR_SECRET_KEY".to_string(),
+    ///     Environment::Custom("https://sumsub-proxy.internal".to_string()),
+    /// );
+    /// ```
+    pub fn new_with_environment(app_token: String, secret_key: String, environment: Environment) -> Self {
+        Self {
+            inner: Arc::new(ClientInner {
+                app_token: app_token.into(),
+                secret_key: secret_key.into(),
+                http_client: default_http_client()
+                    .build()
+                    .expect("failed to build the default HTTP client"),
+                environment,
+                hooks: Hooks::default(),
+                locale: None,
+                rate_limiter: None,
+                circuit_breaker: None,
+                default_headers: HeaderMap::new(),
+                timeout: None,
+                metrics: Metrics::default(),
+                policies: Policies::default(),
+                clock_offset: Arc::new(AtomicI64::new(0)),
+                clock_resync: false,
+                clock: SharedClock::default(),
+                validate_tags: false,
+                tag_cache: TagCache::default(),
+                lenient_decoding: false,
+                idempotency_keys: false,
+                default_level_name: None,
+                default_token_ttl: None,
+            }),
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Creates a `Client` from the `SUMSUB_APP_TOKEN` and `SUMSUB_SECRET_KEY`
+    /// environment variables, and `SUMSUB_BASE_URL` if set (e.g., for a proxy).
+    ///
+    /// Returns a `SumsubError::Configuration` if a variable is missing, empty,
+    /// or malformed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use sumsub_api::client::Client;
+    ///
+    /// let client = Client::from_env().expect("Sumsub credentials are not configured");
+    /// ```
+    pub fn from_env() -> Result<Self, SumsubError> {
+        let app_token = required_env("SUMSUB_APP_TOKEN")?;
+        let secret_key = required_env("SUMSUB_SECRET_KEY")?;
+        let mut builder = Client::builder(app_token, secret_key);
+        if let Ok(base_url) = std::env::var("SUMSUB_BASE_URL") {
+            builder = builder.base_url(base_url);
+        }
+        builder.build()
+    }
+
+    /// Returns a [`ClientBuilder`] for configuring a client beyond the defaults.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sumsub_api::client::Client;
+    ///
+    /// let client = Client::builder("YOUR_APP_TOKEN", "YOUR_SECRET_KEY")
+    ///     .rate_limit(8.0, 16)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder(app_token: impl Into<String>, secret_key: impl Into<String>) -> ClientBuilder {
+        ClientBuilder {
+            app_token: SecretString::from(app_token.into()),
+            secret_key: SecretString::from(secret_key.into()),
+            environment: Environment::Production,
+            hooks: Hooks::default(),
+            locale: None,
+            rate_limit: None,
+            circuit_breaker: None,
+            http_client: None,
+            default_headers: HeaderMap::new(),
+            timeout: None,
+            connect_timeout: None,
+            metrics: Metrics::default(),
+            policies: Policies::default(),
+            clock_offset: 0,
+            clock_resync: false,
+            clock: SharedClock::default(),
+            validate_tags: false,
+            lenient_decoding: false,
+            idempotency_keys: false,
+            default_level_name: None,
+            default_token_ttl: None,
+        }
+    }
+
+    /// Registers a hook that is called around every request sent by this client.
+    ///
+    /// Hooks are shared by clones created after registration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sumsub_api::client::Client;
+    /// use sumsub_api::hooks;
+    ///
+    /// let client = Client::new("YOUR_APP_TOKEN".to_string(), "YOUR_SECRET_KEY".to_string())
+    ///     .with_hook(hooks::after_receive(|request, response| {
+    ///         println!("{} {} -> {}", request.method(), request.url(), response.status());
+    ///     }));
+    /// ```
+    pub fn with_hook(mut self, hook: impl RequestHook + 'static) -> Self {
+        Arc::make_mut(&mut self.inner).hooks.push(Arc::new(hook));
+        self
+    }
+
+    /// Enables debug logging: every request and response is passed to `log`
+    /// as a line with secrets and personal data redacted. See
+    /// [`hooks::debug_logger`](crate::hooks::debug_logger).
+    pub fn with_debug_logging(self, log: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.with_hook(crate::hooks::debug_logger(log))
+    }
+
+    /// Sets the sink receiving the method, endpoint, status, and latency of
+    /// every request sent by this client, replacing the default no-op sink.
+    pub fn with_metrics(mut self, sink: impl MetricsSink + 'static) -> Self {
+        Arc::make_mut(&mut self.inner).metrics = Metrics::new(Arc::new(sink));
+        self
+    }
+
+    /// Sets the locale (e.g., `de` or `pt-BR`) sent as the `Accept-Language`
+    /// header, for endpoints whose output Sumsub localizes, such as messages,
+    /// reports, and reject descriptions.
+    ///
+    /// The locale can be overridden per call with [`RequestOptions::locale`].
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        Arc::make_mut(&mut self.inner).locale = Some(locale.into());
+        self
+    }
+
+    /// Returns a clone of this client whose calls use the given request options.
+    ///
+    /// The clone shares the credentials, connection pool, and hooks of this client.
+    pub fn with_options(&self, options: RequestOptions) -> Self {
         Self {
-            app_token,
-            secret_key,
-            http_client: reqwest::Client::new(),
-            base_url,
+            inner: self.inner.clone(),
+            options,
         }
     }
 
@@ -111,28 +815,145 @@ impl Client {
         response: reqwest::Response,
     ) -> Result<T, SumsubError> {
         if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let message = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Could not read error body".to_string());
-            return Err(SumsubError::ApiError { status, message });
+            return Err(api_error(response).await);
+        }
+        self.decode(&json_bytes(response).await?)
+    }
+
+    /// Deserializes a JSON body. With lenient decoding, a failure keeps the
+    /// body and the path of the field that failed.
+    fn decode<T: for<'de> serde::Deserialize<'de>>(&self, bytes: &[u8]) -> Result<T, SumsubError> {
+        serde_json::from_slice(bytes).map_err(|error| {
+            if !self.inner.lenient_decoding {
+                return error.into();
+            }
+            let mut deserializer = serde_json::Deserializer::from_slice(bytes);
+            match serde_path_to_error::deserialize::<_, T>(&mut deserializer) {
+                Err(source) => decode_error(bytes, source),
+                // Trailing data after a valid value.
+                Ok(_) => error.into(),
+            }
+        })
+    }
+
+    /// Like `handle_response_and_deserialize`, but also extracts the warnings
+    /// and diagnostics of the response. An empty body deserializes as `null`.
+    async fn handle_response_with_diagnostics<T: for<'de> serde::Deserialize<'de>>(
+        &self,
+        response: reqwest::Response,
+    ) -> Result<crate::response::ApiResponse<T>, SumsubError> {
+        if !response.status().is_success() {
+            return Err(api_error(response).await);
+        }
+        let mut diagnostics = response_diagnostics(&response);
+        let bytes = json_bytes(response).await?;
+        let mut value: serde_json::Value = if bytes.is_empty() {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_slice(&bytes)?
+        };
+        if let Some(object) = value.as_object_mut() {
+            if let Some(raw) = object.remove("warnings") {
+                diagnostics.warnings = serde_json::from_value(raw)?;
+            }
+            if diagnostics.correlation_id.is_none() {
+                diagnostics.correlation_id = object
+                    .get("correlationId")
+                    .and_then(|id| id.as_str())
+                    .map(str::to_string);
+            }
+        }
+        let data = if self.inner.lenient_decoding {
+            serde_path_to_error::deserialize(value).map_err(|source| decode_error(&bytes, source))?
+        } else {
+            serde_json::from_value(value)?
+        };
+        Ok(diagnostics.map(|()| data))
+    }
+
+    /// Like `handle_response_and_deserialize`, but an empty body deserializes
+    /// as `T::default()`.
+    async fn handle_response_or_default<T: for<'de> serde::Deserialize<'de> + Default>(
+        &self,
+        response: reqwest::Response,
+    ) -> Result<T, SumsubError> {
+        if !response.status().is_success() {
+            return Err(api_error(response).await);
+        }
+        let bytes = json_bytes(response).await?;
+        if bytes.iter().all(u8::is_ascii_whitespace) {
+            return Ok(T::default());
         }
-        response.json().await.map_err(SumsubError::from)
+        self.decode(&bytes)
     }
 
     async fn handle_empty_response(&self, response: reqwest::Response) -> Result<(), SumsubError> {
         if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let message = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Could not read error body".to_string());
-            return Err(SumsubError::ApiError { status, message });
+            return Err(api_error(response).await);
         }
         Ok(())
     }
 
+    async fn handle_bytes_response(&self, response: reqwest::Response) -> Result<Vec<u8>, SumsubError> {
+        if !response.status().is_success() {
+            return Err(api_error(response).await);
+        }
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// Like `handle_bytes_response`, but streams the body instead of
+    /// buffering it.
+    async fn handle_stream_response(
+        &self,
+        response: reqwest::Response,
+    ) -> Result<crate::response::ByteStream, SumsubError> {
+        use futures::TryStreamExt;
+
+        if !response.status().is_success() {
+            return Err(api_error(response).await);
+        }
+        Ok(Box::pin(response.bytes_stream().map_err(SumsubError::from)))
+    }
+
+    /// Like `handle_bytes_response`, but also keeps the diagnostics of the
+    /// response.
+    async fn handle_bytes_response_with_diagnostics(
+        &self,
+        response: reqwest::Response,
+    ) -> Result<crate::response::ApiResponse<Vec<u8>>, SumsubError> {
+        if !response.status().is_success() {
+            return Err(api_error(response).await);
+        }
+        let diagnostics = response_diagnostics(&response);
+        let data = response.bytes().await?.to_vec();
+        Ok(diagnostics.map(|()| data))
+    }
+
+    /// Requests a media file without downloading it and returns the
+    /// temporary link the API redirected to, if any.
+    async fn media_link(&self, path: &str) -> Result<Option<String>, SumsubError> {
+        let response = self
+            .execute_with_redirects(Method::GET, path, RequestBody::Empty, false)
+            .await?;
+        if response.status().is_redirection() {
+            return Ok(response
+                .headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|location| location.to_str().ok())
+                .and_then(|location| response.url().join(location).ok())
+                .map(String::from));
+        }
+        if !response.status().is_success() {
+            return Err(api_error(response).await);
+        }
+        // An injected HTTP client may have followed the redirect itself.
+        if self.is_api_url(response.url()) {
+            Ok(None)
+        } else {
+            Ok(Some(response.url().to_string()))
+        }
+    }
+
     /// Sends a request to the Sumsub API.
     ///
     /// This is a private helper function that handles the common logic for
@@ -144,40 +965,419 @@ impl Client {
         path: &str,
         body: Option<T>,
     ) -> Result<reqwest::Response, SumsubError> {
-        let ts = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        let body = match body {
+            Some(body) => RequestBody::Json(serde_json::to_string(&body).map_err(SumsubError::from)?),
+            None => RequestBody::Empty,
+        };
+        self.execute(method, path, body).await
+    }
 
-        let body_str = if let Some(body) = body {
-            Some(serde_json::to_string(&body).map_err(SumsubError::from)?)
-        } else {
+    /// Signs and sends an arbitrary request and returns the raw response,
+    /// e.g., to call endpoints this crate has no typed method for yet.
+    ///
+    /// The request goes through the same pipeline as the typed methods
+    /// (rate limiting, hooks, policies, and redirects). The response is
+    /// returned as is, including error statuses.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The HTTP method.
+    /// * `path` - The path and query after the base URL, e.g., `/resources/applicants/{id}/one`.
+    /// * `body` - The JSON body, if any.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use sumsub_api::client::Client;
+    /// use sumsub_api::http::Method;
+    /// use sumsub_api::json::Value;
+    ///
+    /// # async fn run(client: Client) -> Result<(), sumsub_api::error::SumsubError> {
+    /// let response = client
+    ///     .request_raw(Method::GET, "/resources/applicants/abc/one", None::<()>)
+    ///     .await?;
+    /// let applicant: Value = response.json().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "raw-api")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn request_raw<T: Serialize>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<T>,
+    ) -> Result<reqwest::Response, SumsubError> {
+        if !path.starts_with('/') {
+            return Err(SumsubError::Validation(format!(
+                "the path must start with '/': {}",
+                path
+            )));
+        }
+        self.send_request(method, path, body).await
+    }
+
+    /// Sends a request to any endpoint like [`Client::request_raw`], and
+    /// deserializes the response together with its diagnostics (correlation
+    /// ID, request ID, and rate limit state).
+    ///
+    /// Error statuses are returned as `SumsubError`s.
+    #[cfg(feature = "raw-api")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn request_with_diagnostics<T, B>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<B>,
+    ) -> Result<crate::response::ApiResponse<T>, SumsubError>
+    where
+        T: for<'de> serde::Deserialize<'de>,
+        B: Serialize,
+    {
+        let response = self.request_raw(method, path, body).await?;
+        self.handle_response_with_diagnostics(response).await
+    }
+
+    /// Signs and sends a request with the given body.
+    ///
+    /// Every request made by the client goes through this function, which
+    /// waits for the rate limiter, adds the authentication headers, and runs
+    /// the registered hooks, and retries it according to the policy of its
+    /// endpoint class. With the `tracing` feature, each public method runs in
+    /// a span named after it, and each request is wrapped in a
+    /// `sumsub.request` span whose `operation` field is that method's name.
+    async fn execute(
+        &self,
+        method: Method,
+        path: &str,
+        body: RequestBody,
+    ) -> Result<reqwest::Response, SumsubError> {
+        self.execute_with_redirects(method, path, body, true).await
+    }
+
+    /// Like `execute`, but returns redirect responses as is unless
+    /// `follow_redirects` is set.
+    async fn execute_with_redirects(
+        &self,
+        method: Method,
+        path: &str,
+        body: RequestBody,
+        follow_redirects: bool,
+    ) -> Result<reqwest::Response, SumsubError> {
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+
+            let (applicant_id, txn_id) = path_ids(path);
+            // The innermost method span of this crate names the operation.
+            let current = tracing::Span::current();
+            let operation = current
+                .metadata()
+                .filter(|metadata| metadata.target().starts_with("sumsub_api"))
+                .map(|metadata| metadata.name());
+            let span = tracing::info_span!(
+                "sumsub.request",
+                operation,
+                http.method = %method,
+                path = path.split('?').next().unwrap_or(path),
+                applicant_id,
+                txn_id,
+                http.status_code = tracing::field::Empty,
+                latency_ms = tracing::field::Empty,
+            );
+            let started = Instant::now();
+            let result = self
+                .send_with_policy(method, path, body, follow_redirects)
+                .instrument(span.clone())
+                .await;
+            span.record("latency_ms", started.elapsed().as_millis() as u64);
+            match &result {
+                Ok(response) => {
+                    span.record("http.status_code", response.status().as_u16());
+                }
+                Err(e) => {
+                    tracing::warn!(parent: &span, error = %e, "Sumsub request failed");
+                }
+            }
+            result
+        }
+        #[cfg(not(feature = "tracing"))]
+        self.send_with_policy(method, path, body, follow_redirects).await
+    }
+
+    /// Sends a request, retrying it after network errors, 429s, and 5xx
+    /// responses as allowed by the policy of its endpoint class. A
+    /// `Retry-After` header on the response overrides the policy's delay, up
+    /// to the policy's `max_retry_after`.
+    async fn send_with_policy(
+        &self,
+        method: Method,
+        path: &str,
+        body: RequestBody,
+        follow_redirects: bool,
+    ) -> Result<reqwest::Response, SumsubError> {
+        let circuit_breaker = self.inner.circuit_breaker.as_deref();
+        if let Some(circuit_breaker) = circuit_breaker {
+            circuit_breaker
+                .acquire()
+                .map_err(|retry_after| SumsubError::CircuitOpen { retry_after })?;
+        }
+        let policy = self.inner.policies.get(EndpointClass::classify(&method, path));
+        let timeout = self.options.timeout.or(policy.timeout).or(self.inner.timeout);
+        let mut body = body;
+        let mut attempt = 0;
+        let mut resynced = false;
+        loop {
+            let may_resync = self.inner.clock_resync && !resynced;
+            let retry_body = if attempt < policy.max_retries || may_resync {
+                body.try_clone()
+            } else {
+                None
+            };
+            let result = self
+                .send_signed(method.clone(), path, body, timeout, follow_redirects)
+                .await;
+            let (retryable, wait) = match &result {
+                Ok(response) => (
+                    response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS
+                        || response.status().is_server_error(),
+                    retry_after(response),
+                ),
+                Err(e) => (e.is_retryable(), None),
+            };
+            let skewed = match &result {
+                Ok(response) if may_resync && response.status() == reqwest::StatusCode::UNAUTHORIZED => {
+                    self.resync_clock(response)
+                }
+                _ => false,
+            };
+            match retry_body {
+                Some(next_body) if skewed => {
+                    resynced = true;
+                    body = next_body;
+                }
+                Some(next_body) if retryable && attempt < policy.max_retries => {
+                    let delay = crate::policy::backoff(policy.retry_delay, attempt);
+                    let wait = wait.map(|wait| wait.min(policy.max_retry_after));
+                    tokio::time::sleep(wait.unwrap_or(delay)).await;
+                    attempt += 1;
+                    body = next_body;
+                }
+                _ => {
+                    if let Some(circuit_breaker) = circuit_breaker {
+                        circuit_breaker.record(match &result {
+                            Ok(response) => !response.status().is_server_error(),
+                            Err(e) => !matches!(e, SumsubError::Reqwest(_)),
+                        });
+                    }
+                    return result;
+                }
+            }
+        }
+    }
+
+    async fn send_signed(
+        &self,
+        method: Method,
+        path: &str,
+        body: RequestBody,
+        timeout: Option<Duration>,
+        follow_redirects: bool,
+    ) -> Result<reqwest::Response, SumsubError> {
+        if let Some(rate_limiter) = &self.inner.rate_limiter {
+            rate_limiter.acquire().await;
+        }
+
+        let path = self.options.apply_query(path);
+        let body_str = body.text().map(str::to_string);
+        let url = format!("{}{}", self.inner.environment.base_url(), path);
+
+        let mut headers = self.signed_headers(&method, &path, body.bytes())?;
+        if let Some(content_type) = body.content_type() {
+            headers.insert(CONTENT_TYPE, content_type);
+        }
+
+        let hook_request = if self.inner.hooks.is_empty() {
             None
+        } else {
+            let mut hook_request = HookRequest::new(method.clone(), url.clone(), &headers, body_str);
+            self.inner.hooks.before_send(&mut hook_request);
+            headers.extend(hook_request.extra_headers().clone());
+            Some(hook_request)
         };
 
-        let signature = sign_request(
-            &self.secret_key,
-            ts,
-            method.as_str(),
-            path,
-            &body_str,
-        );
+        let mut request_builder = self.inner.http_client.request(method.clone(), &url).headers(headers);
+        if let Some(timeout) = timeout {
+            request_builder = request_builder.timeout(timeout);
+        }
+        request_builder = match body {
+            RequestBody::Empty => request_builder,
+            RequestBody::Json(body) | RequestBody::NdJson(body) => request_builder.body(body),
+            RequestBody::Multipart(body) => request_builder.body(body.bytes),
+        };
 
-        let url = format!("{}{}", self.base_url, path);
-        let mut request_builder = self.http_client.request(method, &url);
+        let started = Instant::now();
+        let result = request_builder.send().await;
+        let request_metrics = RequestMetrics {
+            method: method.clone(),
+            endpoint: endpoint_name(&path),
+            status: result.as_ref().ok().map(reqwest::Response::status),
+            latency: started.elapsed(),
+        };
+        self.inner.metrics.record(&request_metrics);
+        let policy = self.inner.policies.get(EndpointClass::classify(&method, &path));
+        if let Some(budget) = policy.latency_budget.filter(|budget| request_metrics.latency > *budget) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(
+                http.method = %method,
+                endpoint = %request_metrics.endpoint,
+                latency_ms = request_metrics.latency.as_millis() as u64,
+                budget_ms = budget.as_millis() as u64,
+                "Sumsub request exceeded its latency budget"
+            );
+            self.inner.metrics.record_slow_call(&request_metrics, budget);
+        }
+        let response = match result {
+            Ok(response) => response,
+            Err(e) => {
+                if let Some(hook_request) = &hook_request {
+                    self.inner.hooks.on_error(hook_request, &e);
+                }
+                return Err(SumsubError::from(e));
+            }
+        };
 
-        request_builder = request_builder
-            .header("X-App-Token", &self.app_token)
-            .header("X-App-Access-Sig", signature)
-            .header("X-App-Access-Ts", ts.to_string());
+        if let Some(hook_request) = hook_request {
+            let hook_response = HookResponse::new(
+                response.status(),
+                response.headers().clone(),
+                started.elapsed(),
+            );
+            self.inner.hooks.after_receive(&hook_request, &hook_response);
+        }
 
-        if let Some(body) = body_str {
-            request_builder = request_builder
-                .header("Content-Type", "application/json")
-                .body(body);
+        if follow_redirects && method == Method::GET {
+            return self.follow_redirects(response, timeout).await;
         }
+        Ok(response)
+    }
+
+    /// The number of seconds currently added to the local clock when signing
+    /// requests. See [`ClientBuilder::clock_offset`] and [`ClientBuilder::clock_resync`].
+    pub fn clock_offset(&self) -> i64 {
+        self.inner.clock_offset.load(Ordering::Relaxed)
+    }
+
+    /// Corrects the clock offset from the `Date` header of a response.
+    /// Returns `true` if the offset was off by more than the tolerance.
+    fn resync_clock(&self, response: &reqwest::Response) -> bool {
+        let Some(server_time) = response
+            .headers()
+            .get(reqwest::header::DATE)
+            .and_then(|date| date.to_str().ok())
+            .and_then(|date| httpdate::parse_http_date(date).ok())
+        else {
+            return false;
+        };
+        let offset = unix_secs(server_time) - unix_secs(self.inner.clock.now());
+        if (offset - self.clock_offset()).abs() <= CLOCK_SKEW_TOLERANCE_SECS {
+            return false;
+        }
+        self.inner.clock_offset.store(offset, Ordering::Relaxed);
+        true
+    }
+
+    /// Returns a client for a call that sends an idempotency key: the key set
+    /// in the request options or, if enabled, a new UUID.
+    fn idempotent(&self) -> Client {
+        let mut client = self.clone();
+        client.options.call_idempotency_key = self
+            .options
+            .idempotency_key
+            .clone()
+            .or_else(|| self.inner.idempotency_keys.then(|| uuid::Uuid::new_v4().to_string()));
+        client
+    }
+
+    /// Builds the authentication, locale, and default headers of a request.
+    fn signed_headers(
+        &self,
+        method: &Method,
+        path: &str,
+        body: Option<&[u8]>,
+    ) -> Result<HeaderMap, SumsubError> {
+        let ts = u64::try_from(unix_secs(self.inner.clock.now()) + self.clock_offset()).map_err(|_| {
+            SumsubError::Configuration("the clock is set before the Unix epoch".to_string())
+        })?;
+        let signature = sign_request(self.inner.secret_key.expose_secret(), ts, method.as_str(), path, body);
+
+        let mut headers = HeaderMap::new();
+        headers.insert("X-App-Token", sensitive_header_value(self.inner.app_token.expose_secret())?);
+        headers.insert("X-App-Access-Sig", sensitive_header_value(&signature)?);
+        headers.insert("X-App-Access-Ts", header_value(&ts.to_string())?);
+        if let Some(locale) = self.options.locale.as_ref().or(self.inner.locale.as_ref()) {
+            headers.insert(ACCEPT_LANGUAGE, header_value(locale)?);
+        }
+        for (name, value) in self.inner.default_headers.iter().chain(&self.options.headers) {
+            if !is_auth_header(name) {
+                headers.insert(name.clone(), value.clone());
+            }
+        }
+        if let Some(key) = &self.options.call_idempotency_key {
+            headers.insert(crate::options::IDEMPOTENCY_KEY_HEADER, header_value(key)?);
+        }
+        Ok(headers)
+    }
+
+    /// Returns `true` if `url` is on the same origin as the API base URL.
+    fn is_api_url(&self, url: &reqwest::Url) -> bool {
+        reqwest::Url::parse(self.inner.environment.base_url())
+            .map(|base_url| base_url.origin() == url.origin())
+            .unwrap_or(false)
+    }
 
-        request_builder.send().await.map_err(SumsubError::from)
+    /// Follows the redirects of a `GET` request.
+    ///
+    /// Redirects to the API host are signed again for their new path.
+    /// Redirects to other hosts, e.g., presigned storage URLs for reports,
+    /// are sent without any client headers, so the credentials never leave
+    /// the API host. The final response's `url()` is the URL it was received
+    /// from. A redirect without a valid `Location` is returned as is.
+    async fn follow_redirects(
+        &self,
+        mut response: reqwest::Response,
+        timeout: Option<Duration>,
+    ) -> Result<reqwest::Response, SumsubError> {
+        for _ in 0..MAX_REDIRECTS {
+            if !response.status().is_redirection() {
+                break;
+            }
+            let Some(url) = response
+                .headers()
+                .get(reqwest::header::LOCATION)
+                .and_then(|location| location.to_str().ok())
+                .and_then(|location| response.url().join(location).ok())
+            else {
+                break;
+            };
+
+            let mut request_builder = self.inner.http_client.get(url.clone());
+            if self.is_api_url(&url) {
+                if let Some(rate_limiter) = &self.inner.rate_limiter {
+                    rate_limiter.acquire().await;
+                }
+                let path = match url.query() {
+                    Some(query) => format!("{}?{}", url.path(), query),
+                    None => url.path().to_string(),
+                };
+                request_builder = request_builder.headers(self.signed_headers(&Method::GET, &path, None)?);
+            }
+            if let Some(timeout) = timeout {
+                request_builder = request_builder.timeout(timeout);
+            }
+            response = request_builder.send().await?;
+        }
+        Ok(response)
     }
 
     /// Creates a new applicant.
@@ -188,18 +1388,50 @@ impl Client {
     ///
     /// * `request` - The request to create an applicant.
     /// * `level_name` - The name of the verification level to assign to the applicant.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn create_applicant(
         &self,
         request: CreateApplicantRequest,
         level_name: &str,
     ) -> Result<Applicant, SumsubError> {
-        let path = format!("/resources/applicants?levelName={}", level_name);
+        let path = Query::new("/resources/applicants")
+            .param("levelName", level_name)
+            .build();
         let response = self
+            .idempotent()
             .send_request(Method::POST, &path, Some(request))
             .await?;
+        if response.status() == reqwest::StatusCode::CONFLICT {
+            let message = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "Could not read error body".to_string());
+            return Err(match existing_applicant_id(&message) {
+                Some(existing_applicant_id) => SumsubError::AlreadyExists { existing_applicant_id },
+                None => SumsubError::from_response(409, None, message),
+            });
+        }
         self.handle_response_and_deserialize(response).await
     }
 
+    /// Creates a new applicant, or returns the existing applicant if one with
+    /// the same `externalUserId` already exists.
+    ///
+    /// The existing applicant is returned as is; it is not moved to `level_name`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn create_or_get_applicant(
+        &self,
+        request: CreateApplicantRequest,
+        level_name: &str,
+    ) -> Result<Applicant, SumsubError> {
+        match self.create_applicant(request, level_name).await {
+            Err(SumsubError::AlreadyExists { existing_applicant_id }) => {
+                self.get_applicant_data(&existing_applicant_id).await
+            }
+            result => result,
+        }
+    }
+
     /// Gets applicant data.
     ///
     /// [Sumsub API reference](https://docs.sumsub.com/reference/get-applicant-data)
@@ -207,20 +1439,48 @@ impl Client {
     /// # Arguments
     ///
     /// * `applicant_id` - The ID of the applicant to get.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_applicant_data(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<Applicant, SumsubError> {
         let path = format!("/resources/applicants/{}/one", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
+    /// Gets applicant data together with the diagnostics of the response.
+    ///
+    /// See [`Client::get_applicant_data`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_applicant_data_with_diagnostics(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::response::ApiResponse<Applicant>, SumsubError> {
+        let path = format!("/resources/applicants/{}/one", applicant_id);
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_response_with_diagnostics(response).await
+    }
+
+    /// Gets all applicants created or changed since `since`
+    /// (`YYYY-MM-DD HH:MM:SS`), following pages until the last one.
+    ///
+    /// Pass the returned `next_since` to the next call to get an incremental
+    /// change feed. See [`crate::sync::SyncBatch`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn sync_applicants_since(
+        &self,
+        since: &str,
+    ) -> Result<crate::sync::SyncBatch<Applicant>, SumsubError> {
+        self.sync_since("/resources/applicants/-/list", since).await
+    }
+
     /// Retrieves the latest TIN check result for an applicant.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-tin-check-results)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_latest_tin_check_result(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<TinCheckResult, SumsubError> {
         self.get_latest_check_result(applicant_id, CheckType::Tin)
             .await
@@ -228,80 +1488,142 @@ impl Client {
 
     /// Retrieves the latest similar search result for an applicant.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-duplicate-applicants-check-result)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_latest_similar_search_result(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<SimilarSearchResult, SumsubError> {
         self.get_latest_check_result(applicant_id, CheckType::SimilarSearch)
             .await
     }
 
     /// Retrieves the latest PoA check result for an applicant.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_latest_poa_check_result(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<PoaCheckResult, SumsubError> {
         self.get_latest_check_result(applicant_id, CheckType::Poa)
             .await
     }
 
     /// Retrieves the latest bank card check result for an applicant.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_latest_bank_card_check_result(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<BankCardCheckResult, SumsubError> {
         self.get_latest_check_result(applicant_id, CheckType::BankCard)
             .await
     }
 
     /// Retrieves the latest email confirmation check result for an applicant.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_latest_email_confirmation_check_result(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<EmailConfirmationCheckResult, SumsubError> {
         self.get_latest_check_result(applicant_id, CheckType::EmailConfirmation)
             .await
     }
 
     /// Retrieves the latest phone confirmation check result for an applicant.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_latest_phone_confirmation_check_result(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<PhoneConfirmationCheckResult, SumsubError> {
         self.get_latest_check_result(applicant_id, CheckType::PhoneConfirmation)
             .await
     }
 
     /// Retrieves the latest IP check result for an applicant.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_latest_ip_check_result(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<IpCheckResult, SumsubError> {
         self.get_latest_check_result(applicant_id, CheckType::IpCheck)
             .await
     }
 
     /// Retrieves the latest NFC check result for an applicant.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_latest_nfc_check_result(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<NfcCheckResult, SumsubError> {
         self.get_latest_check_result(applicant_id, CheckType::Nfc)
             .await
     }
 
+    /// Retrieves the latest face match result for an applicant, including the
+    /// similarity score between the selfie and the document photo.
+    /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#retrieving-check-results)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_latest_face_match_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<FaceMatchCheckResult, SumsubError> {
+        self.get_latest_check_result(applicant_id, CheckType::FaceMatch)
+            .await
+    }
+
+    /// Retrieves the latest liveness check result for an applicant, including
+    /// the liveness score and detected spoofing attempts.
+    /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#retrieving-liveness-resultsvideos)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_latest_liveness_result(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<LivenessCheckResult, SumsubError> {
+        self.get_latest_check_result(applicant_id, CheckType::Liveness)
+            .await
+    }
+
     /// Gets audit trail events.
     ///
     /// [Sumsub API reference](https://docs.sumsub.com/reference/audit-trail-events)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_audit_trail_events(&self) -> Result<Vec<AuditTrailEvent>, SumsubError> {
         let path = "/resources/auditTrailEvents/";
         let response = self.send_request(Method::GET, path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
+    /// Gets a single page of audit trail events.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The number of records to skip.
+    /// * `limit` - The maximum number of records to return.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_audit_trail_events_page(
+        &self,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<AuditTrailEvent>, SumsubError> {
+        let path = Query::new("/resources/auditTrailEvents/")
+            .param("offset", offset)
+            .param("limit", limit)
+            .build();
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_response_and_deserialize(response).await
+    }
+
+    /// Lists the members of the team (agents), e.g., to attribute review
+    /// decisions and notes to specific reviewers in audit reports.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_team_members(&self) -> Result<Vec<crate::misc::TeamMember>, SumsubError> {
+        let path = "/resources/clients/-/members";
+        let response = self.send_request(Method::GET, path, None::<()>).await?;
+        self.handle_response_and_deserialize(response).await
+    }
+
     /// Gets the API health status.
     ///
     /// [Sumsub API reference](https://docs.sumsub.com/reference/review-api-health)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_api_health_status(&self) -> Result<ApiHealthStatus, SumsubError> {
         let path = "/resources/status/api";
         let response = self.send_request(Method::GET, path, None::<()>).await?;
@@ -310,6 +1632,10 @@ impl Client {
 
     /// Creates a new applicant action.
     ///
+    /// If the request has a payment source, its account identifier is validated
+    /// first and a `SumsubError::Validation` is returned without sending the request
+    /// if it does not match the payment source type.
+    ///
     /// [Sumsub API reference](https://docs.sumsub.com/reference/create-applicant-action)
     ///
     /// # Arguments
@@ -317,16 +1643,19 @@ impl Client {
     /// * `applicant_id` - The ID of the applicant to create the action for.
     /// * `level_name` - The name of the verification level to assign to the action.
     /// * `request` - The request to create an applicant action.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn create_applicant_action(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         level_name: &str,
         request: CreateApplicantActionRequest,
     ) -> Result<ApplicantAction, SumsubError> {
-        let path = format!(
-            "/resources/applicantActions/-/forApplicant/{}?levelName={}",
-            applicant_id, level_name
-        );
+        if let Some(payment_source) = &request.payment_source {
+            payment_source.fixed_info.validate()?;
+        }
+        let path = Query::new(format!("/resources/applicantActions/-/forApplicant/{}", applicant_id))
+            .param("levelName", level_name)
+            .build();
         let response = self
             .send_request(Method::POST, &path, Some(request))
             .await?;
@@ -340,9 +1669,10 @@ impl Client {
     /// # Arguments
     ///
     /// * `action_id` - The ID of the action to check.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn request_action_check(
         &self,
-        action_id: &str,
+        action_id: &ActionId,
     ) -> Result<RequestActionCheckResponse, SumsubError> {
         let path = format!(
             "/resources/applicantActions/{}/review/status/pending",
@@ -359,15 +1689,70 @@ impl Client {
     /// # Arguments
     ///
     /// * `applicant_id` - The ID of the applicant to get the actions for.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_applicant_actions(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<GetApplicantActionsResponse, SumsubError> {
         let path = format!("/resources/applicantActions/-;applicantId={}", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
+    /// Gets a single page of actions for an applicant, matching `filter`.
+    ///
+    /// [Sumsub API reference](https://docs.sumsub.com/reference/get-applicant-actions)
+    ///
+    /// # Arguments
+    ///
+    /// * `applicant_id` - The ID of the applicant to get the actions for.
+    /// * `filter` - The review status and date range to filter by.
+    /// * `offset` - The number of records to skip.
+    /// * `limit` - The maximum number of records to return.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_applicant_actions_page(
+        &self,
+        applicant_id: &ApplicantId,
+        filter: &crate::actions::ActionFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Result<GetApplicantActionsResponse, SumsubError> {
+        let query = Query::new(format!("/resources/applicantActions/-;applicantId={}", applicant_id))
+            .param("offset", offset)
+            .param("limit", limit);
+        let path = filter.apply(query).build();
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_response_and_deserialize(response).await
+    }
+
+    /// Streams all actions for an applicant matching `filter`, fetching the
+    /// next page only once the previous one was consumed.
+    ///
+    /// Paging stops after `totalItems` actions or at the first short page.
+    pub fn applicant_actions_stream<'a>(
+        &'a self,
+        applicant_id: &'a ApplicantId,
+        filter: &'a crate::actions::ActionFilter,
+    ) -> impl futures::Stream<Item = Result<ApplicantAction, SumsubError>> + 'a {
+        use futures::{StreamExt, TryStreamExt};
+
+        futures::stream::try_unfold(Some(0u32), move |offset| async move {
+            let Some(offset) = offset else {
+                return Ok(None);
+            };
+            let page = self
+                .get_applicant_actions_page(applicant_id, filter, offset, DEFAULT_PAGE_SIZE)
+                .await?;
+            let fetched = page.items.len() as u32;
+            let next_offset = offset + fetched;
+            let next = (fetched == DEFAULT_PAGE_SIZE && next_offset < page.total_items)
+                .then_some(next_offset);
+            Ok::<_, SumsubError>(Some((futures::stream::iter(page.items.into_iter().map(Ok)), next)))
+        })
+        .try_flatten()
+        .boxed()
+    }
+
     /// Gets information about a specific applicant action.
     ///
     /// [Sumsub API reference](https://docs.sumsub.com/reference/get-action-information)
@@ -375,15 +1760,50 @@ impl Client {
     /// # Arguments
     ///
     /// * `action_id` - The ID of the action to get information for.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_action_information(
         &self,
-        action_id: &str,
+        action_id: &ActionId,
     ) -> Result<ApplicantAction, SumsubError> {
         let path = format!("/resources/applicantActions/{}/one", action_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
+    /// Gets an applicant action by the `externalActionId` it was created with.
+    ///
+    /// # Arguments
+    ///
+    /// * `external_action_id` - The action ID assigned by your system.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_action_by_external_id(
+        &self,
+        external_action_id: &str,
+    ) -> Result<ApplicantAction, SumsubError> {
+        let path = format!(
+            "/resources/applicantActions/-;externalActionId={}/one",
+            urlencoding::encode(external_action_id)
+        );
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_response_and_deserialize(response).await
+    }
+
+    /// Gets the questionnaires submitted for an applicant action.
+    ///
+    /// The questionnaires are read from the action information; see
+    /// [`Client::get_action_information`].
+    ///
+    /// # Arguments
+    ///
+    /// * `action_id` - The ID of the action to get the questionnaires of.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_applicant_action_questionnaires(
+        &self,
+        action_id: &ActionId,
+    ) -> Result<Vec<Questionnaire>, SumsubError> {
+        Ok(self.get_action_information(action_id).await?.questionnaires)
+    }
+
     /// Adds a questionnaire to an applicant action.
     ///
     /// [Sumsub API reference](https://docs.sumsub.com/reference/add-applicant-action-questionnaire)
@@ -392,9 +1812,10 @@ impl Client {
     ///
     /// * `action_id` - The ID of the action to add the questionnaire to.
     /// * `questionnaire` - The questionnaire to add.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn add_applicant_action_questionnaire(
         &self,
-        action_id: &str,
+        action_id: &ActionId,
         questionnaire: Questionnaire,
     ) -> Result<Questionnaire, SumsubError> {
         let path = format!(
@@ -414,12 +1835,15 @@ impl Client {
     /// # Arguments
     ///
     /// * `applicant_id` - The ID of the company applicant.
-    /// * `request` - The request to link a beneficiary.
+    /// * `request` - The request to link a beneficiary. It is validated with
+    ///   [`LinkBeneficiaryRequest::validate`] before being sent.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn link_beneficiary(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         request: LinkBeneficiaryRequest,
     ) -> Result<(), SumsubError> {
+        request.validate()?;
         let path = format!(
             "/resources/applicants/{}/fixedInfo/companyInfo/beneficiaries",
             applicant_id
@@ -436,9 +1860,10 @@ impl Client {
     ///
     /// * `applicant_id` - The ID of the company applicant.
     /// * `beneficiary_id` - The ID of the beneficiary to unlink.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn unlink_beneficiary(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         beneficiary_id: &str,
     ) -> Result<(), SumsubError> {
         let path = format!(
@@ -449,6 +1874,82 @@ impl Client {
         self.handle_empty_response(response).await
     }
 
+    /// Gets the beneficiaries linked to a company applicant.
+    ///
+    /// [Sumsub API reference](https://docs.sumsub.com/reference/get-applicant-data)
+    ///
+    /// # Arguments
+    ///
+    /// * `applicant_id` - The ID of the company applicant.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_company_beneficiaries(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<crate::kyb::LinkedBeneficiary>, SumsubError> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CompanyApplicant {
+            fixed_info: Option<FixedInfoBeneficiaries>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct FixedInfoBeneficiaries {
+            company_info: Option<CompanyInfoBeneficiaries>,
+        }
+
+        #[derive(Deserialize)]
+        struct CompanyInfoBeneficiaries {
+            #[serde(default)]
+            beneficiaries: Vec<crate::kyb::LinkedBeneficiary>,
+        }
+
+        let path = format!("/resources/applicants/{}/one", applicant_id);
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        let applicant: CompanyApplicant = self.handle_response_and_deserialize(response).await?;
+        Ok(applicant
+            .fixed_info
+            .and_then(|info| info.company_info)
+            .map(|info| info.beneficiaries)
+            .unwrap_or_default())
+    }
+
+    /// Gets the verification state of every beneficiary linked to a company
+    /// applicant.
+    ///
+    /// The review statuses of the beneficiaries are fetched concurrently,
+    /// a few at a time. Beneficiaries without an applicant are awaiting the
+    /// applicant. A beneficiary whose status cannot be fetched is reported
+    /// with the error, without failing the others.
+    ///
+    /// # Arguments
+    ///
+    /// * `applicant_id` - The ID of the company applicant.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_beneficiary_readiness(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::kyb::BeneficiaryReadinessReport, SumsubError> {
+        use futures::StreamExt;
+
+        let beneficiaries = self.get_company_beneficiaries(applicant_id).await?;
+        let beneficiaries = futures::stream::iter(beneficiaries)
+            .map(|beneficiary| async move {
+                let state = match &beneficiary.applicant_id {
+                    Some(applicant_id) => self
+                        .get_applicant_status(applicant_id)
+                        .await
+                        .map(|status| crate::applicants::VerificationState::from_status(&status)),
+                    None => Ok(crate::applicants::VerificationState::AwaitingApplicant),
+                };
+                crate::kyb::BeneficiaryReadiness { beneficiary, state }
+            })
+            .buffered(BENEFICIARY_STATUS_CONCURRENCY)
+            .collect()
+            .await;
+        Ok(crate::kyb::BeneficiaryReadinessReport { beneficiaries })
+    }
+
     /// Changes the extracted company data.
     ///
     /// [Sumsub API reference](https://docs.sumsub.com/reference/change-extracted-company-data)
@@ -457,9 +1958,10 @@ impl Client {
     ///
     /// * `applicant_id` - The ID of the company applicant.
     /// * `company_info` - The company data to update.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn change_extracted_company_data(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         company_info: CompanyInfo,
     ) -> Result<CompanyInfo, SumsubError> {
         let path = format!("/resources/applicants/{}/info/companyInfo", applicant_id);
@@ -471,39 +1973,147 @@ impl Client {
 
     /// Changes the provided company data.
     ///
-    /// [Sumsub API reference](https://docs.sumsub.com/reference/change-provided-info-fixedinfo)
+    /// [Sumsub API reference](https://docs.sumsub.com/reference/change-provided-info-fixedinfo)
+    ///
+    /// # Arguments
+    ///
+    /// * `applicant_id` - The ID of the company applicant.
+    /// * `fixed_info` - The company data to update.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn change_provided_company_data(
+        &self,
+        applicant_id: &ApplicantId,
+        fixed_info: FixedInfo,
+    ) -> Result<(), SumsubError> {
+        let path = format!("/resources/applicants/{}/fixedInfo", applicant_id);
+        let response = self.send_request(Method::PATCH, &path, Some(fixed_info)).await?;
+        self.handle_empty_response(response).await
+    }
+
+    /// Gets additional company check data.
+    ///
+    /// [Sumsub API reference](https://docs.sumsub.com/reference/get-additional-company-check-data)
+    ///
+    /// # Arguments
+    ///
+    /// * `applicant_id` - The ID of the company applicant.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_additional_company_check_data(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<GetAdditionalCompanyCheckDataResponse, SumsubError> {
+        let path = Query::new("/resources/checks/latest")
+            .param("type", "COMPANY")
+            .param("applicantId", applicant_id)
+            .build();
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_response_and_deserialize(response).await
+    }
+
+    /// Starts AML screening for every officer and significant person found
+    /// by the latest company check of a company applicant, for enhanced due
+    /// diligence.
+    ///
+    /// A lightweight individual applicant is created at `level_name` for each
+    /// person, and an AML check is started. A person listed in several roles
+    /// is screened once. The external user ID of each applicant is derived
+    /// from the company applicant ID and the person's name and date of birth,
+    /// so screening the same company again reuses the existing applicants.
+    /// Persons are screened concurrently.
+    ///
+    /// AML checks run asynchronously; once they have completed (e.g., when
+    /// the `applicantReviewed` webhooks arrive), pass the returned screenings
+    /// to [`Client::get_person_screening_report`] to collect the hits.
     ///
     /// # Arguments
     ///
     /// * `applicant_id` - The ID of the company applicant.
-    /// * `fixed_info` - The company data to update.
-    pub async fn change_provided_company_data(
+    /// * `level_name` - The level of the screening applicants; it should include an AML check.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn screen_company_persons(
         &self,
-        applicant_id: &str,
-        fixed_info: FixedInfo,
-    ) -> Result<(), SumsubError> {
-        let path = format!("/resources/applicants/{}/fixedInfo", applicant_id);
-        let response = self.send_request(Method::PATCH, &path, Some(fixed_info)).await?;
-        self.handle_empty_response(response).await
+        applicant_id: &ApplicantId,
+        level_name: &str,
+    ) -> Result<Vec<crate::kyb::PersonScreening>, SumsubError> {
+        use futures::{StreamExt, TryStreamExt};
+
+        let check_data = self.get_additional_company_check_data(applicant_id).await?;
+        let persons = crate::kyb::merge_persons(
+            check_data
+                .checks
+                .iter()
+                .flat_map(|check| check.company_check_info.persons()),
+        );
+
+        let mut screenings: Vec<_> = futures::stream::iter(persons.into_iter().enumerate())
+            .map(|(n, person)| async move {
+                let (first_name, last_name) = person.split_name();
+                let request = CreateApplicantRequest {
+                    external_user_id: person.external_user_id(applicant_id),
+                    applicant_type: Some("individual".to_string()),
+                    fixed_info: Some(FixedInfo {
+                        first_name: Some(first_name),
+                        last_name,
+                        dob: person.dob.clone(),
+                        country: person.country.clone(),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                };
+                let applicant = self.create_or_get_applicant(request, level_name).await?;
+                self.run_aml_check(&applicant.id).await?;
+                Ok::<_, SumsubError>((n, crate::kyb::PersonScreening { person, applicant_id: applicant.id }))
+            })
+            .buffer_unordered(PERSON_SCREENING_CONCURRENCY)
+            .try_collect()
+            .await?;
+        screenings.sort_by_key(|(n, _)| *n);
+
+        Ok(screenings.into_iter().map(|(_, screening)| screening).collect())
     }
 
-    /// Gets additional company check data.
-    ///
-    /// [Sumsub API reference](https://docs.sumsub.com/reference/get-additional-company-check-data)
+    /// Collects the AML hits of persons screened by
+    /// [`Client::screen_company_persons`]. Call this once their AML checks
+    /// have completed; until then, the hits may be missing or stale.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_person_screening_report(
+        &self,
+        screenings: &[crate::kyb::PersonScreening],
+    ) -> Result<crate::kyb::PersonScreeningReport, SumsubError> {
+        use futures::{StreamExt, TryStreamExt};
+
+        let screenings = futures::stream::iter(screenings.to_vec())
+            .map(|screening| async move {
+                let aml = self.get_aml_case_data(&screening.applicant_id).await?;
+                Ok::<_, SumsubError>(crate::kyb::PersonScreeningResult {
+                    person: screening.person,
+                    applicant_id: screening.applicant_id,
+                    hits: aml.applicant.hits,
+                })
+            })
+            .buffered(PERSON_SCREENING_CONCURRENCY)
+            .try_collect()
+            .await?;
+
+        Ok(crate::kyb::PersonScreeningReport { screenings })
+    }
+
+    /// Searches public company registries, e.g., to autocomplete legal entity
+    /// data in an onboarding form before creating a company applicant.
     ///
     /// # Arguments
     ///
-    /// * `applicant_id` - The ID of the company applicant.
-    pub async fn get_additional_company_check_data(
+    /// * `query` - The name and/or registration number and country to search for.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn search_company_registry(
         &self,
-        applicant_id: &str,
-    ) -> Result<GetAdditionalCompanyCheckDataResponse, SumsubError> {
-        let path = format!(
-            "/resources/checks/latest?type=COMPANY&applicantId={}",
-            applicant_id
-        );
+        query: &crate::kyb::CompanySearchQuery,
+    ) -> Result<Vec<crate::kyb::CompanySearchResult>, SumsubError> {
+        let path = query.apply(Query::new("/resources/kyb/companies/search")).build();
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
-        self.handle_response_and_deserialize(response).await
+        let search: crate::kyb::CompanySearchResponse =
+            self.handle_response_and_deserialize(response).await?;
+        Ok(search.items)
     }
 
     /// Submits a transaction for an existing applicant.
@@ -514,9 +2124,10 @@ impl Client {
     ///
     /// * `applicant_id` - The ID of the applicant to submit the transaction for.
     /// * `request` - The transaction to submit.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn submit_transaction(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         request: SubmitTransactionRequest,
     ) -> Result<SubmitTransactionResponse, SumsubError> {
         let path = format!(
@@ -524,6 +2135,7 @@ impl Client {
             applicant_id
         );
         let response = self
+            .idempotent()
             .send_request(Method::POST, &path, Some(request))
             .await?;
         self.handle_response_and_deserialize(response).await
@@ -536,13 +2148,15 @@ impl Client {
     /// # Arguments
     ///
     /// * `request` - The transaction to submit.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn submit_transaction_for_non_existing_applicant(
         &self,
         request: SubmitTransactionRequest,
     ) -> Result<SubmitTransactionResponse, SumsubError> {
         let path = "/resources/applicants/-/kyt/txns/-/data";
         let response = self
-            .send_request(Method::POST, &path, Some(request))
+            .idempotent()
+            .send_request(Method::POST, path, Some(request))
             .await?;
         self.handle_response_and_deserialize(response).await
     }
@@ -554,9 +2168,10 @@ impl Client {
     /// # Arguments
     ///
     /// * `txn_id` - The ID of the transaction to delete.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn delete_transaction(
         &self,
-        txn_id: &str,
+        txn_id: &TxnId,
     ) -> Result<DeleteTransactionResponse, SumsubError> {
         let path = format!("/resources/kyt/txns/{}", txn_id);
         let response = self.send_request(Method::DELETE, &path, None::<()>).await?;
@@ -570,10 +2185,32 @@ impl Client {
     /// # Arguments
     ///
     /// * `requests` - A vector of transactions to import.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn bulk_transaction_import(
         &self,
         requests: Vec<BulkTransactionImportRequest>,
     ) -> Result<BulkTransactionImportResponse, SumsubError> {
+        let response = self.send_bulk_transaction_import(requests).await?;
+        self.handle_response_and_deserialize(response).await
+    }
+
+    /// Imports transactions in bulk, keeping the warnings returned for
+    /// partially imported batches.
+    ///
+    /// See [`Client::bulk_transaction_import`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn bulk_transaction_import_with_diagnostics(
+        &self,
+        requests: Vec<BulkTransactionImportRequest>,
+    ) -> Result<crate::response::ApiResponse<BulkTransactionImportResponse>, SumsubError> {
+        let response = self.send_bulk_transaction_import(requests).await?;
+        self.handle_response_with_diagnostics(response).await
+    }
+
+    async fn send_bulk_transaction_import(
+        &self,
+        requests: Vec<BulkTransactionImportRequest>,
+    ) -> Result<reqwest::Response, SumsubError> {
         let path = "/resources/kyt/misc/txns/import";
         let body = requests
             .into_iter()
@@ -582,31 +2219,8 @@ impl Client {
             .map_err(SumsubError::from)?
             .join("\n");
 
-        let ts = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-
-        let signature = sign_request(
-            &self.secret_key,
-            ts,
-            "POST",
-            path,
-            &Some(body.clone()),
-        );
-
-        let url = format!("{}{}", self.base_url, path);
-        let mut request_builder = self.http_client.request(Method::POST, &url);
-
-        request_builder = request_builder
-            .header("X-App-Token", &self.app_token)
-            .header("X-App-Access-Sig", signature)
-            .header("X-App-Access-Ts", ts.to_string())
-            .header("Content-Type", "application/x-ndjson")
-            .body(body);
-
-        let response = request_builder.send().await.map_err(SumsubError::from)?;
-        self.handle_response_and_deserialize(response).await
+        self.execute(Method::POST, path, RequestBody::NdJson(body))
+            .await
     }
 
     /// Initiates the SDK for a Travel Rule transaction.
@@ -616,13 +2230,14 @@ impl Client {
     /// # Arguments
     ///
     /// * `request` - The request to initiate the SDK.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn initiate_sdk_for_travel_rule_transaction(
         &self,
         request: InitiateSdkRequest,
     ) -> Result<InitiateSdkResponse, SumsubError> {
         let path = "/resources/tr/sdk/init";
         let response = self
-            .send_request(Method::POST, &path, Some(request))
+            .send_request(Method::POST, path, Some(request))
             .await?;
         self.handle_response_and_deserialize(response).await
     }
@@ -635,9 +2250,10 @@ impl Client {
     ///
     /// * `txn_id` - The ID of the transaction to patch.
     /// * `request` - The request to patch the transaction.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn patch_transaction_with_chain_transaction_id(
         &self,
-        txn_id: &str,
+        txn_id: &TxnId,
         request: PatchTransactionRequest,
     ) -> Result<SubmitTransactionResponse, SumsubError> {
         let path = format!("/resources/kyt/txns/{}/data/info", txn_id);
@@ -655,15 +2271,16 @@ impl Client {
     ///
     /// * `txn_id` - The ID of the transaction.
     /// * `status` - The ownership status to set.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn confirm_or_reject_transaction_ownership(
         &self,
-        txn_id: &str,
+        txn_id: &TxnId,
         status: OwnershipStatus,
     ) -> Result<SubmitTransactionResponse, SumsubError> {
         let path = format!(
             "/resources/kyt/txns/{}/ownership/{}",
             txn_id,
-            status.to_string()
+            status
         );
         let response = self.send_request(Method::POST, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
@@ -677,9 +2294,10 @@ impl Client {
     ///
     /// * `txn_id` - The ID of the transaction.
     /// * `request` - The request to confirm wallet ownership.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn confirm_wallet_ownership(
         &self,
-        txn_id: &str,
+        txn_id: &TxnId,
         request: ConfirmWalletOwnershipRequest,
     ) -> Result<SubmitTransactionResponse, SumsubError> {
         let path = format!("/resources/kyt/txns/{}/travelRuleOwnership", txn_id);
@@ -696,6 +2314,7 @@ impl Client {
     /// # Arguments
     ///
     /// * `requests` - A vector of wallet addresses to import.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn import_wallet_addresses(
         &self,
         requests: Vec<ImportWalletAddressesRequest>,
@@ -708,33 +2327,38 @@ impl Client {
             .map_err(SumsubError::from)?
             .join("\n");
 
-        let ts = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-
-        let signature = sign_request(
-            &self.secret_key,
-            ts,
-            "POST",
-            path,
-            &Some(body.clone()),
-        );
-
-        let url = format!("{}{}", self.base_url, path);
-        let mut request_builder = self.http_client.request(Method::POST, &url);
-
-        request_builder = request_builder
-            .header("X-App-Token", &self.app_token)
-            .header("X-App-Access-Sig", signature)
-            .header("X-App-Access-Ts", ts.to_string())
-            .header("Content-Type", "application/x-ndjson")
-            .body(body);
-
-        let response = request_builder.send().await.map_err(SumsubError::from)?;
+        let response = self
+            .execute(Method::POST, path, RequestBody::NdJson(body))
+            .await?;
         self.handle_response_and_deserialize(response).await
     }
 
+    /// Like [`Client::import_wallet_addresses`], but first checks the format
+    /// of every address (see [`crate::travel_rule::ImportWalletAddressesRequest::validate`]).
+    ///
+    /// If any address is invalid, nothing is imported and a
+    /// `SumsubError::Validation` listing every invalid row is returned.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn import_wallet_addresses_checked(
+        &self,
+        requests: Vec<ImportWalletAddressesRequest>,
+    ) -> Result<ImportWalletAddressesResponse, SumsubError> {
+        let errors: Vec<String> = requests
+            .iter()
+            .enumerate()
+            .filter_map(|(row, request)| {
+                request.validate().err().map(|e| match e {
+                    SumsubError::Validation(reason) => format!("row {}: {}", row, reason),
+                    e => format!("row {}: {}", row, e),
+                })
+            })
+            .collect();
+        if !errors.is_empty() {
+            return Err(SumsubError::Validation(errors.join("; ")));
+        }
+        self.import_wallet_addresses(requests).await
+    }
+
     /// Gets transaction data.
     ///
     /// [Sumsub API reference](https://docs.sumsub.com/reference/get-transaction-data)
@@ -742,9 +2366,10 @@ impl Client {
     /// # Arguments
     ///
     /// * `txn_id` - The ID of the transaction to get.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_transaction_data(
         &self,
-        txn_id: &str,
+        txn_id: &TxnId,
     ) -> Result<SubmitTransactionResponse, SumsubError> {
         let path = format!("/resources/kyt/txns/{}", txn_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
@@ -758,11 +2383,14 @@ impl Client {
     /// # Arguments
     ///
     /// * `applicant_id` - The ID of the applicant.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_all_transactions_for_applicant(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<Vec<SubmitTransactionResponse>, SumsubError> {
-        let path = format!("/resources/kyt/txns?applicantId={}", applicant_id);
+        let path = Query::new("/resources/kyt/txns")
+            .param("applicantId", applicant_id)
+            .build();
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
 
         #[derive(serde::Deserialize)]
@@ -773,6 +2401,79 @@ impl Client {
         Ok(list.list)
     }
 
+    /// Gets the aggregated transaction monitoring state of an applicant: the
+    /// current risk score, the transactions on hold, and the triggered rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `applicant_id` - The ID of the applicant.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_applicant_kyt_summary(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::transactions::KytApplicantSummary, SumsubError> {
+        let path = format!("/resources/applicants/{}/kyt/summary", applicant_id);
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_response_and_deserialize(response).await
+    }
+
+    /// Gets all transactions created or changed since `since`
+    /// (`YYYY-MM-DD HH:MM:SS`), following pages until the last one.
+    ///
+    /// Pass the returned `next_since` to the next call to get an incremental
+    /// change feed. See [`crate::sync::SyncBatch`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn sync_transactions_since(
+        &self,
+        since: &str,
+    ) -> Result<crate::sync::SyncBatch<SubmitTransactionResponse>, SumsubError> {
+        self.sync_since("/resources/kyt/txns/-/list", since).await
+    }
+
+    /// Pages through a list endpoint filtered by `updatedAtFrom`, tracking the
+    /// latest change among the returned records. See
+    /// [`crate::sync::SyncBatch`] for how far `next_since` advances.
+    async fn sync_since<T: for<'de> Deserialize<'de>>(
+        &self,
+        list_path: &str,
+        since: &str,
+    ) -> Result<crate::sync::SyncBatch<T>, SumsubError> {
+        #[derive(Deserialize)]
+        struct Page {
+            items: Vec<serde_json::Value>,
+        }
+
+        let since_secs = crate::sync::parse_timestamp(since).ok_or_else(|| {
+            SumsubError::Validation(format!("invalid sync timestamp (expected YYYY-MM-DD HH:MM:SS): {}", since))
+        })?;
+        let started = unix_secs(self.inner.clock.now()) + self.clock_offset() - crate::sync::SYNC_OVERLAP_SECS;
+        let mut items = Vec::new();
+        let mut latest = since_secs;
+        let mut offset = 0;
+        loop {
+            let path = Query::new(list_path)
+                .param("updatedAtFrom", since)
+                .param("offset", offset)
+                .param("limit", DEFAULT_PAGE_SIZE)
+                .build();
+            let response = self.send_request(Method::GET, &path, None::<()>).await?;
+            let page: Page = self.handle_response_and_deserialize(response).await?;
+            let fetched = page.items.len() as u32;
+            for item in page.items {
+                if let Some(changed_at) = crate::sync::changed_at(&item).and_then(crate::sync::parse_timestamp) {
+                    latest = latest.max(changed_at);
+                }
+                items.push(serde_json::from_value(item)?);
+            }
+            if fetched < DEFAULT_PAGE_SIZE {
+                break;
+            }
+            offset += fetched;
+        }
+        let next_since = crate::sync::format_timestamp(latest.min(started).max(since_secs));
+        Ok(crate::sync::SyncBatch { items, next_since })
+    }
+
     /// Sets the block status for a transaction.
     ///
     /// [Sumsub API reference](https://docs.sumsub.com/reference/set-transaction-block)
@@ -781,9 +2482,10 @@ impl Client {
     ///
     /// * `txn_id` - The ID of the transaction.
     /// * `request` - The request to set the block status.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn set_transaction_block(
         &self,
-        txn_id: &str,
+        txn_id: &TxnId,
         request: SetTransactionBlockRequest,
     ) -> Result<SubmitTransactionResponse, SumsubError> {
         let path = format!("/resources/kyt/txns/{}/block", txn_id);
@@ -797,24 +2499,25 @@ impl Client {
 
     /// Moves an applicant to a different verification level.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#change-level-and-reset-steps)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn move_applicant_to_level(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         level_name: &str,
     ) -> Result<(), SumsubError> {
-        let path = format!(
-            "/resources/applicants/{}/moveToLevel?levelName={}",
-            applicant_id, level_name
-        );
+        let path = Query::new(format!("/resources/applicants/{}/moveToLevel", applicant_id))
+            .param("levelName", level_name)
+            .build();
         let response = self.send_request(Method::POST, &path, None::<()>).await?;
         self.handle_empty_response(response).await
     }
 
     /// Updates fixed information for an applicant.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#updating-fixed-applicant-info)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn update_applicant_fixed_info(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         fixed_info: FixedInfo,
     ) -> Result<(), SumsubError> {
         let path = format!("/resources/applicants/{}/fixedInfo", applicant_id);
@@ -824,11 +2527,44 @@ impl Client {
         self.handle_empty_response(response).await
     }
 
+    /// Updates the fixed information of an applicant to match `fixed_info`,
+    /// sending only the fields that changed.
+    ///
+    /// The current fixed information is fetched first; if it already matches,
+    /// no update is sent, avoiding review resets triggered by no-op updates.
+    /// Fields that are `None` in `fixed_info` are left unchanged. Returns
+    /// `true` if an update was sent.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn ensure_fixed_info(
+        &self,
+        applicant_id: &ApplicantId,
+        fixed_info: FixedInfo,
+    ) -> Result<bool, SumsubError> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ApplicantFixedInfo {
+            #[serde(default)]
+            fixed_info: FixedInfo,
+        }
+
+        let path = format!("/resources/applicants/{}/one", applicant_id);
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        let current: ApplicantFixedInfo = self.handle_response_and_deserialize(response).await?;
+        match fixed_info.changes_from(&current.fixed_info) {
+            Some(changes) => {
+                self.update_applicant_fixed_info(applicant_id, changes).await?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
     /// Retrieves the review status for an applicant.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#retrieving-review-status)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_applicant_status(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<ApplicantStatus, SumsubError> {
         let path = format!("/resources/applicants/{}/status", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
@@ -837,41 +2573,93 @@ impl Client {
 
     /// Retrieves moderation states for an applicant to clarify rejections.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#clarify-rejection-reason)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_applicant_moderation_states(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<Vec<ModerationState>, SumsubError> {
         let path = format!("/resources/moderationStates/-;applicantId={}", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
-    /// Requests a re-check for an applicant.
+    /// Retrieves a single page of moderation states for an applicant.
+    /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#clarify-rejection-reason)
+    ///
+    /// # Arguments
+    ///
+    /// * `applicant_id` - The ID of the applicant.
+    /// * `offset` - The number of records to skip.
+    /// * `limit` - The maximum number of records to return.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_applicant_moderation_states_page(
+        &self,
+        applicant_id: &ApplicantId,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<ModerationState>, SumsubError> {
+        let path = Query::new(format!("/resources/moderationStates/-;applicantId={}", applicant_id))
+            .param("offset", offset)
+            .param("limit", limit)
+            .build();
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_response_and_deserialize(response).await
+    }
+
+    /// Retrieves all moderation states for an applicant by following pages
+    /// until the last one, sorted by creation time (oldest first).
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_all_applicant_moderation_states(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<ModerationState>, SumsubError> {
+        let mut states = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = self
+                .get_applicant_moderation_states_page(applicant_id, offset, DEFAULT_PAGE_SIZE)
+                .await?;
+            let fetched = page.len() as u32;
+            states.extend(page);
+            if fetched < DEFAULT_PAGE_SIZE {
+                break;
+            }
+            offset += fetched;
+        }
+        states.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        Ok(states)
+    }
+
+    /// Requests a re-check for an applicant, returning the API's
+    /// acknowledgement.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#request-re-check)
-    pub async fn request_applicant_recheck(&self, applicant_id: &str) -> Result<(), SumsubError> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn request_applicant_recheck(&self, applicant_id: &ApplicantId) -> Result<OperationResult, SumsubError> {
         let path = format!("/resources/applicants/{}/status/pending", applicant_id);
         let response = self.send_request(Method::POST, &path, None::<()>).await?;
-        self.handle_empty_response(response).await
+        self.handle_response_or_default(response).await
     }
 
-    /// Adds an applicant to the blocklist.
+    /// Adds an applicant to the blocklist, returning the updated applicant.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#add-to-blocklist)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn add_applicant_to_blocklist(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         note: String,
-    ) -> Result<(), SumsubError> {
+    ) -> Result<Applicant, SumsubError> {
         let path = format!("/resources/applicants/{}/blacklist", applicant_id);
         let request = BlacklistRequest { note };
         let response = self.send_request(Method::POST, &path, Some(request)).await?;
-        self.handle_empty_response(response).await
+        self.handle_response_and_deserialize(response).await
     }
 
     /// Creates a share token for an applicant.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#reusable-kyc)
-    pub async fn create_share_token<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn create_share_token(
         &self,
-        request: ShareTokenRequest<'a>,
+        request: ShareTokenRequest<'_>,
     ) -> Result<ShareTokenResponse, SumsubError> {
         let path = "/resources/accessTokens/-/shareToken";
         let response = self.send_request(Method::POST, path, Some(request)).await?;
@@ -880,9 +2668,10 @@ impl Client {
 
     /// Imports a shared applicant.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#reusable-kyc)
-    pub async fn import_shared_applicant<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn import_shared_applicant(
         &self,
-        token: &'a str,
+        token: &str,
     ) -> Result<ImportApplicantResponse, SumsubError> {
         let path = "/resources/applicants/-/import";
         let request = ImportApplicantRequest { token };
@@ -892,9 +2681,10 @@ impl Client {
 
     /// Resets a single verification step for an applicant.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#reset-an-applicants-step)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn reset_applicant_step(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         id_doc_set_type: &str,
     ) -> Result<(), SumsubError> {
         let path = format!(
@@ -907,7 +2697,8 @@ impl Client {
 
     /// Resets an applicant entirely.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#reset-an-applicant)
-    pub async fn reset_applicant(&self, applicant_id: &str) -> Result<(), SumsubError> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn reset_applicant(&self, applicant_id: &ApplicantId) -> Result<(), SumsubError> {
         let path = format!("/resources/applicants/{}/reset", applicant_id);
         let response = self.send_request(Method::POST, &path, None::<()>).await?;
         self.handle_empty_response(response).await
@@ -915,6 +2706,7 @@ impl Client {
 
     /// Ingests a completed applicant profile.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#batch-import-of-completed-applicants)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn ingest_completed_applicant(
         &self,
         request: IngestCompletedRequest,
@@ -924,11 +2716,116 @@ impl Client {
         self.handle_empty_response(response).await
     }
 
+    /// Runs `f` for every item with at most `options.concurrency` items in
+    /// flight, e.g., to fetch the statuses of thousands of applicants.
+    ///
+    /// Items are not retried by default. With `options.max_retries` set, items
+    /// failing with a transient error (see [`SumsubError::is_retryable`]) are
+    /// retried with exponential backoff; only set it if `f` is safe to repeat.
+    /// A failing item does not abort the batch; one outcome is returned per
+    /// item, in input order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use sumsub_api::batch::BatchOptions;
+    ///
+    /// # async fn run(client: sumsub_api::client::Client, applicant_ids: Vec<sumsub_api::ids::ApplicantId>) {
+    /// let outcomes = client
+    ///     .batch(applicant_ids, BatchOptions::default(), |client, id| async move {
+    ///         client.get_applicant_status(&id).await
+    ///     })
+    ///     .await;
+    /// for outcome in outcomes {
+    ///     if let Err(e) = outcome.result {
+    ///         eprintln!("{}: {}", outcome.item, e);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn batch<I, T, F, Fut>(
+        &self,
+        items: impl IntoIterator<Item = I>,
+        options: crate::batch::BatchOptions,
+        f: F,
+    ) -> Vec<crate::batch::BatchOutcome<I, T>>
+    where
+        I: Clone,
+        F: Fn(Client, I) -> Fut,
+        Fut: std::future::Future<Output = Result<T, SumsubError>>,
+    {
+        use futures::StreamExt;
+
+        let options = &options;
+        let f = &f;
+        futures::stream::iter(items)
+            .map(|item| async move {
+                let (result, attempts) = crate::policy::retry(options.max_retries, options.retry_delay, || {
+                    f(self.clone(), item.clone())
+                })
+                .await;
+                crate::batch::BatchOutcome { item, attempts, result }
+            })
+            .buffered(options.concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Ingests completed applicant profiles in bulk, e.g., when migrating
+    /// historical KYC records.
+    ///
+    /// Records are submitted concurrently in chunks of `options.chunk_size`.
+    /// Records are not retried by default, because a request that timed out
+    /// may still have created the applicant. With `options.max_retries` set,
+    /// records failing with a transient error (see
+    /// [`SumsubError::is_retryable`]) are retried with exponential backoff.
+    /// A failing record does not abort the import; one outcome is returned
+    /// per record, in input order.
+    ///
+    /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#batch-import-of-completed-applicants)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn ingest_completed_applicants<I>(
+        &self,
+        requests: I,
+        options: BulkIngestOptions,
+    ) -> Vec<IngestOutcome>
+    where
+        I: IntoIterator<Item = IngestCompletedRequest>,
+    {
+        let path = "/resources/applicants/-/ingestCompleted";
+        let requests: Vec<_> = requests.into_iter().collect();
+        let mut outcomes = Vec::with_capacity(requests.len());
+
+        for chunk in requests.chunks(options.chunk_size.max(1)) {
+            let chunk_outcomes = futures::future::join_all(chunk.iter().map(|request| {
+                let options = &options;
+                async move {
+                    let (result, attempts) = crate::policy::retry(options.max_retries, options.retry_delay, || async {
+                        let response = self.send_request(Method::POST, path, Some(request)).await?;
+                        self.handle_empty_response(response).await
+                    })
+                    .await;
+                    IngestOutcome {
+                        external_user_id: request.applicant.external_user_id.clone(),
+                        attempts,
+                        result,
+                    }
+                }
+            }))
+            .await;
+            outcomes.extend(chunk_outcomes);
+        }
+
+        outcomes
+    }
+
     /// Updates top-level applicant data (e.g., email, phone).
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#updating-top-level-applicant-data)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn update_applicant_top_level_data(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         request: UpdateApplicantRequest,
     ) -> Result<Applicant, SumsubError> {
         let path = format!("/resources/applicants/{}", applicant_id);
@@ -940,82 +2837,111 @@ impl Client {
 
     // Checks Section
 
-    /// Starts a specific check for an applicant.
+    /// Starts a specific check for an applicant, returning the API's
+    /// acknowledgement. Fetch the result with [`Client::get_latest_check_result`].
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#starting-specific-checks)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn start_check(
         &self,
         request: StartCheckRequest<'_>,
-    ) -> Result<serde_json::Value, SumsubError> {
+    ) -> Result<OperationResult, SumsubError> {
         let path = "/resources/checks";
         let response = self.send_request(Method::POST, path, Some(request)).await?;
-        self.handle_response_and_deserialize(response).await
+        self.handle_response_or_default(response).await
     }
 
     /// Retrieves the latest check results for an applicant.
     /// The return type `T` must be a struct that can be deserialized from the JSON response for the given `check_type`.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#retrieving-check-results)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_latest_check_result<T: for<'de> serde::Deserialize<'de>>(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         check_type: CheckType,
     ) -> Result<T, SumsubError> {
-        let path = format!(
-            "/resources/checks/latest?type={}&applicantId={}",
-            check_type.to_string(),
-            applicant_id
-        );
+        let path = Query::new("/resources/checks/latest")
+            .param("type", check_type)
+            .param("applicantId", applicant_id)
+            .build();
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
     // Additional/Supplemental Methods
 
+    /// Returns `level_name`, or the default level if it is `None`.
+    fn level_or_default<'a>(&'a self, level_name: Option<&'a str>) -> Result<&'a str, SumsubError> {
+        level_name
+            .or(self.inner.default_level_name.as_deref())
+            .ok_or_else(|| {
+                SumsubError::Validation(
+                    "no level name given and no default level configured".to_string(),
+                )
+            })
+    }
+
+    /// Returns `ttl_in_secs`, or the default token lifetime if it is `None`.
+    fn ttl_or_default(&self, ttl_in_secs: Option<u64>) -> Option<u64> {
+        ttl_in_secs.or(self.inner.default_token_ttl.map(|ttl| ttl.as_secs()))
+    }
+
     /// Generates an external WebSDK link.
     ///
+    /// If `level_name` or `ttl_in_secs` is `None`, the defaults configured with
+    /// [`ClientBuilder::default_level_name`] and
+    /// [`ClientBuilder::default_token_ttl`] are used.
+    ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#generate-external-websdk-link)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn generate_external_websdk_link(
         &self,
-        level_name: &str,
-        external_user_id: Option<&str>,
+        level_name: Option<&str>,
+        external_user_id: Option<&ExternalUserId>,
         ttl_in_secs: Option<u64>,
     ) -> Result<GenerateWebsdkLinkResponse, SumsubError> {
         let path = "/resources/accessTokens/-/websdkLink";
         let request = GenerateWebsdkLinkRequest {
-            level_name,
+            level_name: self.level_or_default(level_name)?,
             external_user_id,
-            ttl_in_secs,
+            ttl_in_secs: self.ttl_or_default(ttl_in_secs),
         };
-        let response = self.send_request(Method::POST, &path, Some(request)).await?;
+        let response = self.send_request(Method::POST, path, Some(request)).await?;
         self.handle_response_and_deserialize(response).await
     }
 
     /// Generates an access token for a new applicant for the WebSDK.
+    ///
+    /// If `level_name` or `ttl_in_secs` is `None`, the defaults configured with
+    /// [`ClientBuilder::default_level_name`] and
+    /// [`ClientBuilder::default_token_ttl`] are used.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#generate-access-token)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn generate_token_for_new_applicant(
         &self,
-        level_name: &str,
-        external_user_id: Option<&str>,
+        level_name: Option<&str>,
+        external_user_id: Option<&ExternalUserId>,
         ttl_in_secs: Option<u64>,
     ) -> Result<NewApplicantAccessTokenResponse, SumsubError> {
-        let mut path = format!("/resources/accessTokens?levelName={}", level_name);
-        if let Some(id) = external_user_id {
-            path.push_str(&format!("&externalUserId={}", id));
-        }
-        if let Some(ttl) = ttl_in_secs {
-            path.push_str(&format!("&ttlInSecs={}", ttl));
-        }
+        let path = Query::new("/resources/accessTokens")
+            .param("levelName", self.level_or_default(level_name)?)
+            .opt_param("externalUserId", external_user_id)
+            .opt_param("ttlInSecs", self.ttl_or_default(ttl_in_secs))
+            .build();
         let response = self.send_request(Method::POST, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
     /// Generates an access token for an existing applicant for the WebSDK.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#access-tokens-for-existing-users)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn generate_token_for_existing_applicant(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         level_name: &str,
     ) -> Result<String, SumsubError> {
-        let path = format!("/resources/applicants/{}/accessTokens?levelName={}", applicant_id, level_name);
+        let path = Query::new(format!("/resources/applicants/{}/accessTokens", applicant_id))
+            .param("levelName", level_name)
+            .build();
         let response = self.send_request(Method::POST, &path, None::<()>).await?;
 
         #[derive(Deserialize)]
@@ -1029,9 +2955,10 @@ impl Client {
 
     /// Retrieves similar applicants by text and face.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#retrieving-similar-applicantsduplicates)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_similar_applicants_by_text_and_face(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<SimilarByTextAndFaceResult, SumsubError> {
         let path = format!("/resources/applicants/{}/similar/byTextAndFace", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
@@ -1040,21 +2967,73 @@ impl Client {
 
     /// Retrieves applicant events/logs.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#retrieving-applicant-eventslogs)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_applicant_events(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<Vec<ApplicantEvent>, SumsubError> {
         let path = format!("/resources/applicants/{}/events", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
+    /// Gets a single page of events for an applicant, matching `filter`.
+    ///
+    /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#retrieving-applicant-eventslogs)
+    ///
+    /// # Arguments
+    ///
+    /// * `applicant_id` - The ID of the applicant.
+    /// * `filter` - The date range and event types to filter by.
+    /// * `offset` - The number of records to skip.
+    /// * `limit` - The maximum number of records to return.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_applicant_events_page(
+        &self,
+        applicant_id: &ApplicantId,
+        filter: &crate::applicants::EventFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<ApplicantEvent>, SumsubError> {
+        let query = Query::new(format!("/resources/applicants/{}/events", applicant_id))
+            .param("offset", offset)
+            .param("limit", limit);
+        let path = filter.apply(query).build();
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_response_and_deserialize(response).await
+    }
+
+    /// Gets all events for an applicant matching `filter` by following pages
+    /// until the last one.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_all_applicant_events(
+        &self,
+        applicant_id: &ApplicantId,
+        filter: &crate::applicants::EventFilter,
+    ) -> Result<Vec<ApplicantEvent>, SumsubError> {
+        let mut events = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = self
+                .get_applicant_events_page(applicant_id, filter, offset, DEFAULT_PAGE_SIZE)
+                .await?;
+            let fetched = page.len() as u32;
+            events.extend(page);
+            if fetched < DEFAULT_PAGE_SIZE {
+                break;
+            }
+            offset += fetched;
+        }
+        Ok(events)
+    }
+
     /// Sends a verification email to the applicant.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#sending-verification-emails)
-    pub async fn send_verification_email<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn send_verification_email(
         &self,
-        applicant_id: &str,
-        request: SendVerificationMessageRequest<'a>,
+        applicant_id: &ApplicantId,
+        request: SendVerificationMessageRequest<'_>,
     ) -> Result<(), SumsubError> {
         let path = format!("/resources/applicants/{}/info/email/send", applicant_id);
         let response = self.send_request(Method::POST, &path, Some(request)).await?;
@@ -1063,41 +3042,252 @@ impl Client {
 
     /// Retrieves the liveness video.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#retrieving-liveness-resultsvideos)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_liveness_video(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<Vec<u8>, SumsubError> {
         let path = format!("/resources/applicants/{}/info/facemap/video", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let message = response.text().await.unwrap_or_else(|_| "Could not read error body".to_string());
-            return Err(SumsubError::ApiError { status, message });
-        }
-        Ok(response.bytes().await?.to_vec())
+        self.handle_bytes_response(response).await
+    }
+
+    /// Streams the liveness video instead of loading it into memory, e.g., to
+    /// pipe it to a file or object storage.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// # async fn run(client: sumsub_api::client::Client) -> Result<(), sumsub_api::error::SumsubError> {
+    /// let mut video = client.get_liveness_video_stream(&"applicant-id".into()).await?;
+    /// let mut file = tokio::fs::File::create("liveness.webm").await?;
+    /// while let Some(chunk) = video.try_next().await? {
+    ///     file.write_all(&chunk).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_liveness_video_stream(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::response::ByteStream, SumsubError> {
+        let path = format!("/resources/applicants/{}/info/facemap/video", applicant_id);
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_stream_response(response).await
+    }
+
+    /// Gets a temporary download link for the liveness video instead of its
+    /// bytes, e.g., to let a browser fetch it directly from storage.
+    ///
+    /// Returns `None` if the API serves the video directly rather than
+    /// redirecting to a link; use [`Client::get_liveness_video`] then.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_liveness_video_link(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Option<String>, SumsubError> {
+        let path = format!("/resources/applicants/{}/info/facemap/video", applicant_id);
+        self.media_link(&path).await
     }
 
     /// Retrieves a PDF report of the verification.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#generating-pdf-reports)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_verification_pdf_report(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<Vec<u8>, SumsubError> {
         let path = format!("/resources/applicants/{}/requiredIdDocsStatus.pdf", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let message = response.text().await.unwrap_or_else(|_| "Could not read error body".to_string());
-            return Err(SumsubError::ApiError { status, message });
-        }
-        Ok(response.bytes().await?.to_vec())
+        self.handle_bytes_response(response).await
+    }
+
+    /// Streams the PDF report of the verification instead of loading it into
+    /// memory. See [`Client::get_verification_pdf_report`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_verification_pdf_report_stream(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::response::ByteStream, SumsubError> {
+        let path = format!("/resources/applicants/{}/requiredIdDocsStatus.pdf", applicant_id);
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_stream_response(response).await
+    }
+
+    /// Retrieves a PDF report of the verification, keeping the URL it was
+    /// finally downloaded from (e.g., a storage URL the API redirected to).
+    ///
+    /// See [`Client::get_verification_pdf_report`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_verification_pdf_report_with_diagnostics(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::response::ApiResponse<Vec<u8>>, SumsubError> {
+        let path = format!("/resources/applicants/{}/requiredIdDocsStatus.pdf", applicant_id);
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_bytes_response_with_diagnostics(response).await
+    }
+
+    /// Collects the applicant data, agreement, level consents, notes, and
+    /// audit trail events of an applicant into a single export, e.g., to
+    /// answer a data subject access request.
+    ///
+    /// Everything except the level consents, which depend on the applicant's
+    /// level, is fetched concurrently. All pages of notes are read.
+    ///
+    /// The audit trail cannot be filtered by applicant, so its events are
+    /// read account-wide, one request per page of 100, and those of the
+    /// applicant are kept. At most `max_audit_trail_pages` pages are read;
+    /// on a busy account the whole audit trail can take thousands of
+    /// requests, so choose the cap with the rate limit in mind, or pass 0 to
+    /// skip the audit trail. `DataSubjectExport::audit_trail_complete` tells
+    /// whether the last page was reached.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn export_data_subject_record(
+        &self,
+        applicant_id: &ApplicantId,
+        max_audit_trail_pages: u32,
+    ) -> Result<crate::export::DataSubjectExport, SumsubError> {
+        let applicant = async {
+            let path = format!("/resources/applicants/{}/one", applicant_id);
+            let response = self.send_request(Method::GET, &path, None::<()>).await?;
+            self.handle_response_and_deserialize::<serde_json::Value>(response).await
+        };
+        let agreement = async {
+            match self.get_applicant_agreement(applicant_id).await {
+                Ok(agreement) => Ok(Some(agreement)),
+                Err(SumsubError::NotFound { .. }) => Ok(None),
+                Err(e) => Err(e),
+            }
+        };
+        let all_notes = crate::applicants::NoteFilter::default();
+        let notes = self.get_all_applicant_notes(applicant_id, &all_notes);
+        let audit_trail = async {
+            let mut events = Vec::new();
+            let mut offset = 0;
+            for _ in 0..max_audit_trail_pages {
+                let page = self.get_audit_trail_events_page(offset, DEFAULT_PAGE_SIZE).await?;
+                let fetched = page.len() as u32;
+                events.extend(
+                    page.into_iter()
+                        .filter(|event| event.applicant_id.as_deref() == Some(applicant_id)),
+                );
+                if fetched < DEFAULT_PAGE_SIZE {
+                    return Ok::<_, SumsubError>((events, true));
+                }
+                offset += fetched;
+            }
+            Ok((events, false))
+        };
+        let (applicant, agreement, notes, (audit_trail, audit_trail_complete)) =
+            futures::try_join!(applicant, agreement, notes, audit_trail)?;
+
+        let level_consents = match applicant["review"]["levelName"].as_str() {
+            Some(level_name) => Some(self.get_applicant_facing_consents(level_name).await?),
+            None => None,
+        };
+
+        Ok(crate::export::DataSubjectExport {
+            applicant_id: applicant_id.clone(),
+            applicant,
+            agreement,
+            level_consents,
+            notes,
+            audit_trail,
+            audit_trail_complete,
+        })
+    }
+
+    /// Exports everything Sumsub holds about an applicant into `dest_dir`,
+    /// e.g., for legal hold requests.
+    ///
+    /// The applicant data, all document images, the liveness video (if any),
+    /// and the PDF report are downloaded concurrently. See
+    /// [`ApplicantBundle`](crate::export::ApplicantBundle) for the layout of
+    /// the written files.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn export_applicant_bundle(
+        &self,
+        applicant_id: &ApplicantId,
+        dest_dir: impl AsRef<std::path::Path>,
+    ) -> Result<crate::export::ApplicantBundle, SumsubError> {
+        let dest_dir = dest_dir.as_ref();
+        let images_dir = dest_dir.join("images");
+        tokio::fs::create_dir_all(&images_dir).await?;
+
+        let applicant_json = async {
+            let path = format!("/resources/applicants/{}/one", applicant_id);
+            let response = self.send_request(Method::GET, &path, None::<()>).await?;
+            let content = self.handle_bytes_response(response).await?;
+            let file = dest_dir.join("applicant.json");
+            tokio::fs::write(&file, content).await?;
+            Ok::<_, SumsubError>(file)
+        };
+
+        let images = async {
+            use futures::{StreamExt, TryStreamExt};
+
+            let infos = self.get_document_images_info(applicant_id).await?;
+            let images_dir = &images_dir;
+            futures::stream::iter(infos)
+                .map(|info| async move {
+                    let content = self
+                        .get_document_image(applicant_id, &info.inspection_id, &info.image_id)
+                        .await?;
+                    let file = images_dir.join(format!(
+                        "{}_{}.{}",
+                        crate::export::file_name_part(&info.id_doc_type),
+                        crate::export::file_name_part(&info.image_id),
+                        crate::export::file_extension(&content)
+                    ));
+                    tokio::fs::write(&file, content).await?;
+                    Ok::<_, SumsubError>(file)
+                })
+                .buffer_unordered(BUNDLE_IMAGE_CONCURRENCY)
+                .try_collect::<Vec<_>>()
+                .await
+        };
+
+        let liveness_video = async {
+            match self.get_liveness_video(applicant_id).await {
+                Ok(content) => {
+                    let file = dest_dir.join(format!("liveness.{}", crate::export::file_extension(&content)));
+                    tokio::fs::write(&file, content).await?;
+                    Ok(Some(file))
+                }
+                Err(SumsubError::NotFound { .. }) => Ok(None),
+                Err(e) => Err(e),
+            }
+        };
+
+        let report = async {
+            let content = self.get_verification_pdf_report(applicant_id).await?;
+            let file = dest_dir.join("report.pdf");
+            tokio::fs::write(&file, content).await?;
+            Ok::<_, SumsubError>(file)
+        };
+
+        let (applicant_json, images, liveness_video, report) =
+            tokio::try_join!(applicant_json, images, liveness_video, report)?;
+
+        Ok(crate::export::ApplicantBundle {
+            applicant_json,
+            images,
+            liveness_video,
+            report,
+        })
     }
 
     /// Changes applicant data in the `info` field.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#changing-applicant-data)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn change_applicant_data(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         info: crate::models::Info,
     ) -> Result<crate::models::Applicant, SumsubError> {
         let path = format!("/resources/applicants/{}/info", applicant_id);
@@ -1107,9 +3297,10 @@ impl Client {
 
     /// Retrieves the list of available verification levels.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#retrieving-available-levels)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_available_levels(&self) -> Result<Vec<AvailableLevel>, SumsubError> {
         let path = "/resources/sdkIntegrations/levels";
-        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        let response = self.send_request(Method::GET, path, None::<()>).await?;
 
         #[derive(Deserialize, Debug)]
         struct LevelsResponse {
@@ -1121,10 +3312,11 @@ impl Client {
 
     /// Sends a verification SMS to the applicant's phone.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#sending-verification-sms)
-    pub async fn send_verification_phone_sms<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn send_verification_phone_sms(
         &self,
-        applicant_id: &str,
-        request: SendVerificationMessageRequest<'a>,
+        applicant_id: &ApplicantId,
+        request: SendVerificationMessageRequest<'_>,
     ) -> Result<(), SumsubError> {
         let path = format!("/resources/applicants/{}/info/phone/send", applicant_id);
         let response = self.send_request(Method::POST, &path, Some(request)).await?;
@@ -1133,91 +3325,128 @@ impl Client {
 
     /// Retrieves a ZIP archive report of the verification.
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#generating-pdf-reports)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_verification_zip_report(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<Vec<u8>, SumsubError> {
         let path = format!("/resources/applicants/{}/requiredIdDocsStatus.zip", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let message = response.text().await.unwrap_or_else(|_| "Could not read error body".to_string());
-            return Err(SumsubError::ApiError { status, message });
-        }
-        Ok(response.bytes().await?.to_vec())
+        self.handle_bytes_response(response).await
+    }
+
+    /// Streams a ZIP archive report of the verification instead of loading
+    /// it into memory. See [`Client::get_verification_zip_report`] and
+    /// `archive::ReportEntries` for reading its entries.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_verification_zip_report_stream(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::response::ByteStream, SumsubError> {
+        let path = format!("/resources/applicants/{}/requiredIdDocsStatus.zip", applicant_id);
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_stream_response(response).await
     }
 
     /// Adds a verification document to an applicant.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#add-verification-documents)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn add_verification_document(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         metadata: crate::applicants::AddDocumentMetadata<'_>,
         content: Vec<u8>,
         file_name: &str,
         mime_type: &str,
     ) -> Result<(), SumsubError> {
+        let response = self
+            .send_verification_document(applicant_id, metadata, content, file_name, mime_type)
+            .await?;
+        self.handle_empty_response(response).await
+    }
+
+    /// Adds a verification document to an applicant, keeping the warnings
+    /// returned for the document (e.g., image quality warnings).
+    ///
+    /// See [`Client::add_verification_document`].
+    #[cfg(feature = "raw-api")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn add_verification_document_with_diagnostics(
+        &self,
+        applicant_id: &ApplicantId,
+        metadata: crate::applicants::AddDocumentMetadata<'_>,
+        content: Vec<u8>,
+        file_name: &str,
+        mime_type: &str,
+    ) -> Result<crate::response::ApiResponse<serde_json::Value>, SumsubError> {
+        let response = self
+            .send_verification_document(applicant_id, metadata, content, file_name, mime_type)
+            .await?;
+        self.handle_response_with_diagnostics(response).await
+    }
+
+    async fn send_verification_document(
+        &self,
+        applicant_id: &ApplicantId,
+        metadata: crate::applicants::AddDocumentMetadata<'_>,
+        content: Vec<u8>,
+        file_name: &str,
+        mime_type: &str,
+    ) -> Result<reqwest::Response, SumsubError> {
         let path = format!("/resources/applicants/{}/docsets/-", applicant_id);
 
         let metadata_str = serde_json::to_string(&metadata)?;
 
-        let part = reqwest::multipart::Part::bytes(content)
-            .file_name(file_name.to_string())
-            .mime_str(mime_type)
-            .map_err(|e| SumsubError::MimeError(e.to_string()))?;
-
-        let form = reqwest::multipart::Form::new()
-            .part("metadata", reqwest::multipart::Part::text(metadata_str))
-            .part("content", part);
-
-        let ts = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-
-        let signature = sign_request(
-            &self.secret_key,
-            ts,
-            "POST",
-            &path,
-            &None,
-        );
-
-        let url = format!("{}{}", self.base_url, &path);
-        let response = self
-            .http_client
-            .post(&url)
-            .header("X-App-Token", &self.app_token)
-            .header("X-App-Access-Sig", signature)
-            .header("X-App-Access-Ts", ts.to_string())
-            .multipart(form)
-            .send()
-            .await?;
+        let form = MultipartForm::new()
+            .text("metadata", &metadata_str)
+            .file("content", content, file_name, mime_type)?;
 
-        self.handle_empty_response(response).await
+        self.idempotent()
+            .execute(Method::POST, &path, RequestBody::Multipart(form.finish()))
+            .await
     }
 
     /// Copies an applicant profile.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#copy-applicant-profile)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn copy_applicant_profile(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<crate::models::Applicant, SumsubError> {
         let path = format!("/resources/applicants/{}/duplicate", applicant_id);
         let response = self.send_request(Method::POST, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
+    /// Returns a `SumsubError::Configuration` if requests would affect
+    /// production applicants, i.e., on [`Environment::Production`] with an
+    /// app token that is not a sandbox (`sbx:`) token.
+    fn ensure_sandbox(&self) -> Result<(), SumsubError> {
+        if self.inner.environment == Environment::Production
+            && !self.inner.app_token.expose_secret().starts_with("sbx:")
+        {
+            return Err(SumsubError::Configuration(
+                "sandbox operations require a sandbox (sbx:) app token".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
     /// Simulates a review response in the Sandbox environment.
     ///
+    /// On [`Environment::Production`], returns a `SumsubError::Configuration`
+    /// without sending the request unless the app token is a sandbox token.
+    ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#simulate-review-response-in-sandbox)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn simulate_review_response(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         request: crate::applicants::SimulateReviewRequest<'_>,
     ) -> Result<(), SumsubError> {
+        self.ensure_sandbox()?;
         let path = format!(
             "/resources/applicants/{}/sandbox/status/testCompleted",
             applicant_id
@@ -1226,33 +3455,165 @@ impl Client {
         self.handle_empty_response(response).await
     }
 
-    /// Runs an AML check for an applicant.
+    /// Simulates the rejection of a single document set in the Sandbox
+    /// environment, e.g., a proof of address, leaving the other steps as
+    /// they are so that the WebSDK asks only for that document set again.
+    ///
+    /// The review is rejected for resubmission (`RETRY`). Sumsub selects the
+    /// steps to resubmit by the reject labels; if `reject_labels` is empty, a
+    /// label for `doc_set` is used (e.g., `BAD_PROOF_OF_ADDRESS`).
+    ///
+    /// Like [`Client::simulate_review_response`], this is refused on
+    /// [`Environment::Production`] unless the app token is a sandbox token.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn simulate_doc_set_rejection(
+        &self,
+        applicant_id: &ApplicantId,
+        doc_set: &crate::applicants::IdDocSetType,
+        reject_labels: &[&str],
+    ) -> Result<(), SumsubError> {
+        let reject_labels = if reject_labels.is_empty() {
+            crate::sandbox::default_reject_labels(doc_set)
+        } else {
+            reject_labels.to_vec()
+        };
+        let client_comment = format!("Simulated rejection of the {} step", doc_set.as_str());
+        let review = crate::applicants::SimulateReviewRequest {
+            review_answer: "RED",
+            reject_labels: Some(reject_labels),
+            review_reject_type: Some("RETRY"),
+            client_comment: Some(&client_comment),
+            moderation_comment: None,
+        };
+        self.simulate_review_response(applicant_id, review).await
+    }
+
+    /// Creates a fully-populated applicant in the Sandbox environment and
+    /// drives it to the given outcome.
+    ///
+    /// This creates the applicant, uploads stub identity and selfie documents,
+    /// submits the applicant for review, and simulates the review decision.
+    /// Returns the applicant as it is after the review was simulated.
+    ///
+    /// Like [`Client::simulate_review_response`], this is refused on
+    /// [`Environment::Production`] unless the app token is a sandbox token,
+    /// before any applicant is created.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn create_sandbox_applicant(
+        &self,
+        request: CreateApplicantRequest,
+        level_name: &str,
+        outcome: crate::sandbox::SandboxOutcome,
+    ) -> Result<Applicant, SumsubError> {
+        self.ensure_sandbox()?;
+        let country = request
+            .fixed_info
+            .as_ref()
+            .and_then(|info| info.country.clone())
+            .unwrap_or_else(|| crate::sandbox::DEFAULT_COUNTRY.to_string());
+        let applicant = self.create_applicant(request, level_name).await?;
+
+        for id_doc_type in crate::sandbox::STUB_DOC_TYPES {
+            let metadata = crate::applicants::AddDocumentMetadata {
+                id_doc_type,
+                country: &country,
+                first_name: None,
+                middle_name: None,
+                last_name: None,
+                dob: None,
+                place_of_birth: None,
+                issued_date: None,
+                valid_until: None,
+                number: None,
+                sub_type: None,
+                id_doc_sub_type: None,
+            };
+            let file_name = format!("{}.png", id_doc_type.to_lowercase());
+            self.add_verification_document(
+                &applicant.id,
+                metadata,
+                crate::sandbox::STUB_IMAGE.to_vec(),
+                &file_name,
+                "image/png",
+            )
+            .await?;
+        }
+
+        self.request_applicant_recheck(&applicant.id).await?;
+
+        if let Some(review_answer) = outcome.review_answer() {
+            let reject_labels = match &outcome {
+                crate::sandbox::SandboxOutcome::Rejected { reject_labels, .. } => {
+                    Some(reject_labels.iter().map(String::as_str).collect())
+                }
+                _ => None,
+            };
+            let review = crate::applicants::SimulateReviewRequest {
+                review_answer,
+                reject_labels,
+                review_reject_type: outcome.review_reject_type(),
+                client_comment: None,
+                moderation_comment: None,
+            };
+            self.simulate_review_response(&applicant.id, review).await?;
+        }
+
+        self.get_applicant_data(&applicant.id).await
+    }
+
+    /// Runs an AML check for an applicant, returning the API's
+    /// acknowledgement.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#run-aml-check)
-    pub async fn run_aml_check(&self, applicant_id: &str) -> Result<(), SumsubError> {
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn run_aml_check(&self, applicant_id: &ApplicantId) -> Result<OperationResult, SumsubError> {
         let path = format!("/resources/applicants/{}/aml", applicant_id);
         let response = self.send_request(Method::POST, &path, None::<()>).await?;
-        self.handle_empty_response(response).await
+        self.handle_response_or_default(response).await
     }
 
     /// Gets AML case data for an applicant.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-aml-case-data)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_aml_case_data(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<crate::applicants::AmlData, SumsubError> {
         let path = format!("/resources/applicants/{}/aml", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
+    /// Downloads the AML screening report for an applicant, e.g., to keep as
+    /// evidence of screening in compliance files.
+    ///
+    /// # Arguments
+    ///
+    /// * `applicant_id` - The ID of the applicant.
+    /// * `format` - The format of the report.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_aml_report(
+        &self,
+        applicant_id: &ApplicantId,
+        format: crate::applicants::AmlReportFormat,
+    ) -> Result<Vec<u8>, SumsubError> {
+        let path = format!(
+            "/resources/applicants/{}/aml/report.{}",
+            applicant_id,
+            format.extension()
+        );
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_bytes_response(response).await
+    }
+
     /// Updates the review status of an AML hit.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#update-aml-hit-review)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn update_aml_hit_review(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         hit_id: &str,
         request: crate::applicants::UpdateAmlHitReviewRequest<'_>,
     ) -> Result<(), SumsubError> {
@@ -1264,22 +3625,123 @@ impl Client {
     /// Marks an image as inactive.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#marking-image-as-inactive)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn mark_image_as_inactive(
         &self,
-        applicant_id: &str,
-        image_id: &str,
+        applicant_id: &ApplicantId,
+        image_id: &ImageId,
     ) -> Result<(), SumsubError> {
         let path = format!("/resources/applicants/{}/images/{}", applicant_id, image_id);
         let response = self.send_request(Method::DELETE, &path, None::<()>).await?;
         self.handle_empty_response(response).await
     }
 
+    /// Sets how an image should be displayed (rotation and crop area) without
+    /// modifying the original.
+    ///
+    /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-information-about-document-images)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn set_image_transform(
+        &self,
+        applicant_id: &ApplicantId,
+        image_id: &ImageId,
+        transform: &crate::applicants::ImageTransform,
+    ) -> Result<(), SumsubError> {
+        let path = format!("/resources/applicants/{}/images/{}/transform", applicant_id, image_id);
+        let response = self.send_request(Method::PATCH, &path, Some(transform)).await?;
+        self.handle_empty_response(response).await
+    }
+
+    /// Deactivates a category of data (e.g., selfies or proof of address
+    /// documents) of an applicant without deleting the whole profile.
+    ///
+    /// Every image of the applicant whose document type belongs to `category`
+    /// is marked as inactive with [`Client::mark_image_as_inactive`]. Inactive
+    /// images are no longer used for verification, but Sumsub still stores
+    /// them; this does not erase the data.
+    ///
+    /// A failing image does not stop the others from being deactivated. One
+    /// outcome is returned per matching image, in the order the API lists them.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn deactivate_applicant_data(
+        &self,
+        applicant_id: &ApplicantId,
+        category: crate::applicants::DataCategory,
+    ) -> Result<Vec<crate::applicants::ImageDeactivation>, SumsubError> {
+        let images = self.get_document_images_info(applicant_id).await?;
+        let images = images.into_iter().filter(|image| category.matches(&image.id_doc_type));
+        Ok(self.deactivate_images(applicant_id, images).await)
+    }
+
+    /// Marks every image of a document set (e.g., an outdated proof of address)
+    /// as inactive. Returns one outcome per image, like
+    /// [`Client::deactivate_applicant_data`].
+    ///
+    /// The images are those listed for the step with the document set's
+    /// `idDocSetType` in the status of verification steps. A step that has
+    /// not been submitted has no images to deactivate.
+    ///
+    /// Use [`Client::rerequest_doc_set`] to ask the applicant to submit the
+    /// document set again.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn deactivate_doc_set(
+        &self,
+        applicant_id: &ApplicantId,
+        doc_set: &crate::applicants::IdDocSetType,
+    ) -> Result<Vec<crate::applicants::ImageDeactivation>, SumsubError> {
+        let (mut steps, images) = futures::try_join!(
+            self.get_required_docs_status_detailed(applicant_id),
+            self.get_document_images_info(applicant_id),
+        )?;
+        let image_ids = steps
+            .remove(doc_set.as_str())
+            .flatten()
+            .map(|step| step.image_ids)
+            .unwrap_or_default();
+        let images = images
+            .into_iter()
+            .filter(|image| image_ids.contains(&image.image_id));
+        Ok(self.deactivate_images(applicant_id, images).await)
+    }
+
+    /// Marks the given images as inactive one by one, continuing after failures.
+    async fn deactivate_images(
+        &self,
+        applicant_id: &ApplicantId,
+        images: impl Iterator<Item = crate::applicants::ImageInfo>,
+    ) -> Vec<crate::applicants::ImageDeactivation> {
+        let mut outcomes = Vec::new();
+        for image in images {
+            let result = self.mark_image_as_inactive(applicant_id, &image.image_id).await;
+            outcomes.push(crate::applicants::ImageDeactivation {
+                image_id: image.image_id,
+                id_doc_type: image.id_doc_type,
+                result,
+            });
+        }
+        outcomes
+    }
+
+    /// Resets a verification step so that the applicant is asked to submit
+    /// the document set again.
+    ///
+    /// A typed form of [`Client::reset_applicant_step`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn rerequest_doc_set(
+        &self,
+        applicant_id: &ApplicantId,
+        doc_set: &crate::applicants::IdDocSetType,
+    ) -> Result<(), SumsubError> {
+        self.reset_applicant_step(applicant_id, doc_set.as_str()).await
+    }
+
     /// Deactivates an applicant profile.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#deactivate-applicant-profile)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn deactivate_applicant_profile(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         moderation_comment: Option<&str>,
     ) -> Result<(), SumsubError> {
         let path = format!("/resources/applicants/{}/deactivated", applicant_id);
@@ -1292,14 +3754,54 @@ impl Client {
         self.handle_empty_response(response).await
     }
 
+    /// Gets the applicant tags configured in the account.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_applicant_tag_dictionary(&self) -> Result<Vec<String>, SumsubError> {
+        self.fetch_tag_dictionary(TagKind::Applicant).await
+    }
+
+    /// Gets the transaction tags configured in the account.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_transaction_tag_dictionary(&self) -> Result<Vec<String>, SumsubError> {
+        self.fetch_tag_dictionary(TagKind::Transaction).await
+    }
+
+    async fn fetch_tag_dictionary(&self, kind: TagKind) -> Result<Vec<String>, SumsubError> {
+        let response = self.send_request(Method::GET, kind.dictionary_path(), None::<()>).await?;
+        self.handle_response_and_deserialize(response).await
+    }
+
+    /// Rejects tags missing from the dictionary of `kind`, if tag validation
+    /// is enabled (see [`ClientBuilder::validate_tags`]).
+    async fn check_tags(&self, kind: TagKind, tags: &[&str]) -> Result<(), SumsubError> {
+        if !self.inner.validate_tags {
+            return Ok(());
+        }
+        let cache = &self.inner.tag_cache;
+        if let Some(dictionary) = cache.get(kind) {
+            if unknown_tags(&dictionary, tags).is_empty() {
+                return Ok(());
+            }
+        }
+        let dictionary = cache.set(kind, self.fetch_tag_dictionary(kind).await?);
+        let unknown = unknown_tags(&dictionary, tags);
+        if unknown.is_empty() {
+            Ok(())
+        } else {
+            Err(SumsubError::Validation(format!("unknown {:?} tags: {:?}", kind, unknown)))
+        }
+    }
+
     /// Adds tags to an applicant.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#add-custom-applicant-tags)
-    pub async fn add_applicant_tags<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn add_applicant_tags(
         &self,
-        applicant_id: &str,
-        tags: Vec<&'a str>,
+        applicant_id: &ApplicantId,
+        tags: Vec<&str>,
     ) -> Result<(), SumsubError> {
+        self.check_tags(TagKind::Applicant, &tags).await?;
         let path = format!("/resources/applicants/{}/tags", applicant_id);
         let response = self.send_request(Method::POST, &path, Some(tags)).await?;
         self.handle_empty_response(response).await
@@ -1308,11 +3810,13 @@ impl Client {
     /// Adds and overwrites tags for an applicant.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#adding-overwriting-custom-applicant-tags)
-    pub async fn add_and_overwrite_applicant_tags<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn add_and_overwrite_applicant_tags(
         &self,
-        applicant_id: &str,
-        tags: Vec<&'a str>,
+        applicant_id: &ApplicantId,
+        tags: Vec<&str>,
     ) -> Result<(), SumsubError> {
+        self.check_tags(TagKind::Applicant, &tags).await?;
         let path = format!("/resources/applicants/{}/tags/-/overwrite", applicant_id);
         let response = self.send_request(Method::POST, &path, Some(tags)).await?;
         self.handle_empty_response(response).await
@@ -1321,10 +3825,11 @@ impl Client {
     /// Removes tags from an applicant.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#remove-custom-applicant-tags)
-    pub async fn remove_applicant_tags<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn remove_applicant_tags(
         &self,
-        applicant_id: &str,
-        tags: Vec<&'a str>,
+        applicant_id: &ApplicantId,
+        tags: Vec<&str>,
     ) -> Result<(), SumsubError> {
         let path = format!("/resources/applicants/{}/tags", applicant_id);
         let response = self.send_request(Method::DELETE, &path, Some(tags)).await?;
@@ -1334,10 +3839,11 @@ impl Client {
     /// Adds accepted consents for an applicant.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#add-accepted-applicant-consents)
-    pub async fn add_applicant_consents<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn add_applicant_consents(
         &self,
-        applicant_id: &str,
-        consents: Vec<&'a str>,
+        applicant_id: &ApplicantId,
+        consents: Vec<&str>,
     ) -> Result<(), SumsubError> {
         let path = format!("/resources/applicants/{}/consents", applicant_id);
         let request = crate::applicants::AddConsentsRequest { accepted: consents };
@@ -1348,34 +3854,133 @@ impl Client {
     /// Gets the applicant-facing consents for a given level.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-applicant-facing-consents)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_applicant_facing_consents(
         &self,
         level_name: &str,
     ) -> Result<crate::applicants::ApplicantFacingConsentsResponse, SumsubError> {
-        let path = format!("/resources/sdkIntegrations/levels/{}/consents", level_name);
+        let path = format!(
+            "/resources/sdkIntegrations/levels/{}/consents",
+            urlencoding::encode(level_name)
+        );
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_response_and_deserialize(response).await
+    }
+
+    /// Gets the agreements (terms of service, privacy policy versions) configured for a level.
+    ///
+    /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-level-agreements)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_level_agreements(
+        &self,
+        level_name: &str,
+    ) -> Result<crate::applicants::LevelAgreementsResponse, SumsubError> {
+        let path = format!(
+            "/resources/sdkIntegrations/levels/{}/agreements",
+            urlencoding::encode(level_name)
+        );
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_response_and_deserialize(response).await
+    }
+
+    /// Gets the agreements accepted by an applicant.
+    ///
+    /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-applicant-agreement)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_applicant_agreement(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::applicants::ApplicantAgreement, SumsubError> {
+        let path = format!("/resources/applicants/{}/agreement", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
+    /// Records the acceptance of agreements by an applicant.
+    ///
+    /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#record-applicant-agreement)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn record_applicant_agreement(
+        &self,
+        applicant_id: &ApplicantId,
+        request: crate::applicants::RecordAgreementRequest<'_>,
+    ) -> Result<crate::applicants::ApplicantAgreement, SumsubError> {
+        let path = format!("/resources/applicants/{}/agreement", applicant_id);
+        let response = self.send_request(Method::POST, &path, Some(request)).await?;
+        self.handle_response_and_deserialize(response).await
+    }
+
     /// Gets notes for an applicant.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-applicant-notes)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_applicant_notes(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<Vec<crate::applicants::Note>, SumsubError> {
         let path = format!("/resources/applicants/{}/notes", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
+    /// Gets a single page of notes for an applicant, matching `filter`.
+    ///
+    /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-applicant-notes)
+    ///
+    /// # Arguments
+    ///
+    /// * `applicant_id` - The ID of the applicant.
+    /// * `filter` - The date range and author to filter by.
+    /// * `offset` - The number of records to skip.
+    /// * `limit` - The maximum number of records to return.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_applicant_notes_page(
+        &self,
+        applicant_id: &ApplicantId,
+        filter: &crate::applicants::NoteFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<crate::applicants::Note>, SumsubError> {
+        let query = Query::new(format!("/resources/applicants/{}/notes", applicant_id))
+            .param("offset", offset)
+            .param("limit", limit);
+        let path = filter.apply(query).build();
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_response_and_deserialize(response).await
+    }
+
+    /// Gets all notes for an applicant matching `filter` by following pages
+    /// until the last one.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_all_applicant_notes(
+        &self,
+        applicant_id: &ApplicantId,
+        filter: &crate::applicants::NoteFilter,
+    ) -> Result<Vec<crate::applicants::Note>, SumsubError> {
+        let mut notes = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = self
+                .get_applicant_notes_page(applicant_id, filter, offset, DEFAULT_PAGE_SIZE)
+                .await?;
+            let fetched = page.len() as u32;
+            notes.extend(page);
+            if fetched < DEFAULT_PAGE_SIZE {
+                break;
+            }
+            offset += fetched;
+        }
+        Ok(notes)
+    }
+
     /// Adds a note to an applicant.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#add-applicant-note)
-    pub async fn add_applicant_note<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn add_applicant_note(
         &self,
-        applicant_id: &str,
-        note: &'a str,
+        applicant_id: &ApplicantId,
+        note: &str,
     ) -> Result<crate::applicants::Note, SumsubError> {
         let path = format!("/resources/applicants/{}/notes", applicant_id);
         let request = crate::applicants::AddNoteRequest { note };
@@ -1386,11 +3991,12 @@ impl Client {
     /// Edits an applicant note.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#edit-applicant-note)
-    pub async fn edit_applicant_note<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn edit_applicant_note(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         note_id: &str,
-        note: &'a str,
+        note: &str,
     ) -> Result<crate::applicants::Note, SumsubError> {
         let path = format!("/resources/applicants/{}/notes/{}", applicant_id, note_id);
         let request = crate::applicants::EditNoteRequest { note };
@@ -1401,9 +4007,10 @@ impl Client {
     /// Removes an applicant note.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#remove-applicant-note)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn remove_applicant_note(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         note_id: &str,
     ) -> Result<(), SumsubError> {
         let path = format!("/resources/applicants/{}/notes/{}", applicant_id, note_id);
@@ -1414,9 +4021,10 @@ impl Client {
     /// Adds an attachment to an applicant note.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#add-attachment-to-applicant-note)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn add_note_attachment(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         note_id: &str,
         content: Vec<u8>,
         file_name: &str,
@@ -1424,35 +4032,10 @@ impl Client {
     ) -> Result<crate::applicants::Note, SumsubError> {
         let path = format!("/resources/applicants/{}/notes/{}/attachments", applicant_id, note_id);
 
-        let part = reqwest::multipart::Part::bytes(content)
-            .file_name(file_name.to_string())
-            .mime_str(mime_type)
-            .map_err(|e| SumsubError::MimeError(e.to_string()))?;
+        let form = MultipartForm::new().file("content", content, file_name, mime_type)?;
 
-        let form = reqwest::multipart::Form::new().part("content", part);
-
-        let ts = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-
-        let signature = sign_request(
-            &self.secret_key,
-            ts,
-            "POST",
-            &path,
-            &None,
-        );
-
-        let url = format!("{}{}", self.base_url, &path);
         let response = self
-            .http_client
-            .post(&url)
-            .header("X-App-Token", &self.app_token)
-            .header("X-App-Access-Sig", signature)
-            .header("X-App-Access-Ts", ts.to_string())
-            .multipart(form)
-            .send()
+            .execute(Method::POST, &path, RequestBody::Multipart(form.finish()))
             .await?;
 
         self.handle_response_and_deserialize(response).await
@@ -1461,9 +4044,10 @@ impl Client {
     /// Downloads an attachment from a note.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#download-note-attachment)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn download_note_attachment(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         note_id: &str,
         attachment_id: &str,
     ) -> Result<Vec<u8>, SumsubError> {
@@ -1472,20 +4056,16 @@ impl Client {
             applicant_id, note_id, attachment_id
         );
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let message = response.text().await.unwrap_or_else(|_| "Could not read error body".to_string());
-            return Err(SumsubError::ApiError { status, message });
-        }
-        Ok(response.bytes().await?.to_vec())
+        self.handle_bytes_response(response).await
     }
 
     /// Removes an attachment from a note.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#remove-note-attachment)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn remove_note_attachment(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         note_id: &str,
         attachment_id: &str,
     ) -> Result<(), SumsubError> {
@@ -1500,11 +4080,15 @@ impl Client {
     /// Gets applicant data by external user ID.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-applicant-data-externaluserid)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_applicant_data_by_external_user_id(
         &self,
-        external_user_id: &str,
+        external_user_id: &ExternalUserId,
     ) -> Result<crate::models::Applicant, SumsubError> {
-        let path = format!("/resources/applicants/-;externalUserId={}/one", external_user_id);
+        let path = format!(
+            "/resources/applicants/-;externalUserId={}/one",
+            urlencoding::encode(external_user_id)
+        );
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
@@ -1512,21 +4096,123 @@ impl Client {
     /// Gets the status of verification steps for an applicant.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-status-of-verification-steps)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_verification_steps_status(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<std::collections::HashMap<String, crate::applicants::VerificationStepStatus>, SumsubError> {
         let path = format!("/resources/applicants/{}/requiredIdDocsStatus", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
+    /// Gets the status of verification steps for an applicant, including the
+    /// image IDs of each step and their individual review results.
+    ///
+    /// Steps that have not been submitted yet are returned as `None`.
+    ///
+    /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-status-of-verification-steps)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_required_docs_status_detailed(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<std::collections::HashMap<String, Option<crate::applicants::RequiredDocStepStatus>>, SumsubError> {
+        let path = format!("/resources/applicants/{}/requiredIdDocsStatus", applicant_id);
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_response_and_deserialize(response).await
+    }
+
+    /// Asks Sumsub to deliver past webhooks again, e.g., to recover events
+    /// missed while the webhook receiver was down.
+    ///
+    /// The request is rejected with a `SumsubError::Validation` without
+    /// calling the API if it sets neither a time range nor applicants, to
+    /// avoid resending every webhook of the account. Accounts without
+    /// webhook re-delivery get a `SumsubError::NotFound`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn resend_webhooks(
+        &self,
+        request: &crate::webhooks::WebhookResendRequest,
+    ) -> Result<OperationResult, SumsubError> {
+        if request.since.is_none() && request.until.is_none() && request.applicant_ids.is_empty() {
+            return Err(SumsubError::Validation(
+                "a webhook resend needs a time range or applicant IDs".to_string(),
+            ));
+        }
+        let path = "/resources/webhooks/-/resend";
+        let response = self.send_request(Method::POST, path, Some(request)).await?;
+        self.handle_response_or_default(response).await
+    }
+
+    /// Fetches the applicant, its moderation states, and the latest check
+    /// results of each verification step for an `applicantReviewed` webhook.
+    ///
+    /// The three requests are made concurrently.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_review_context(
+        &self,
+        payload: &crate::webhooks::ApplicantReviewedPayload,
+    ) -> Result<crate::webhooks::ReviewContext, SumsubError> {
+        let applicant_id = &payload.applicant_id;
+        let (applicant, moderation_states, step_results) = tokio::try_join!(
+            self.get_applicant_data(applicant_id),
+            self.get_all_applicant_moderation_states(applicant_id),
+            self.get_required_docs_status_detailed(applicant_id),
+        )?;
+        Ok(crate::webhooks::ReviewContext {
+            applicant,
+            moderation_states,
+            step_results,
+        })
+    }
+
+    /// Fetches everything needed for a manual review screen: the applicant's
+    /// status, moderation states, images, notes, and the latest results of
+    /// `check_types`.
+    ///
+    /// All requests are made concurrently.
+    ///
+    /// # Arguments
+    ///
+    /// * `applicant_id` - The ID of the applicant.
+    /// * `check_types` - The check types to fetch the latest results of.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_review_bundle(
+        &self,
+        applicant_id: &ApplicantId,
+        check_types: &[CheckType],
+    ) -> Result<crate::applicants::ReviewBundle, SumsubError> {
+        let check_results = futures::future::try_join_all(check_types.iter().map(|&check_type| async move {
+            match self.get_latest_check_result(applicant_id, check_type).await {
+                Ok(result) => Ok(Some((check_type, result))),
+                Err(SumsubError::NotFound { .. }) => Ok(None),
+                Err(e) => Err(e),
+            }
+        }));
+        let note_filter = crate::applicants::NoteFilter::default();
+        let (status, moderation_states, images, check_results, notes) = tokio::try_join!(
+            self.get_applicant_status(applicant_id),
+            self.get_all_applicant_moderation_states(applicant_id),
+            self.get_document_images_info(applicant_id),
+            check_results,
+            self.get_all_applicant_notes(applicant_id, &note_filter),
+        )?;
+        Ok(crate::applicants::ReviewBundle {
+            status,
+            moderation_states,
+            images,
+            check_results: check_results.into_iter().flatten().collect(),
+            notes,
+        })
+    }
+
     /// Gets the review history for an applicant.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-applicant-review-history)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_applicant_review_history(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<Vec<crate::applicants::ReviewHistoryRecord>, SumsubError> {
         let path = format!("/resources/applicants/{}/review/history", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
@@ -1536,40 +4222,106 @@ impl Client {
     /// Gets a document image.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-document-images)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_document_image(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         inspection_id: &str,
-        image_id: &str,
+        image_id: &ImageId,
     ) -> Result<Vec<u8>, SumsubError> {
         let path = format!("/resources/applicants/{}/images/{}/{}", applicant_id, inspection_id, image_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let message = response.text().await.unwrap_or_else(|_| "Could not read error body".to_string());
-            return Err(SumsubError::ApiError { status, message });
-        }
-        Ok(response.bytes().await?.to_vec())
+        self.handle_bytes_response(response).await
+    }
+
+    /// Downloads a document image and checks its size and hash against the
+    /// metadata in `image` (see [`Client::get_document_images_info`]).
+    ///
+    /// A mismatch is reported in the returned report rather than as an error,
+    /// so the content can still be quarantined or downloaded again.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_document_image_verified(
+        &self,
+        applicant_id: &ApplicantId,
+        image: &crate::applicants::ImageInfo,
+    ) -> Result<(Vec<u8>, crate::integrity::IntegrityReport), SumsubError> {
+        let content = self
+            .get_document_image(applicant_id, &image.inspection_id, &image.image_id)
+            .await?;
+        let report = crate::integrity::IntegrityReport::new(
+            image.image_id.clone(),
+            &content,
+            image.file_metadata.as_ref(),
+        );
+        Ok((content, report))
+    }
+
+    /// Streams a document image instead of loading it into memory. See
+    /// [`Client::get_document_image`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_document_image_stream(
+        &self,
+        applicant_id: &ApplicantId,
+        inspection_id: &str,
+        image_id: &ImageId,
+    ) -> Result<crate::response::ByteStream, SumsubError> {
+        let path = format!("/resources/applicants/{}/images/{}/{}", applicant_id, inspection_id, image_id);
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        self.handle_stream_response(response).await
+    }
+
+    /// Gets a temporary download link for a document image instead of its
+    /// bytes, e.g., to let a browser fetch it directly from storage.
+    ///
+    /// Returns `None` if the API serves the image directly rather than
+    /// redirecting to a link; use [`Client::get_document_image`] then.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_document_image_link(
+        &self,
+        applicant_id: &ApplicantId,
+        inspection_id: &str,
+        image_id: &ImageId,
+    ) -> Result<Option<String>, SumsubError> {
+        let path = format!("/resources/applicants/{}/images/{}/{}", applicant_id, inspection_id, image_id);
+        self.media_link(&path).await
     }
 
     /// Gets information about document images for an applicant.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-information-about-document-images)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_document_images_info(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<Vec<crate::applicants::ImageInfo>, SumsubError> {
         let path = format!("/resources/applicants/{}/info/images", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
+    /// Gets the fields extracted by OCR from an applicant's identity documents,
+    /// one result per document image.
+    ///
+    /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-information-about-document-images)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_id_doc_ocr_results(
+        &self,
+        applicant_id: &ApplicantId,
+    ) -> Result<Vec<crate::applicants::IdDocOcrResult>, SumsubError> {
+        let path = format!("/resources/applicants/{}/info/idDocs/ocr", applicant_id);
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        let ocr: crate::applicants::IdDocOcrResponse =
+            self.handle_response_and_deserialize(response).await?;
+        Ok(ocr.items)
+    }
+
     /// Adds an image to an applicant action.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#add-images-to-applicant-actions)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn add_image_to_action(
         &self,
-        action_id: &str,
+        action_id: &ActionId,
         metadata: Option<crate::actions::AddActionImageMetadata<'_>>,
         content: Vec<u8>,
         file_name: &str,
@@ -1577,39 +4329,14 @@ impl Client {
     ) -> Result<Vec<crate::actions::ActionImage>, SumsubError> {
         let path = format!("/resources/applicantActions/{}/images", action_id);
 
-        let part = reqwest::multipart::Part::bytes(content)
-            .file_name(file_name.to_string())
-            .mime_str(mime_type)
-            .map_err(|e| SumsubError::MimeError(e.to_string()))?;
-
-        let mut form = reqwest::multipart::Form::new().part("content", part);
+        let mut form = MultipartForm::new().file("content", content, file_name, mime_type)?;
         if let Some(metadata) = metadata {
             let metadata_str = serde_json::to_string(&metadata)?;
-            form = form.part("metadata", reqwest::multipart::Part::text(metadata_str));
+            form = form.text("metadata", &metadata_str);
         }
 
-        let ts = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-
-        let signature = sign_request(
-            &self.secret_key,
-            ts,
-            "POST",
-            &path,
-            &None,
-        );
-
-        let url = format!("{}{}", self.base_url, &path);
         let response = self
-            .http_client
-            .post(&url)
-            .header("X-App-Token", &self.app_token)
-            .header("X-App-Access-Sig", signature)
-            .header("X-App-Access-Ts", ts.to_string())
-            .multipart(form)
-            .send()
+            .execute(Method::POST, &path, RequestBody::Multipart(form.finish()))
             .await?;
 
         self.handle_response_and_deserialize(response).await
@@ -1618,28 +4345,40 @@ impl Client {
     /// Gets an image from an applicant action.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-images-from-applicant-actions)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_image_from_action(
         &self,
-        action_id: &str,
-        image_id: &str,
+        action_id: &ActionId,
+        image_id: &ImageId,
     ) -> Result<Vec<u8>, SumsubError> {
         let path = format!("/resources/applicantActions/{}/images/{}", action_id, image_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let message = response.text().await.unwrap_or_else(|_| "Could not read error body".to_string());
-            return Err(SumsubError::ApiError { status, message });
-        }
-        Ok(response.bytes().await?.to_vec())
+        self.handle_bytes_response(response).await
+    }
+
+    /// Gets a temporary download link for an image from an applicant action
+    /// instead of its bytes.
+    ///
+    /// Returns `None` if the API serves the image directly rather than
+    /// redirecting to a link; use [`Client::get_image_from_action`] then.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn get_image_from_action_link(
+        &self,
+        action_id: &ActionId,
+        image_id: &ImageId,
+    ) -> Result<Option<String>, SumsubError> {
+        let path = format!("/resources/applicantActions/{}/images/{}", action_id, image_id);
+        self.media_link(&path).await
     }
 
     /// Gets OCR fields from company documents.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-ocr-fields-from-company-documents)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_ocr_fields_from_company_documents(
         &self,
-        applicant_id: &str,
-    ) -> Result<std::collections::HashMap<String, String>, SumsubError> {
+        applicant_id: &ApplicantId,
+    ) -> Result<crate::kyb::CompanyOcrResult, SumsubError> {
         let path = format!("/resources/applicants/{}/info/companyInfo/ocr", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
@@ -1648,9 +4387,10 @@ impl Client {
     /// Submits applicant data for Non-Doc Verification.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#submit-applicant-data)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn submit_non_doc_data(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         data: serde_json::Value,
     ) -> Result<(), SumsubError> {
         let path = format!("/resources/applicants/{}/info/nondoc", applicant_id);
@@ -1661,10 +4401,11 @@ impl Client {
     /// Confirms applicant data for Non-Doc Verification.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#confirm-applicant-data)
-    pub async fn confirm_non_doc_data<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn confirm_non_doc_data(
         &self,
-        applicant_id: &str,
-        consent: &'a str,
+        applicant_id: &ApplicantId,
+        consent: &str,
     ) -> Result<(), SumsubError> {
         let path = format!("/resources/applicants/{}/info/nondoc/confirm", applicant_id);
         let request = crate::non_doc::ConfirmNonDocDataRequest { consent };
@@ -1675,9 +4416,13 @@ impl Client {
     /// Gets applicant data from Non-Doc Verification.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-non-doc-applicant-data)
+    #[deprecated(
+        note = "returns untyped JSON; use `get_applicant_data` and `get_id_doc_ocr_results` for typed applicant data"
+    )]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_non_doc_data(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
     ) -> Result<serde_json::Value, SumsubError> {
         let path = format!("/resources/applicants/{}/info/nondoc", applicant_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
@@ -1687,13 +4432,14 @@ impl Client {
     /// Approves or rejects a transaction.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#approve-and-reject-transaction)
-    pub async fn review_transaction<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn review_transaction(
         &self,
-        txn_id: &str,
+        txn_id: &TxnId,
         action: crate::transactions::TransactionReviewAction,
-        moderation_comment: Option<&'a str>,
+        moderation_comment: Option<&str>,
     ) -> Result<crate::transactions::SubmitTransactionResponse, SumsubError> {
-        let path = format!("/resources/kyt/txns/{}/review/{}", txn_id, action.to_string());
+        let path = format!("/resources/kyt/txns/{}/review/{}", txn_id, action);
         let request = crate::transactions::ReviewTransactionRequest {
             review: crate::transactions::ReviewTransactionDetails {
                 moderation_comment,
@@ -1706,9 +4452,10 @@ impl Client {
     /// Rescores a transaction.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#re-score-transaction)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn rescore_transaction(
         &self,
-        txn_id: &str,
+        txn_id: &TxnId,
     ) -> Result<crate::transactions::SubmitTransactionResponse, SumsubError> {
         let path = format!("/resources/kyt/txns/{}/rescore", txn_id);
         let response = self.send_request(Method::POST, &path, None::<()>).await?;
@@ -1718,9 +4465,10 @@ impl Client {
     /// Changes transaction properties.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#changing-transaction-custom-properties)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn change_transaction_properties(
         &self,
-        txn_id: &str,
+        txn_id: &TxnId,
         properties: serde_json::Value,
     ) -> Result<crate::transactions::SubmitTransactionResponse, SumsubError> {
         let path = format!("/resources/kyt/txns/{}/info", txn_id);
@@ -1731,12 +4479,14 @@ impl Client {
     /// Finds specific transactions using an expression.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#find-specific-transactions)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn find_transactions(
         &self,
         expression: &str,
     ) -> Result<crate::transactions::FindTransactionsResponse, SumsubError> {
-        let encoded_expression = urlencoding::encode(expression);
-        let path = format!("/resources/kyt/txns/search?expression={}", encoded_expression);
+        let path = Query::new("/resources/kyt/txns/search")
+            .param("expression", expression)
+            .build();
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
@@ -1744,22 +4494,25 @@ impl Client {
     /// Gets the list of available currencies for transaction monitoring.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-available-currencies)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_available_currencies(
         &self,
     ) -> Result<crate::transactions::AvailableCurrenciesResponse, SumsubError> {
         let path = "/resources/kyt/misc/availableCurrencies";
-        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        let response = self.send_request(Method::GET, path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
     /// Adds tags to a transaction.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#add-txn-tags)
-    pub async fn add_transaction_tags<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn add_transaction_tags(
         &self,
-        txn_id: &str,
-        tags: Vec<&'a str>,
+        txn_id: &TxnId,
+        tags: Vec<&str>,
     ) -> Result<(), SumsubError> {
+        self.check_tags(TagKind::Transaction, &tags).await?;
         let path = format!("/resources/kyt/txns/{}/tags", txn_id);
         let request = crate::transactions::AddTransactionTagsRequest { tags };
         let response = self.send_request(Method::POST, &path, Some(request)).await?;
@@ -1769,9 +4522,10 @@ impl Client {
     /// Gets tags for a transaction.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-txn-tags)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_transaction_tags(
         &self,
-        txn_id: &str,
+        txn_id: &TxnId,
     ) -> Result<crate::transactions::GetTransactionTagsResponse, SumsubError> {
         let path = format!("/resources/kyt/txns/{}/tags", txn_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
@@ -1781,10 +4535,11 @@ impl Client {
     /// Removes tags from a transaction.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#remove-txn-tags)
-    pub async fn remove_transaction_tags<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn remove_transaction_tags(
         &self,
-        txn_id: &str,
-        tags: Vec<&'a str>,
+        txn_id: &TxnId,
+        tags: Vec<&str>,
     ) -> Result<(), SumsubError> {
         let path = format!("/resources/kyt/txns/{}/tags", txn_id);
         let request = crate::transactions::RemoveTransactionTagsRequest { tags };
@@ -1795,10 +4550,11 @@ impl Client {
     /// Adds a note to a transaction.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#add-txn-notes)
-    pub async fn add_transaction_note<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn add_transaction_note(
         &self,
-        txn_id: &str,
-        note: &'a str,
+        txn_id: &TxnId,
+        note: &str,
     ) -> Result<crate::transactions::TransactionNote, SumsubError> {
         let path = format!("/resources/kyt/txns/{}/notes", txn_id);
         let request = crate::transactions::AddTransactionNoteRequest { note };
@@ -1809,9 +4565,10 @@ impl Client {
     /// Gets notes for a transaction.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-txn-notes)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_transaction_notes(
         &self,
-        txn_id: &str,
+        txn_id: &TxnId,
     ) -> Result<Vec<crate::transactions::TransactionNote>, SumsubError> {
         let path = format!("/resources/kyt/txns/{}/notes", txn_id);
         let response = self.send_request(Method::GET, &path, None::<()>).await?;
@@ -1821,11 +4578,12 @@ impl Client {
     /// Edits a transaction note.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#edit-txn-notes)
-    pub async fn edit_transaction_note<'a>(
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn edit_transaction_note(
         &self,
-        txn_id: &str,
+        txn_id: &TxnId,
         note_id: &str,
-        note: &'a str,
+        note: &str,
     ) -> Result<crate::transactions::TransactionNote, SumsubError> {
         let path = format!("/resources/kyt/txns/{}/notes/{}", txn_id, note_id);
         let request = crate::transactions::EditTransactionNoteRequest { note };
@@ -1836,9 +4594,10 @@ impl Client {
     /// Removes a transaction note.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#remove-txn-notes)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn remove_transaction_note(
         &self,
-        txn_id: &str,
+        txn_id: &TxnId,
         note_id: &str,
     ) -> Result<(), SumsubError> {
         let path = format!("/resources/kyt/txns/{}/notes/{}", txn_id, note_id);
@@ -1849,18 +4608,20 @@ impl Client {
     /// Adds a payment method.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#add-payment-method)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn add_payment_method(
         &self,
         payment_method: crate::transactions::PaymentMethod,
     ) -> Result<crate::transactions::PaymentMethod, SumsubError> {
         let path = "/resources/kyt/misc/paymentMethods";
-        let response = self.send_request(Method::POST, &path, Some(payment_method)).await?;
+        let response = self.send_request(Method::POST, path, Some(payment_method)).await?;
         self.handle_response_and_deserialize(response).await
     }
 
     /// Updates a wallet address.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#update-wallet-address)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn update_wallet_address(
         &self,
         address: &str,
@@ -1874,42 +4635,88 @@ impl Client {
     /// Gets the list of available VASPs.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#get-available-vasps)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn get_available_vasps(&self) -> Result<crate::travel_rule::VaspsResponse, SumsubError> {
         let path = "/resources/kyt/vasps";
-        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        let response = self.send_request(Method::GET, path, None::<()>).await?;
         self.handle_response_and_deserialize(response).await
     }
 
+    /// Gets a single page of Travel Rule transfers matching `filter`, e.g.,
+    /// to reconcile the transfers still awaiting counterparty data.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - The status, counterparty VASP, and date range to filter by.
+    /// * `offset` - The number of records to skip.
+    /// * `limit` - The maximum number of records to return.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn list_travel_rule_transfers_page(
+        &self,
+        filter: &crate::travel_rule::TransferFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<crate::travel_rule::TravelRuleTransfer>, SumsubError> {
+        #[derive(Deserialize)]
+        struct Page {
+            items: Vec<crate::travel_rule::TravelRuleTransfer>,
+        }
+
+        let query = Query::new("/resources/kyt/travelRule/transfers")
+            .param("offset", offset)
+            .param("limit", limit);
+        let path = filter.apply(query).build();
+        let response = self.send_request(Method::GET, &path, None::<()>).await?;
+        let page: Page = self.handle_response_and_deserialize(response).await?;
+        Ok(page.items)
+    }
+
+    /// Gets all Travel Rule transfers matching `filter` by following pages
+    /// until the last one.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn list_all_travel_rule_transfers(
+        &self,
+        filter: &crate::travel_rule::TransferFilter,
+    ) -> Result<Vec<crate::travel_rule::TravelRuleTransfer>, SumsubError> {
+        let mut transfers = Vec::new();
+        let mut offset = 0;
+        loop {
+            let page = self
+                .list_travel_rule_transfers_page(filter, offset, DEFAULT_PAGE_SIZE)
+                .await?;
+            let fetched = page.len() as u32;
+            transfers.extend(page);
+            if fetched < DEFAULT_PAGE_SIZE {
+                break;
+            }
+            offset += fetched;
+        }
+        Ok(transfers)
+    }
+
     /// Generates a Device Intelligence access token.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#generate-access-token-device-intelligence)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn generate_device_intelligence_token(
         &self,
-        lang: Option<&str>,
-    ) -> Result<String, SumsubError> {
-        let path = "/resources/accessTokens?type=device";
-        let request_body = if let Some(lang) = lang {
-            Some(serde_json::json!({ "lang": lang }))
-        } else {
-            None
-        };
+        request: crate::device_intelligence::DeviceTokenRequest<'_>,
+    ) -> Result<crate::device_intelligence::DeviceIntelligenceToken, SumsubError> {
+        let path = request
+            .apply(Query::new("/resources/accessTokens").param("type", "device"))
+            .build();
+        let request_body = request.lang.map(|lang| serde_json::json!({ "lang": lang }));
         let response = self.send_request(Method::POST, &path, request_body).await?;
-
-        #[derive(Deserialize)]
-        struct TokenResponse {
-            token: String,
-        }
-
-        let token_response: TokenResponse = self.handle_response_and_deserialize(response).await?;
-        Ok(token_response.token)
+        self.handle_response_and_deserialize(response).await
     }
 
     /// Sends an applicant platform event with captured device information.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#send-applicant-platform-event-with-captured-device)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn send_platform_event(
         &self,
-        applicant_id: &str,
+        applicant_id: &ApplicantId,
         event: crate::device_intelligence::PlatformEvent<'_>,
     ) -> Result<(), SumsubError> {
         let path = format!("/resources/applicants/{}/platformEvents", applicant_id);
@@ -1917,12 +4724,40 @@ impl Client {
         self.handle_empty_response(response).await
     }
 
+    /// Sends a burst of platform events for an applicant.
+    ///
+    /// Events are sent one at a time, in input order, so that Sumsub
+    /// receives them in the order they happened. A failing event does not
+    /// abort the batch; one result is returned per event, in input order.
+    ///
+    /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#send-applicant-platform-event-with-captured-device)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn send_platform_events(
+        &self,
+        applicant_id: &ApplicantId,
+        events: &[crate::device_intelligence::PlatformEvent<'_>],
+    ) -> Vec<Result<(), SumsubError>> {
+        let path = format!("/resources/applicants/{}/platformEvents", applicant_id);
+        let mut results = Vec::with_capacity(events.len());
+
+        for event in events {
+            let result = match self.send_request(Method::POST, &path, Some(event)).await {
+                Ok(response) => self.handle_empty_response(response).await,
+                Err(e) => Err(e),
+            };
+            results.push(result);
+        }
+
+        results
+    }
+
     /// Sends financial transaction data with captured device information.
     ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#send-financial-transaction-with-captured-device)
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn send_financial_transaction_with_device(
         &self,
-        txn_id: &str,
+        txn_id: &TxnId,
         fingerprint: &str,
     ) -> Result<(), SumsubError> {
         let path = format!("/resources/kyt/txns/{}/data/applicant/device", txn_id);
@@ -1931,50 +4766,79 @@ impl Client {
         self.handle_empty_response(response).await
     }
 
+    /// Sends device information captured for the counterparty of a
+    /// transaction, e.g., when both sides of a marketplace transaction are
+    /// your users.
+    ///
+    /// # Arguments
+    ///
+    /// * `txn_id` - The ID of the transaction.
+    /// * `device` - The device captured for the counterparty.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn send_counterparty_device(
+        &self,
+        txn_id: &TxnId,
+        device: crate::device_intelligence::CounterpartyDevice<'_>,
+    ) -> Result<(), SumsubError> {
+        let path = format!("/resources/kyt/txns/{}/data/counterparty/device", txn_id);
+        let response = self.send_request(Method::POST, &path, Some(device)).await?;
+        self.handle_empty_response(response).await
+    }
+
     /// Imports an applicant profile from a zip archive.
     ///
+    /// Sumsub signs uploads over the whole body, so the upload is built in
+    /// memory; expect a peak memory use of about twice the archive size.
+    ///
     /// [Sumsub API reference](https://developers.sumsub.com/api-reference/#import-applicant-profile-from-archive)
     ///
     /// # Arguments
     ///
     /// * `content` - The content of the zip archive.
     /// * `file_name` - The name of the file.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
     pub async fn import_applicant_profile_from_archive(
         &self,
         content: Vec<u8>,
         file_name: &str,
     ) -> Result<(), SumsubError> {
-        let path = "/resources/applicants/-/ingest";
-
-        let part = reqwest::multipart::Part::bytes(content)
-            .file_name(file_name.to_string())
-            .mime_str("application/zip")
-            .map_err(|e| SumsubError::MimeError(e.to_string()))?;
+        self.send_archive(content, file_name).await
+    }
 
-        let form = reqwest::multipart::Form::new().part("content", part);
+    /// Imports an applicant profile from a zip archive on disk, retrying once
+    /// if the upload fails with a transient error (see
+    /// [`SumsubError::is_retryable`]).
+    ///
+    /// The archive is read into memory like in
+    /// [`Client::import_applicant_profile_from_archive`]. It is read again
+    /// for the retry, so that it is not kept in memory between attempts.
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip_all))]
+    pub async fn import_applicant_profile_from_archive_file(
+        &self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<(), SumsubError> {
+        let path = path.as_ref();
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "applicant.zip".to_string());
+        let mut retried = false;
+        loop {
+            let content = tokio::fs::read(path).await?;
+            match self.send_archive(content, &file_name).await {
+                Err(e) if e.is_retryable() && !retried => retried = true,
+                result => return result,
+            }
+        }
+    }
 
-        let ts = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+    async fn send_archive(&self, content: Vec<u8>, file_name: &str) -> Result<(), SumsubError> {
+        let path = "/resources/applicants/-/ingest";
 
-        let signature = sign_request(
-            &self.secret_key,
-            ts,
-            "POST",
-            path,
-            &None,
-        );
+        let form = MultipartForm::new().file("content", content, file_name, "application/zip")?;
 
-        let url = format!("{}{}", self.base_url, path);
         let response = self
-            .http_client
-            .post(&url)
-            .header("X-App-Token", &self.app_token)
-            .header("X-App-Access-Sig", signature)
-            .header("X-App-Access-Ts", ts.to_string())
-            .multipart(form)
-            .send()
+            .execute(Method::POST, path, RequestBody::Multipart(form.finish()))
             .await?;
 
         self.handle_empty_response(response).await
@@ -0,0 +1,69 @@
+// src/dashboard.rs
+
+//! This module contains a builder for deep links into the Sumsub dashboard,
+//! so internal tools can link reviewers straight to the right screen.
+
+use crate::ids::{ApplicantId, TxnId};
+
+/// The base URL of the Sumsub dashboard.
+const DASHBOARD_URL: &str = "https://cockpit.sumsub.com/checkus";
+
+/// Builds links to pages of the Sumsub dashboard.
+///
+/// # Example
+///
+/// ```
+/// use sumsub_api::dashboard::DashboardLinks;
+/// use sumsub_api::ids::ApplicantId;
+///
+/// let links = DashboardLinks::sandbox();
+/// assert_eq!(
+///     links.applicant(&ApplicantId::from("5cb56e8e0a975a35f333cb83")),
+///     "https://cockpit.sumsub.com/checkus?sandbox=true#/applicant/5cb56e8e0a975a35f333cb83/basicInfo"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct DashboardLinks {
+    base_url: String,
+    sandbox: bool,
+}
+
+impl DashboardLinks {
+    /// Links to the production dashboard.
+    pub fn production() -> Self {
+        Self::new(DASHBOARD_URL, false)
+    }
+
+    /// Links to the Sandbox mode of the dashboard.
+    pub fn sandbox() -> Self {
+        Self::new(DASHBOARD_URL, true)
+    }
+
+    /// Links to a dashboard at a custom base URL, e.g., a proxy.
+    pub fn new(base_url: impl Into<String>, sandbox: bool) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            sandbox,
+        }
+    }
+
+    /// The page of an applicant.
+    pub fn applicant(&self, applicant_id: &ApplicantId) -> String {
+        self.link(&format!("applicant/{}/basicInfo", urlencoding::encode(applicant_id)))
+    }
+
+    /// The AML screening case of an applicant.
+    pub fn aml_case(&self, applicant_id: &ApplicantId) -> String {
+        self.link(&format!("applicant/{}/aml", urlencoding::encode(applicant_id)))
+    }
+
+    /// The page of a transaction.
+    pub fn transaction(&self, txn_id: &TxnId) -> String {
+        self.link(&format!("kyt/txns/{}", urlencoding::encode(txn_id)))
+    }
+
+    fn link(&self, route: &str) -> String {
+        let query = if self.sandbox { "?sandbox=true" } else { "" };
+        format!("{}{}#/{}", self.base_url, query, route)
+    }
+}
@@ -2,7 +2,10 @@
 
 //! This module will contain the data structures for the "Device Intelligence" section of the Sumsub API.
 
-use serde::Serialize;
+use crate::ids::{ApplicantId, ExternalUserId};
+use crate::query::Query;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -18,3 +21,50 @@ pub struct PlatformEvent<'a> {
 pub struct DeviceFingerprint<'a> {
     pub fingerprint: &'a str,
 }
+
+/// Device information captured for the counterparty of a transaction.
+#[derive(Serialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CounterpartyDevice<'a> {
+    pub fingerprint: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<&'a str>,
+}
+
+/// Parameters for generating a Device Intelligence access token.
+#[derive(Debug, Clone, Default)]
+pub struct DeviceTokenRequest<'a> {
+    /// The language of the SDK UI (e.g., `en`).
+    pub lang: Option<&'a str>,
+    /// Binds the token to the applicant with this external user ID.
+    pub external_user_id: Option<&'a ExternalUserId>,
+    /// Binds the token to the applicant with this ID.
+    pub applicant_id: Option<&'a ApplicantId>,
+}
+
+impl DeviceTokenRequest<'_> {
+    /// Appends the token parameters to `query`.
+    pub(crate) fn apply(&self, query: Query) -> Query {
+        query
+            .opt_param("userId", self.external_user_id.map(ExternalUserId::as_str))
+            .opt_param("applicantId", self.applicant_id.map(ApplicantId::as_str))
+    }
+}
+
+/// A time-bound access token for the Device Intelligence SDK.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DeviceIntelligenceToken {
+    pub token: String,
+    /// The external user ID the token is bound to.
+    pub user_id: Option<ExternalUserId>,
+    /// The time the token expires.
+    pub expires_at: Option<String>,
+    /// The lifetime of the token in seconds.
+    pub ttl_in_secs: Option<u64>,
+    /// Any other data returned for bootstrapping the SDK.
+    #[serde(flatten)]
+    pub sdk_data: HashMap<String, serde_json::Value>,
+}
@@ -2,14 +2,81 @@
 
 //! This module defines the custom error types used throughout the crate.
 
+use std::time::Duration;
 use thiserror::Error;
 
 /// The error type for the Sumsub API client.
 #[derive(Error, Debug)]
 pub enum SumsubError {
     /// An error returned by the Sumsub API.
+    ///
+    /// If the body is a Sumsub JSON error, `message` is its `description` and
+    /// `code` and `correlation_id` are set; otherwise `message` is the raw body.
     #[error("API error (status: {status}): {message}")]
-    ApiError { status: u16, message: String },
+    ApiError {
+        status: u16,
+        message: String,
+        /// The Sumsub error code, e.g., `1002`.
+        code: Option<i64>,
+        /// The correlation ID to quote to Sumsub support.
+        correlation_id: Option<String>,
+    },
+
+    /// The credentials were rejected (401) or lack the permission for the request (403).
+    #[error("Unauthorized (status: {status}): {message}")]
+    Unauthorized {
+        status: u16,
+        message: String,
+        code: Option<i64>,
+        correlation_id: Option<String>,
+    },
+
+    /// The requested resource does not exist (404).
+    #[error("Not found: {message}")]
+    NotFound {
+        message: String,
+        code: Option<i64>,
+        correlation_id: Option<String>,
+    },
+
+    /// The request conflicts with the current state of the resource (409).
+    #[error("Conflict: {message}")]
+    Conflict {
+        message: String,
+        code: Option<i64>,
+        correlation_id: Option<String>,
+    },
+
+    /// Too many requests were sent (429).
+    #[error("Rate limited: {message}")]
+    RateLimited {
+        /// How long to wait before retrying, from the `Retry-After` header.
+        retry_after: Option<Duration>,
+        message: String,
+        code: Option<i64>,
+        correlation_id: Option<String>,
+    },
+
+    /// The response was not JSON, e.g., an HTML 502 or maintenance page
+    /// served by Sumsub's edge instead of the API.
+    #[error("Sumsub unavailable (status: {status}): {body_snippet}")]
+    Unavailable {
+        status: u16,
+        /// The start of the response body.
+        body_snippet: String,
+    },
+
+    /// The circuit breaker is open after repeated failures, so the request
+    /// was not sent (see `ClientBuilder::circuit_breaker`).
+    #[error("Circuit open: Sumsub is failing, retry in {retry_after:?}")]
+    CircuitOpen {
+        /// How long until a trial request is let through.
+        retry_after: Duration,
+    },
+
+    /// An applicant with the same `externalUserId` already exists.
+    #[error("Applicant already exists: {existing_applicant_id}")]
+    AlreadyExists { existing_applicant_id: crate::ids::ApplicantId },
 
     /// An error occurred while making a request with `reqwest`.
     #[error("Reqwest error: {0}")]
@@ -19,7 +86,113 @@ pub enum SumsubError {
     #[error("Serde JSON error: {0}")]
     Serde(#[from] serde_json::Error),
 
+    /// A response body did not match the expected model. Only returned with
+    /// lenient decoding (see `ClientBuilder::lenient_decoding`).
+    #[error("Decode error: {source}")]
+    Decode {
+        /// The body of the response.
+        raw_body: String,
+        /// The error, with the path of the field that failed, e.g., `review.reviewResult`.
+        source: serde_path_to_error::Error<serde_json::Error>,
+    },
+
     /// An error occurred while parsing a MIME type.
     #[error("MIME type error: {0}")]
     MimeError(String),
+
+    /// An I/O error occurred while reading or writing local files.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// An applicant archive could not be built or read.
+    #[error("Archive error: {0}")]
+    Archive(String),
+
+    /// A request failed client-side validation and was not sent.
+    #[error("Validation error: {0}")]
+    Validation(String),
+
+    /// The client is misconfigured, e.g., a credential is missing or empty.
+    #[error("Configuration error: {0}")]
+    Configuration(String),
+
+    /// A header value could not be constructed.
+    #[error("Invalid header: {0}")]
+    InvalidHeader(String),
+}
+
+impl SumsubError {
+    /// Creates an error from the status, `Retry-After` header, and body of a
+    /// failed response: a dedicated variant for 401, 403, 404, 409, and 429,
+    /// and an `ApiError` otherwise.
+    pub(crate) fn from_response(status: u16, retry_after: Option<Duration>, body: String) -> Self {
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ErrorBody {
+            description: Option<String>,
+            error_code: Option<i64>,
+            code: Option<i64>,
+            correlation_id: Option<String>,
+        }
+
+        let (message, code, correlation_id) = match serde_json::from_str::<ErrorBody>(&body) {
+            Ok(parsed) => (
+                parsed.description.unwrap_or(body),
+                parsed.error_code.or(parsed.code),
+                parsed.correlation_id,
+            ),
+            Err(_) => (body, None, None),
+        };
+        match status {
+            401 | 403 => SumsubError::Unauthorized { status, message, code, correlation_id },
+            404 => SumsubError::NotFound { message, code, correlation_id },
+            409 => SumsubError::Conflict { message, code, correlation_id },
+            429 => SumsubError::RateLimited { retry_after, message, code, correlation_id },
+            _ => SumsubError::ApiError { status, message, code, correlation_id },
+        }
+    }
+
+    /// Creates an `Unavailable` error, keeping the first 200 characters of
+    /// the trimmed body.
+    pub(crate) fn unavailable(status: u16, body: &str) -> Self {
+        SumsubError::Unavailable {
+            status,
+            body_snippet: body.trim().chars().take(200).collect(),
+        }
+    }
+
+    /// The HTTP status of the response, if the error was returned by the API.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            SumsubError::ApiError { status, .. }
+            | SumsubError::Unauthorized { status, .. }
+            | SumsubError::Unavailable { status, .. } => Some(*status),
+            SumsubError::NotFound { .. } => Some(404),
+            SumsubError::Conflict { .. } | SumsubError::AlreadyExists { .. } => Some(409),
+            SumsubError::RateLimited { .. } => Some(429),
+            _ => None,
+        }
+    }
+
+    /// How long the API asked to wait before retrying, from the `Retry-After`
+    /// header of a 429 response, or how long until an open circuit lets a
+    /// trial request through.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            SumsubError::RateLimited { retry_after, .. } => *retry_after,
+            SumsubError::CircuitOpen { retry_after } => Some(*retry_after),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the error is likely transient and the request may
+    /// succeed when retried: network errors, rate limiting (429), and server
+    /// errors (5xx), including non-JSON error pages.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            SumsubError::Reqwest(_) | SumsubError::RateLimited { .. } => true,
+            SumsubError::ApiError { status, .. } | SumsubError::Unavailable { status, .. } => *status >= 500,
+            _ => false,
+        }
+    }
 }
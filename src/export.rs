@@ -0,0 +1,83 @@
+// src/export.rs
+
+//! This module contains the data structures for exporting applicant data,
+//! e.g., for legal hold requests.
+
+use serde::Serialize;
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// Describes the files written by `Client::export_applicant_bundle`.
+///
+/// The bundle directory has the following layout:
+///
+/// ```text
+/// <dest_dir>/
+///   applicant.json
+///   images/<idDocType>_<imageId>.<ext>
+///   liveness.<ext>
+///   report.pdf
+/// ```
+#[derive(Debug)]
+pub struct ApplicantBundle {
+    /// The raw applicant data as returned by the API.
+    pub applicant_json: PathBuf,
+    /// The document images of the applicant, in no particular order.
+    pub images: Vec<PathBuf>,
+    /// The liveness video, if the applicant has one.
+    pub liveness_video: Option<PathBuf>,
+    /// The PDF verification report.
+    pub report: PathBuf,
+}
+
+/// Everything recorded about how an applicant's personal data was collected
+/// and processed, as collected by `Client::export_data_subject_record`, e.g.,
+/// to answer a data subject access request (DSAR).
+///
+/// The applicant data is kept as returned by the API, so that no field is
+/// lost. Serialize the export to get a single JSON document.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DataSubjectExport {
+    pub applicant_id: crate::ids::ApplicantId,
+    /// The applicant data.
+    pub applicant: Value,
+    /// The agreement the applicant accepted, if any.
+    pub agreement: Option<crate::applicants::ApplicantAgreement>,
+    /// The consents shown to applicants of the applicant's level, if the
+    /// applicant has a level.
+    pub level_consents: Option<crate::applicants::ApplicantFacingConsentsResponse>,
+    /// All notes on the applicant.
+    pub notes: Vec<crate::applicants::Note>,
+    /// The audit trail events of the applicant found in the pages read.
+    pub audit_trail: Vec<crate::misc::AuditTrailEvent>,
+    /// `false` if the page cap was reached before the end of the audit
+    /// trail, so events of the applicant may be missing.
+    pub audit_trail_complete: bool,
+}
+
+/// Replaces every character of a server-provided value other than
+/// `[A-Za-z0-9_-]` with `_`, so that it can be used in a file name.
+pub(crate) fn file_name_part(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
+/// Guesses a file extension from the leading bytes of a downloaded file.
+pub(crate) fn file_extension(content: &[u8]) -> &'static str {
+    if content.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "jpg"
+    } else if content.starts_with(b"\x89PNG") {
+        "png"
+    } else if content.starts_with(b"%PDF") {
+        "pdf"
+    } else if content.starts_with(&[0x1A, 0x45, 0xDF, 0xA3]) {
+        "webm"
+    } else if content.len() > 8 && &content[4..8] == b"ftyp" {
+        "mp4"
+    } else {
+        "bin"
+    }
+}
@@ -0,0 +1,323 @@
+// src/flow.rs
+
+//! This module contains high-level verification flows that wire together the
+//! individual endpoints of the `Client` for simple integrations.
+
+use crate::applicants::AddDocumentMetadata;
+use crate::client::Client;
+use crate::error::SumsubError;
+use crate::ids::ApplicantId;
+use crate::kyb::{BeneficiaryType, ExistingBeneficiary, LinkBeneficiaryRequest};
+use crate::models::{Applicant, CreateApplicantRequest};
+use crate::webhooks::WebhookPayload;
+use std::time::Duration;
+
+pub use crate::applicants::VerificationState;
+
+/// A KYC flow for a single individual applicant.
+///
+/// The flow creates the applicant, issues a WebSDK access token, tracks the
+/// verification state through polling or webhooks, and fetches the results.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use sumsub_api::client::Client;
+/// use sumsub_api::flow::{KycFlow, VerificationState};
+/// use sumsub_api::models::CreateApplicantRequest;
+///
+/// # async fn run(client: Client) -> Result<(), sumsub_api::error::SumsubError> {
+/// let request = CreateApplicantRequest {
+///     external_user_id: "user-123".into(),
+///     ..Default::default()
+/// };
+/// let mut flow = KycFlow::start(&client, request, "basic-kyc-level").await?;
+/// println!("Pass this token to the WebSDK: {}", flow.access_token());
+///
+/// let state = flow.wait_for_decision(Duration::from_secs(30), 120).await?;
+/// if *state == VerificationState::Approved {
+///     let applicant = flow.results().await?;
+///     println!("Approved: {}", applicant.id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct KycFlow {
+    client: Client,
+    applicant_id: ApplicantId,
+    level_name: String,
+    access_token: String,
+    state: VerificationState,
+}
+
+impl KycFlow {
+    /// Creates an applicant and issues a WebSDK access token for it.
+    pub async fn start(
+        client: &Client,
+        request: CreateApplicantRequest,
+        level_name: &str,
+    ) -> Result<Self, SumsubError> {
+        let applicant = client.create_applicant(request, level_name).await?;
+        Self::resume(client, &applicant.id, level_name).await
+    }
+
+    /// Resumes the flow of an existing applicant, issuing a fresh access token
+    /// and fetching the current state.
+    pub async fn resume(
+        client: &Client,
+        applicant_id: &ApplicantId,
+        level_name: &str,
+    ) -> Result<Self, SumsubError> {
+        let access_token = client
+            .generate_token_for_existing_applicant(applicant_id, level_name)
+            .await?;
+        let mut flow = Self {
+            client: client.clone(),
+            applicant_id: applicant_id.clone(),
+            level_name: level_name.to_string(),
+            access_token,
+            state: VerificationState::AwaitingApplicant,
+        };
+        flow.refresh().await?;
+        Ok(flow)
+    }
+
+    /// The ID of the applicant.
+    pub fn applicant_id(&self) -> &ApplicantId {
+        &self.applicant_id
+    }
+
+    /// The access token to pass to the WebSDK.
+    pub fn access_token(&self) -> &str {
+        &self.access_token
+    }
+
+    /// The last known verification state.
+    pub fn state(&self) -> &VerificationState {
+        &self.state
+    }
+
+    /// Issues a new access token, e.g., after the previous one expired.
+    pub async fn renew_access_token(&mut self) -> Result<&str, SumsubError> {
+        self.access_token = self
+            .client
+            .generate_token_for_existing_applicant(&self.applicant_id, &self.level_name)
+            .await?;
+        Ok(&self.access_token)
+    }
+
+    /// Fetches the current verification state from the API.
+    pub async fn refresh(&mut self) -> Result<&VerificationState, SumsubError> {
+        let status = self.client.get_applicant_status(&self.applicant_id).await?;
+        self.state = VerificationState::from_status(&status);
+        Ok(&self.state)
+    }
+
+    /// Updates the state from a webhook. Webhooks for other applicants are ignored.
+    ///
+    /// Returns `true` if the state was updated.
+    pub fn apply_webhook(&mut self, payload: &WebhookPayload) -> bool {
+        match payload {
+            WebhookPayload::ApplicantReviewed(p) if p.applicant_id == self.applicant_id => {
+                let result = p.review.review_result.as_ref();
+                self.state = VerificationState::from_review(
+                    &p.review.review_status,
+                    result.map(|r| r.review_answer.as_str()),
+                    result.and_then(|r| r.reject_labels.as_deref()),
+                    result.and_then(|r| r.review_reject_type.as_deref()),
+                );
+                true
+            }
+            WebhookPayload::ApplicantPending(p) if p.applicant_id == self.applicant_id => {
+                self.state = VerificationState::Pending;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Polls the API every `interval` until a decision is made or
+    /// `max_polls` polls were made, and returns the last known state.
+    pub async fn wait_for_decision(
+        &mut self,
+        interval: Duration,
+        max_polls: u32,
+    ) -> Result<&VerificationState, SumsubError> {
+        for poll in 0..max_polls {
+            if poll > 0 {
+                tokio::time::sleep(interval).await;
+            }
+            if self.refresh().await?.is_decided() {
+                break;
+            }
+        }
+        Ok(&self.state)
+    }
+
+    /// Fetches the full applicant data, e.g., once a decision was made.
+    pub async fn results(&self) -> Result<Applicant, SumsubError> {
+        self.client.get_applicant_data(&self.applicant_id).await
+    }
+}
+
+/// The verification state of a beneficiary linked by a [`KybFlow`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BeneficiaryState {
+    pub applicant_id: crate::ids::ApplicantId,
+    pub state: VerificationState,
+}
+
+/// The verification state of a company and all of its beneficiaries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KybStatus {
+    pub company: VerificationState,
+    pub beneficiaries: Vec<BeneficiaryState>,
+}
+
+impl KybStatus {
+    /// Combines the states of the company and its beneficiaries into one.
+    ///
+    /// The result is `Rejected` if any party was rejected (with the reject
+    /// labels of all rejected parties, retryable only if every rejection is),
+    /// `Approved` if every party was approved, `AwaitingApplicant` if any
+    /// party still has to submit documents, and `Pending` otherwise.
+    pub fn overall(&self) -> VerificationState {
+        let states: Vec<&VerificationState> = std::iter::once(&self.company)
+            .chain(self.beneficiaries.iter().map(|b| &b.state))
+            .collect();
+
+        let mut reject_labels = Vec::new();
+        let mut can_retry_all = true;
+        let mut rejected = false;
+        for state in &states {
+            if let VerificationState::Rejected { reject_labels: labels, can_retry } = state {
+                rejected = true;
+                can_retry_all &= *can_retry;
+                reject_labels.extend(labels.iter().cloned());
+            }
+        }
+
+        if rejected {
+            VerificationState::Rejected {
+                reject_labels,
+                can_retry: can_retry_all,
+            }
+        } else if states.iter().all(|s| **s == VerificationState::Approved) {
+            VerificationState::Approved
+        } else if states.contains(&&VerificationState::AwaitingApplicant) {
+            VerificationState::AwaitingApplicant
+        } else {
+            VerificationState::Pending
+        }
+    }
+}
+
+/// A KYB flow for a company applicant and its beneficiaries.
+///
+/// The flow creates the company applicant, uploads corporate documents,
+/// creates and links individual applicants for beneficiaries, and tracks the
+/// verification state of the company and all beneficiaries together.
+#[derive(Debug, Clone)]
+pub struct KybFlow {
+    client: Client,
+    applicant_id: ApplicantId,
+    beneficiary_ids: Vec<ApplicantId>,
+}
+
+impl KybFlow {
+    /// Creates a company applicant.
+    ///
+    /// The applicant type is set to `company` unless the request specifies one.
+    pub async fn start(
+        client: &Client,
+        mut request: CreateApplicantRequest,
+        level_name: &str,
+    ) -> Result<Self, SumsubError> {
+        request.applicant_type.get_or_insert_with(|| "company".to_string());
+        let applicant = client.create_applicant(request, level_name).await?;
+        Ok(Self::resume(client, &applicant.id, Vec::new()))
+    }
+
+    /// Resumes the flow of an existing company applicant and its linked beneficiaries.
+    pub fn resume(client: &Client, applicant_id: &ApplicantId, beneficiary_ids: Vec<ApplicantId>) -> Self {
+        Self {
+            client: client.clone(),
+            applicant_id: applicant_id.clone(),
+            beneficiary_ids,
+        }
+    }
+
+    /// The ID of the company applicant.
+    pub fn applicant_id(&self) -> &ApplicantId {
+        &self.applicant_id
+    }
+
+    /// The applicant IDs of the beneficiaries linked through this flow.
+    pub fn beneficiary_ids(&self) -> &[ApplicantId] {
+        &self.beneficiary_ids
+    }
+
+    /// Uploads a corporate document (e.g., a certificate of incorporation).
+    pub async fn upload_document(
+        &self,
+        metadata: AddDocumentMetadata<'_>,
+        content: Vec<u8>,
+        file_name: &str,
+        mime_type: &str,
+    ) -> Result<(), SumsubError> {
+        self.client
+            .add_verification_document(&self.applicant_id, metadata, content, file_name, mime_type)
+            .await
+    }
+
+    /// Creates an individual applicant for a beneficiary and links it to the
+    /// company. Returns the ID of the beneficiary's applicant.
+    pub async fn add_beneficiary(
+        &mut self,
+        request: CreateApplicantRequest,
+        level_name: &str,
+        types: Vec<BeneficiaryType>,
+        share_size: Option<f64>,
+    ) -> Result<ApplicantId, SumsubError> {
+        let beneficiary = self.client.create_applicant(request, level_name).await?;
+        self.link_beneficiary(&beneficiary.id, types, share_size).await?;
+        Ok(beneficiary.id)
+    }
+
+    /// Links an existing individual applicant to the company as a beneficiary.
+    pub async fn link_beneficiary(
+        &mut self,
+        beneficiary_id: &ApplicantId,
+        types: Vec<BeneficiaryType>,
+        share_size: Option<f64>,
+    ) -> Result<(), SumsubError> {
+        let request = LinkBeneficiaryRequest::Existing(ExistingBeneficiary {
+            applicant_id: beneficiary_id.clone(),
+            types,
+            share_size,
+        });
+        self.client.link_beneficiary(&self.applicant_id, request).await?;
+        self.beneficiary_ids.push(beneficiary_id.clone());
+        Ok(())
+    }
+
+    /// Fetches the current verification state of the company and all
+    /// beneficiaries from the API.
+    pub async fn status(&self) -> Result<KybStatus, SumsubError> {
+        let company = self.client.get_applicant_status(&self.applicant_id);
+        let beneficiaries = futures::future::try_join_all(self.beneficiary_ids.iter().map(|id| async move {
+            let status = self.client.get_applicant_status(id).await?;
+            Ok::<_, SumsubError>(BeneficiaryState {
+                applicant_id: id.clone(),
+                state: VerificationState::from_status(&status),
+            })
+        }));
+        let (company, beneficiaries) = tokio::try_join!(company, beneficiaries)?;
+        Ok(KybStatus {
+            company: VerificationState::from_status(&company),
+            beneficiaries,
+        })
+    }
+}
@@ -0,0 +1,85 @@
+// src/health.rs
+
+//! This module contains the `HealthWatcher`, a background task that tracks
+//! the health of the Sumsub API.
+
+use crate::client::Client;
+use crate::misc::Health;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+
+/// Polls [`Client::get_api_health_status`] on an interval in a background
+/// task and publishes the latest health through a `watch` channel, e.g., to
+/// shed Sumsub-dependent work during outages.
+///
+/// The channel holds `None` until the first poll completes. A failed poll
+/// (e.g., a network error) is published as [`Health::Down`]. Receivers are
+/// only notified when the health changes. The task stops when the watcher
+/// is dropped.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use sumsub_api::client::Client;
+/// use sumsub_api::health::HealthWatcher;
+/// use sumsub_api::misc::Health;
+///
+/// # async fn run(client: Client) {
+/// let watcher = HealthWatcher::spawn(client, Duration::from_secs(30));
+/// let mut health = watcher.subscribe();
+/// while health.changed().await.is_ok() {
+///     if *health.borrow() == Some(Health::Down) {
+///         println!("Sumsub is down, pausing verification jobs");
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct HealthWatcher {
+    receiver: watch::Receiver<Option<Health>>,
+    task: JoinHandle<()>,
+}
+
+impl HealthWatcher {
+    /// Starts polling every `interval`, beginning immediately.
+    ///
+    /// Must be called from within a Tokio runtime.
+    pub fn spawn(client: Client, interval: Duration) -> Self {
+        let (sender, receiver) = watch::channel(None);
+        let task = tokio::spawn(async move {
+            let mut ticks = tokio::time::interval(interval);
+            ticks.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticks.tick().await;
+                let health = match client.get_api_health_status().await {
+                    Ok(status) => status.health(),
+                    Err(_) => Health::Down,
+                };
+                sender.send_if_modified(|latest| {
+                    let changed = *latest != Some(health);
+                    *latest = Some(health);
+                    changed
+                });
+            }
+        });
+        Self { receiver, task }
+    }
+
+    /// The latest known health, or `None` if the first poll has not completed.
+    pub fn latest(&self) -> Option<Health> {
+        *self.receiver.borrow()
+    }
+
+    /// Returns a receiver that is notified whenever the health changes.
+    pub fn subscribe(&self) -> watch::Receiver<Option<Health>> {
+        self.receiver.clone()
+    }
+}
+
+impl Drop for HealthWatcher {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
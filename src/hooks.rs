@@ -0,0 +1,386 @@
+// src/hooks.rs
+
+//! This module contains the request hook API, which lets users observe and
+//! adjust every request the client sends without forking the crate.
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Method, StatusCode};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Headers carrying credentials, which are redacted before being shown to hooks.
+const AUTH_HEADERS: [&str; 3] = ["x-app-token", "x-app-access-sig", "x-app-access-ts"];
+
+/// The placeholder used for redacted header values.
+const REDACTED: &str = "[REDACTED]";
+
+/// Other headers that may carry credentials, redacted by [`debug_logger`].
+const SENSITIVE_HEADERS: [&str; 3] = ["authorization", "cookie", "set-cookie"];
+
+/// JSON fields and query parameters holding personal data, redacted by
+/// [`debug_logger`].
+const PII_FIELDS: &[&str] = &[
+    "dob",
+    "number",
+    "firstName",
+    "middleName",
+    "lastName",
+    "firstNameEn",
+    "middleNameEn",
+    "lastNameEn",
+    "legalName",
+    "fullName",
+    "email",
+    "phone",
+    "placeOfBirth",
+    "street",
+    "streetEn",
+    "buildingNumber",
+    "flatNumber",
+    "postCode",
+    "tin",
+    "externalUserId",
+    "accountIdentifier",
+    "institutionName",
+];
+
+/// Returns `true` if `name` is one of the headers set by request signing.
+pub(crate) fn is_auth_header(name: &HeaderName) -> bool {
+    AUTH_HEADERS.contains(&name.as_str())
+}
+
+/// A hook that is called around every request sent by the `Client`.
+///
+/// Both methods have empty default implementations, so a hook only needs to
+/// implement the side it is interested in.
+pub trait RequestHook: Send + Sync {
+    /// Called with the signed request right before it is sent.
+    ///
+    /// Authentication headers are redacted. Extra headers can be added with
+    /// [`HookRequest::insert_header`]; they are not part of the signature.
+    fn before_send(&self, _request: &mut HookRequest) {}
+
+    /// Called with the response right after it is received.
+    fn after_receive(&self, _request: &HookRequest, _response: &HookResponse) {}
+
+    /// Called instead of `after_receive` if no response was received, e.g.,
+    /// because the connection failed or the request timed out.
+    fn on_error(&self, _request: &HookRequest, _error: &reqwest::Error) {}
+}
+
+/// A view of a signed request that is about to be sent.
+#[derive(Debug)]
+pub struct HookRequest {
+    method: Method,
+    url: String,
+    headers: HeaderMap,
+    body: Option<String>,
+    extra_headers: HeaderMap,
+}
+
+impl HookRequest {
+    pub(crate) fn new(method: Method, url: String, headers: &HeaderMap, body: Option<String>) -> Self {
+        let mut redacted = headers.clone();
+        for name in AUTH_HEADERS {
+            if redacted.contains_key(name) {
+                redacted.insert(name, HeaderValue::from_static(REDACTED));
+            }
+        }
+        Self {
+            method,
+            url,
+            headers: redacted,
+            body,
+            extra_headers: HeaderMap::new(),
+        }
+    }
+
+    /// The HTTP method of the request.
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /// The full URL of the request, including the query string.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// The request headers, with authentication headers redacted.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// The request body, if it is textual (JSON or NDJSON).
+    pub fn body(&self) -> Option<&str> {
+        self.body.as_deref()
+    }
+
+    /// Adds a header to the request.
+    ///
+    /// Authentication headers cannot be overridden; attempts to do so are ignored.
+    pub fn insert_header(&mut self, name: HeaderName, value: HeaderValue) {
+        if is_auth_header(&name) {
+            return;
+        }
+        self.headers.insert(name.clone(), value.clone());
+        self.extra_headers.insert(name, value);
+    }
+
+    pub(crate) fn extra_headers(&self) -> &HeaderMap {
+        &self.extra_headers
+    }
+}
+
+/// A view of a response that was received.
+#[derive(Debug)]
+pub struct HookResponse {
+    status: StatusCode,
+    headers: HeaderMap,
+    elapsed: Duration,
+}
+
+impl HookResponse {
+    pub(crate) fn new(status: StatusCode, headers: HeaderMap, elapsed: Duration) -> Self {
+        Self { status, headers, elapsed }
+    }
+
+    /// The HTTP status of the response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// The response headers.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// The time elapsed between sending the request and receiving the response headers.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+/// Creates a hook that calls `f` before every request is sent.
+pub fn before_send<F>(f: F) -> impl RequestHook
+where
+    F: Fn(&mut HookRequest) + Send + Sync + 'static,
+{
+    struct BeforeSend<F>(F);
+
+    impl<F: Fn(&mut HookRequest) + Send + Sync> RequestHook for BeforeSend<F> {
+        fn before_send(&self, request: &mut HookRequest) {
+            (self.0)(request)
+        }
+    }
+
+    BeforeSend(f)
+}
+
+/// Creates a hook that calls `f` after every response is received.
+pub fn after_receive<F>(f: F) -> impl RequestHook
+where
+    F: Fn(&HookRequest, &HookResponse) + Send + Sync + 'static,
+{
+    struct AfterReceive<F>(F);
+
+    impl<F: Fn(&HookRequest, &HookResponse) + Send + Sync> RequestHook for AfterReceive<F> {
+        fn after_receive(&self, request: &HookRequest, response: &HookResponse) {
+            (self.0)(request, response)
+        }
+    }
+
+    AfterReceive(f)
+}
+
+/// Creates a hook that calls `f` whenever a request fails without a response.
+pub fn on_error<F>(f: F) -> impl RequestHook
+where
+    F: Fn(&HookRequest, &reqwest::Error) + Send + Sync + 'static,
+{
+    struct OnError<F>(F);
+
+    impl<F: Fn(&HookRequest, &reqwest::Error) + Send + Sync> RequestHook for OnError<F> {
+        fn on_error(&self, request: &HookRequest, error: &reqwest::Error) {
+            (self.0)(request, error)
+        }
+    }
+
+    OnError(f)
+}
+
+/// Creates a hook that passes a line for every request, response, and
+/// failure to `log`, e.g., `eprintln!` or `tracing::debug!`.
+///
+/// Request lines include the method, URL, headers, and body; response lines
+/// include the status, headers, and latency. Response bodies are not logged.
+/// Authentication headers, cookies, and personal data (e.g., `dob`,
+/// `number`, names, and contact details) in JSON bodies and query strings
+/// are always redacted.
+///
+/// # Example
+///
+/// ```
+/// use sumsub_api::client::Client;
+/// use sumsub_api::hooks;
+///
+/// let client = Client::new("YOUR_APP_TOKEN".to_string(), "YOUR_SECRET_KEY".to_string())
+///     .with_hook(hooks::debug_logger(|line| eprintln!("{}", line)));
+/// ```
+pub fn debug_logger<F>(log: F) -> impl RequestHook
+where
+    F: Fn(&str) + Send + Sync + 'static,
+{
+    struct DebugLogger<F>(F);
+
+    impl<F: Fn(&str) + Send + Sync> RequestHook for DebugLogger<F> {
+        fn before_send(&self, request: &mut HookRequest) {
+            let mut line = format!("--> {} {}", request.method(), redact_url(request.url()));
+            push_headers(&mut line, request.headers());
+            if let Some(body) = request.body() {
+                line.push_str("\n\n");
+                line.push_str(&redact_body(body));
+            }
+            (self.0)(&line)
+        }
+
+        fn after_receive(&self, request: &HookRequest, response: &HookResponse) {
+            let mut line = format!(
+                "<-- {} {} {} ({} ms)",
+                response.status(),
+                request.method(),
+                redact_url(request.url()),
+                response.elapsed().as_millis()
+            );
+            push_headers(&mut line, response.headers());
+            (self.0)(&line)
+        }
+
+        fn on_error(&self, request: &HookRequest, error: &reqwest::Error) {
+            let url = redact_url(request.url());
+            let error = error.to_string().replace(request.url(), &url);
+            (self.0)(&format!("<-- failed {} {}: {}", request.method(), url, error))
+        }
+    }
+
+    DebugLogger(log)
+}
+
+/// Appends one `name: value` line per header, redacting sensitive headers.
+fn push_headers(line: &mut String, headers: &HeaderMap) {
+    for (name, value) in headers {
+        let value = if is_auth_header(name) || SENSITIVE_HEADERS.contains(&name.as_str()) {
+            REDACTED
+        } else {
+            value.to_str().unwrap_or("[binary]")
+        };
+        line.push_str(&format!("\n{}: {}", name, value));
+    }
+}
+
+/// Redacts the values of query parameters and path matrix parameters (e.g.,
+/// `-;externalUserId=...`) holding personal data.
+fn redact_url(url: &str) -> String {
+    let (path, query) = match url.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (url, None),
+    };
+    let path: Vec<String> = path
+        .split('/')
+        .map(|segment| {
+            let mut params = segment.split(';');
+            let mut segment = params.next().unwrap_or_default().to_string();
+            for param in params {
+                segment.push(';');
+                segment.push_str(&redact_param(param));
+            }
+            segment
+        })
+        .collect();
+    let path = path.join("/");
+    match query {
+        Some(query) => {
+            let query: Vec<String> = query.split('&').map(redact_param).collect();
+            format!("{}?{}", path, query.join("&"))
+        }
+        None => path,
+    }
+}
+
+/// Redacts the value of a `key=value` parameter holding personal data.
+fn redact_param(param: &str) -> String {
+    match param.split_once('=') {
+        Some((key, _)) if PII_FIELDS.contains(&key) => format!("{}={}", key, REDACTED),
+        _ => param.to_string(),
+    }
+}
+
+/// Redacts the personal data in a JSON or NDJSON body. Lines that are not
+/// JSON are redacted entirely.
+fn redact_body(body: &str) -> String {
+    body.lines()
+        .map(|line| match serde_json::from_str::<serde_json::Value>(line) {
+            Ok(mut value) => {
+                redact_value(&mut value);
+                value.to_string()
+            }
+            Err(_) if line.trim().is_empty() => String::new(),
+            Err(_) => REDACTED.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn redact_value(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(object) => {
+            for (key, value) in object.iter_mut() {
+                if PII_FIELDS.contains(&key.as_str()) {
+                    *value = serde_json::Value::from(REDACTED);
+                } else {
+                    redact_value(value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(redact_value),
+        _ => {}
+    }
+}
+
+/// The hooks registered on a client.
+#[derive(Clone, Default)]
+pub(crate) struct Hooks(Vec<Arc<dyn RequestHook>>);
+
+impl Hooks {
+    pub(crate) fn push(&mut self, hook: Arc<dyn RequestHook>) {
+        self.0.push(hook);
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn before_send(&self, request: &mut HookRequest) {
+        for hook in &self.0 {
+            hook.before_send(request);
+        }
+    }
+
+    pub(crate) fn after_receive(&self, request: &HookRequest, response: &HookResponse) {
+        for hook in &self.0 {
+            hook.after_receive(request, response);
+        }
+    }
+
+    pub(crate) fn on_error(&self, request: &HookRequest, error: &reqwest::Error) {
+        for hook in &self.0 {
+            hook.on_error(request, error);
+        }
+    }
+}
+
+impl fmt::Debug for Hooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Hooks({})", self.0.len())
+    }
+}
@@ -0,0 +1,180 @@
+// src/ids.rs
+
+//! This module contains newtypes for the IDs used by the API, so that
+//! arguments of methods taking several IDs cannot be swapped by accident.
+//!
+//! The newtypes are used throughout the crate: by the methods of
+//! [`Client`](crate::client::Client) and the [`SumsubApi`](crate::api::SumsubApi)
+//! trait, and by the ID fields of the request and response models.
+//!
+//! Each ID dereferences to `str` and is serialized as a plain string. Numeric
+//! IDs in responses, e.g., the image IDs of verification steps, are read as
+//! their decimal string. IDs are created from strings with `From`:
+//!
+//! ```
+//! use sumsub_api::ids::{ApplicantId, ImageId};
+//!
+//! let applicant_id = ApplicantId::from("63e0a0f4c3b2a1");
+//! let image_id = ImageId::from(String::from("1245897"));
+//! assert_eq!(applicant_id, "63e0a0f4c3b2a1");
+//! assert_eq!(image_id.len(), 7);
+//! ```
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize};
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::Deref;
+
+macro_rules! id_type {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+        #[serde(transparent)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Returns the ID as a string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Returns the ID as a `String`.
+            pub fn into_inner(self) -> String {
+                self.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Borrow<str> for $name {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                Self(id)
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                Self(id.to_string())
+            }
+        }
+
+        impl From<&$name> for $name {
+            fn from(id: &$name) -> Self {
+                id.clone()
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_any(IdVisitor).map(Self)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<&$name> for $name {
+            fn eq(&self, other: &&$name) -> bool {
+                self == *other
+            }
+        }
+
+        impl PartialEq<String> for $name {
+            fn eq(&self, other: &String) -> bool {
+                &self.0 == other
+            }
+        }
+    };
+}
+
+/// Reads an ID from a string or an integer.
+struct IdVisitor;
+
+impl Visitor<'_> for IdVisitor {
+    type Value = String;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string or integer ID")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<String, E> {
+        Ok(v)
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<String, E> {
+        Ok(v.to_string())
+    }
+}
+
+id_type!(
+    /// The ID Sumsub assigned to an applicant.
+    ApplicantId
+);
+
+id_type!(
+    /// The ID of an applicant in your own system (`externalUserId`).
+    ExternalUserId
+);
+
+id_type!(
+    /// The ID of a transaction in transaction monitoring.
+    TxnId
+);
+
+id_type!(
+    /// The ID of an applicant action.
+    ActionId
+);
+
+id_type!(
+    /// The ID of a document image.
+    ImageId
+);
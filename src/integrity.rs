@@ -0,0 +1,75 @@
+// src/integrity.rs
+
+//! This module contains the integrity checks of downloaded files, which let
+//! evidence stores prove that archived documents were not corrupted.
+
+use crate::applicants::FileMetadata;
+use crate::ids::ImageId;
+use sha2::{Digest, Sha256};
+
+/// The outcome of comparing one property of a download with its metadata.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegrityCheck {
+    /// The property matches the metadata.
+    Passed,
+    /// The property differs from the metadata.
+    Failed { expected: String, actual: String },
+    /// The API did not report the property, so it could not be checked.
+    NotAvailable,
+}
+
+impl IntegrityCheck {
+    fn compare(expected: Option<String>, actual: String) -> Self {
+        match expected {
+            None => IntegrityCheck::NotAvailable,
+            Some(expected) if expected == actual => IntegrityCheck::Passed,
+            Some(expected) => IntegrityCheck::Failed { expected, actual },
+        }
+    }
+}
+
+/// The integrity of a downloaded document image.
+///
+/// `size` and `sha256` describe the downloaded content and can be stored
+/// alongside it to verify the file again later.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegrityReport {
+    pub image_id: ImageId,
+    /// The size of the content in bytes.
+    pub size: u64,
+    /// The hex-encoded SHA-256 hash of the content.
+    pub sha256: String,
+    /// The size compared with the reported file size.
+    pub size_check: IntegrityCheck,
+    /// The hash compared with the reported hash.
+    pub hash_check: IntegrityCheck,
+}
+
+impl IntegrityReport {
+    /// Checks `content` against the metadata reported for the image.
+    pub fn new(image_id: ImageId, content: &[u8], metadata: Option<&FileMetadata>) -> Self {
+        let size = content.len() as u64;
+        let sha256 = hex::encode(Sha256::digest(content));
+        let metadata = metadata.cloned().unwrap_or_default();
+        Self {
+            image_id,
+            size,
+            size_check: IntegrityCheck::compare(metadata.file_size.map(|size| size.to_string()), size.to_string()),
+            hash_check: IntegrityCheck::compare(metadata.sha256.map(|hash| hash.to_ascii_lowercase()), sha256.clone()),
+            sha256,
+        }
+    }
+
+    /// Returns `true` if no check failed. Checks of properties that were not
+    /// reported do not count as failures.
+    pub fn is_intact(&self) -> bool {
+        !matches!(self.size_check, IntegrityCheck::Failed { .. })
+            && !matches!(self.hash_check, IntegrityCheck::Failed { .. })
+    }
+
+    /// Returns `true` if at least one property was reported and checked.
+    pub fn is_verified(&self) -> bool {
+        self.is_intact()
+            && (self.size_check == IntegrityCheck::Passed || self.hash_check == IntegrityCheck::Passed)
+    }
+}
@@ -2,7 +2,12 @@
 
 //! This module will contain the data structures for business verification (KYB).
 
+use crate::applicants::VerificationState;
+use crate::error::SumsubError;
+use crate::ids::ApplicantId;
+use crate::query::Query;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 /// Represents the information about a company.
 #[derive(Serialize, Deserialize, Debug, Default)]
@@ -41,6 +46,87 @@ pub struct Address {
     pub sub_street: Option<String>,
 }
 
+/// Represents the fields extracted by OCR from company documents.
+///
+/// Well-known fields are typed; any other extracted fields are kept in `extras`.
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanyOcrResult {
+    pub registration_number: Option<String>,
+    #[serde(alias = "companyName")]
+    pub legal_name: Option<String>,
+    pub address: Option<String>,
+    #[serde(alias = "incorporatedOn")]
+    pub incorporation_date: Option<String>,
+    /// Confidence scores (0.0 to 1.0) keyed by field name.
+    #[serde(default, alias = "confidences")]
+    pub confidence: std::collections::HashMap<String, f64>,
+    /// Other extracted fields not covered by the typed fields above.
+    #[serde(flatten)]
+    pub extras: std::collections::HashMap<String, serde_json::Value>,
+}
+
+impl CompanyOcrResult {
+    /// Returns the confidence score for `field`, if one was reported.
+    pub fn confidence_of(&self, field: &str) -> Option<f64> {
+        self.confidence.get(field).copied()
+    }
+}
+
+/// The role of a beneficiary in a company.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum BeneficiaryType {
+    /// An ultimate beneficial owner.
+    Ubo,
+    Shareholder,
+    Director,
+    /// A person authorized to act on behalf of the company.
+    Representative,
+    /// A beneficiary type not known to this crate. It cannot be sent to the API.
+    #[serde(other)]
+    Unknown,
+}
+
+impl BeneficiaryType {
+    /// Returns `true` if the type represents ownership, for which a share size can be set.
+    pub fn is_ownership(&self) -> bool {
+        matches!(self, BeneficiaryType::Ubo | BeneficiaryType::Shareholder)
+    }
+}
+
+/// Validates the types and share size of a beneficiary.
+///
+/// At least one type is required, types may not repeat or be unknown, and a
+/// share size (between 0 and 100) may only be set for ownership types.
+fn validate_beneficiary(types: &[BeneficiaryType], share_size: Option<f64>) -> Result<(), SumsubError> {
+    if types.is_empty() {
+        return Err(SumsubError::Validation("a beneficiary needs at least one type".to_string()));
+    }
+    if types.contains(&BeneficiaryType::Unknown) {
+        return Err(SumsubError::Validation("an unknown beneficiary type cannot be sent".to_string()));
+    }
+    for (i, t) in types.iter().enumerate() {
+        if types[..i].contains(t) {
+            return Err(SumsubError::Validation(format!("duplicate beneficiary type: {:?}", t)));
+        }
+    }
+    if let Some(share_size) = share_size {
+        if !types.iter().any(BeneficiaryType::is_ownership) {
+            return Err(SumsubError::Validation(
+                "a share size requires the ubo or shareholder type".to_string(),
+            ));
+        }
+        if !(0.0..=100.0).contains(&share_size) {
+            return Err(SumsubError::Validation(format!(
+                "share size must be between 0 and 100, got {}",
+                share_size
+            )));
+        }
+    }
+    Ok(())
+}
+
 /// Represents a request to link a beneficiary to a company.
 #[derive(Serialize, Debug)]
 #[serde(untagged)]
@@ -49,12 +135,22 @@ pub enum LinkBeneficiaryRequest {
     New(NewBeneficiary),
 }
 
+impl LinkBeneficiaryRequest {
+    /// Checks that the combination of beneficiary types and share size is allowed.
+    pub fn validate(&self) -> Result<(), SumsubError> {
+        match self {
+            LinkBeneficiaryRequest::Existing(b) => validate_beneficiary(&b.types, b.share_size),
+            LinkBeneficiaryRequest::New(b) => validate_beneficiary(&b.types, b.share_size),
+        }
+    }
+}
+
 /// Represents an existing beneficiary to be linked to a company.
 #[derive(Serialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct ExistingBeneficiary {
-    pub applicant_id: String,
-    pub types: Vec<String>,
+    pub applicant_id: ApplicantId,
+    pub types: Vec<BeneficiaryType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub share_size: Option<f64>,
 }
@@ -63,7 +159,7 @@ pub struct ExistingBeneficiary {
 #[derive(Serialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct NewBeneficiary {
-    pub types: Vec<String>,
+    pub types: Vec<BeneficiaryType>,
     pub beneficiary_info: BeneficiaryInfo,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub share_size: Option<f64>,
@@ -83,6 +179,111 @@ pub struct BeneficiaryInfo {
     pub tax_residence_country: Option<String>,
 }
 
+/// Represents a beneficiary linked to a company applicant.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkedBeneficiary {
+    /// The ID of the link, used to unlink the beneficiary.
+    pub id: String,
+    /// The ID of the beneficiary's individual applicant, if one was created.
+    /// Beneficiaries added by their details alone have none.
+    pub applicant_id: Option<ApplicantId>,
+    #[serde(default)]
+    pub types: Vec<BeneficiaryType>,
+    pub share_size: Option<f64>,
+}
+
+/// The verification state of a linked beneficiary, or the error that
+/// prevented fetching it.
+#[derive(Debug)]
+pub struct BeneficiaryReadiness {
+    pub beneficiary: LinkedBeneficiary,
+    pub state: Result<VerificationState, SumsubError>,
+}
+
+/// The verification states of all beneficiaries of a company applicant.
+#[derive(Debug)]
+pub struct BeneficiaryReadinessReport {
+    pub beneficiaries: Vec<BeneficiaryReadiness>,
+}
+
+impl BeneficiaryReadinessReport {
+    /// Returns `true` if every beneficiary was approved.
+    pub fn is_ready(&self) -> bool {
+        self.approved().count() == self.beneficiaries.len()
+    }
+
+    /// The beneficiaries that were approved.
+    pub fn approved(&self) -> impl Iterator<Item = &BeneficiaryReadiness> {
+        self.beneficiaries
+            .iter()
+            .filter(|b| matches!(b.state, Ok(VerificationState::Approved)))
+    }
+
+    /// The beneficiaries that were not reviewed yet, including those that
+    /// still have to submit documents.
+    pub fn pending(&self) -> impl Iterator<Item = &BeneficiaryReadiness> {
+        self.beneficiaries
+            .iter()
+            .filter(|b| b.state.as_ref().is_ok_and(|state| !state.is_decided()))
+    }
+
+    /// The beneficiaries that were rejected. Their reject labels are part of `state`.
+    pub fn rejected(&self) -> impl Iterator<Item = &BeneficiaryReadiness> {
+        self.beneficiaries
+            .iter()
+            .filter(|b| matches!(b.state, Ok(VerificationState::Rejected { .. })))
+    }
+
+    /// The beneficiaries whose state could not be fetched.
+    pub fn failed(&self) -> impl Iterator<Item = &BeneficiaryReadiness> {
+        self.beneficiaries.iter().filter(|b| b.state.is_err())
+    }
+}
+
+/// Represents a search for companies in public registries.
+///
+/// At least one of `name` and `registration_number` should be set.
+#[derive(Debug, Clone, Default)]
+pub struct CompanySearchQuery {
+    pub name: Option<String>,
+    pub registration_number: Option<String>,
+    /// The country of incorporation (ISO 3166-1 alpha-3 code).
+    pub country: String,
+}
+
+impl CompanySearchQuery {
+    /// Appends the search parameters to `query`.
+    pub(crate) fn apply(&self, query: Query) -> Query {
+        query
+            .param("country", &self.country)
+            .opt_param("name", self.name.as_ref())
+            .opt_param("registrationNumber", self.registration_number.as_ref())
+    }
+}
+
+/// Represents the response from a company registry search.
+#[derive(Deserialize, Debug)]
+pub struct CompanySearchResponse {
+    pub items: Vec<CompanySearchResult>,
+}
+
+/// Represents a company found in a public registry.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CompanySearchResult {
+    pub company_name: String,
+    pub company_number: String,
+    pub country: String,
+    pub status: Option<String>,
+    #[serde(rename = "type")]
+    pub company_type: Option<String>,
+    pub incorporated_on: Option<String>,
+    pub office_address: Option<String>,
+    pub legal_address_structured: Option<Address>,
+    pub source: Option<String>,
+}
+
 /// Represents the response from a request to get additional company check data.
 #[derive(Deserialize, Debug)]
 pub struct GetAdditionalCompanyCheckDataResponse {
@@ -135,6 +336,138 @@ pub struct CompanyCheckInfo {
     pub significant_persons: Option<Vec<SignificantPerson>>,
 }
 
+impl CompanyCheckInfo {
+    /// Returns the officers and significant persons of the company, for
+    /// screening. A person listed in several roles is returned once.
+    pub fn persons(&self) -> Vec<ScreenedPerson> {
+        let officers = self.officers.iter().flatten().map(|o| ScreenedPerson {
+            full_name: o.full_name.clone(),
+            roles: vec![PersonRole::Officer],
+            dob: o.dob.clone(),
+            country: o.country.clone().or_else(|| o.nationality.clone()),
+        });
+        let significant_persons = self.significant_persons.iter().flatten().map(|p| ScreenedPerson {
+            full_name: p.full_name.clone(),
+            roles: vec![PersonRole::SignificantPerson],
+            dob: p.dob.clone(),
+            country: p.country.clone().or_else(|| p.nationality.clone()),
+        });
+        merge_persons(officers.chain(significant_persons))
+    }
+}
+
+/// Merges the entries of the same person, e.g., a director who is also a
+/// significant person. Entries are the same person if their names match
+/// (ignoring case and whitespace) and their dates of birth do not differ.
+pub(crate) fn merge_persons(persons: impl IntoIterator<Item = ScreenedPerson>) -> Vec<ScreenedPerson> {
+    let mut merged: Vec<ScreenedPerson> = Vec::new();
+    for person in persons {
+        let same = merged.iter_mut().find(|p| {
+            p.normalized_name() == person.normalized_name()
+                && (p.dob.is_none() || person.dob.is_none() || p.dob == person.dob)
+        });
+        match same {
+            Some(existing) => {
+                for role in person.roles {
+                    if !existing.roles.contains(&role) {
+                        existing.roles.push(role);
+                    }
+                }
+                existing.dob = existing.dob.take().or(person.dob);
+                existing.country = existing.country.take().or(person.country);
+            }
+            None => merged.push(person),
+        }
+    }
+    merged
+}
+
+/// The role of a person found in a company check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PersonRole {
+    Officer,
+    SignificantPerson,
+}
+
+/// A company officer or significant person to be screened.
+#[derive(Debug, Clone)]
+pub struct ScreenedPerson {
+    pub full_name: String,
+    /// The roles the person is listed in, e.g., both officer and significant person.
+    pub roles: Vec<PersonRole>,
+    pub dob: Option<String>,
+    pub country: Option<String>,
+}
+
+impl ScreenedPerson {
+    /// Splits the full name into first and last name at the last space. A
+    /// single-word name is used as the first name, without a last name.
+    pub(crate) fn split_name(&self) -> (String, Option<String>) {
+        let full_name = self.full_name.trim();
+        match full_name.rsplit_once(' ') {
+            Some((first, last)) => (first.trim().to_string(), Some(last.to_string())),
+            None => (full_name.to_string(), None),
+        }
+    }
+
+    /// Returns the external user ID of the applicant that screens this person
+    /// for the company applicant `company_applicant_id`.
+    ///
+    /// The ID is derived from the name and date of birth, so screening the
+    /// same company again reuses the applicants created before.
+    pub(crate) fn external_user_id(&self, company_applicant_id: &ApplicantId) -> crate::ids::ExternalUserId {
+        let mut hasher = Sha256::new();
+        hasher.update(self.normalized_name().as_bytes());
+        hasher.update(b"\n");
+        hasher.update(self.dob.as_deref().unwrap_or_default().as_bytes());
+        format!("{}-person-{}", company_applicant_id, hex::encode(&hasher.finalize()[..8])).into()
+    }
+
+    fn normalized_name(&self) -> String {
+        self.full_name
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// A person whose AML screening was started by
+/// [`Client::screen_company_persons`](crate::client::Client::screen_company_persons).
+#[derive(Debug, Clone)]
+pub struct PersonScreening {
+    pub person: ScreenedPerson,
+    /// The ID of the applicant screening the person.
+    pub applicant_id: ApplicantId,
+}
+
+/// The AML screening result of a single person.
+#[derive(Debug)]
+pub struct PersonScreeningResult {
+    pub person: ScreenedPerson,
+    /// The ID of the applicant screening the person.
+    pub applicant_id: ApplicantId,
+    pub hits: Vec<crate::applicants::AmlHit>,
+}
+
+/// The AML screening results of all officers and significant persons of a company.
+#[derive(Debug)]
+pub struct PersonScreeningReport {
+    pub screenings: Vec<PersonScreeningResult>,
+}
+
+impl PersonScreeningReport {
+    /// The screenings with at least one AML hit.
+    pub fn with_hits(&self) -> impl Iterator<Item = &PersonScreeningResult> {
+        self.screenings.iter().filter(|s| !s.hits.is_empty())
+    }
+
+    /// The total number of AML hits across all persons.
+    pub fn total_hits(&self) -> usize {
+        self.screenings.iter().map(|s| s.hits.len()).sum()
+    }
+}
+
 /// Represents an industry code.
 #[derive(Deserialize, Debug)]
 pub struct IndustryCode {
@@ -6,13 +6,29 @@
 //! perform actions such as creating applicants, uploading documents, and
 //! getting verification results.
 
+// The Sumsub API is only served over HTTPS.
+#[cfg(not(any(feature = "native-tls", feature = "rustls")))]
+compile_error!("sumsub_api needs a TLS backend: enable the `native-tls` or the `rustls` feature");
+
 /// The `client` module contains the main `Client` struct, which is used
 /// to make requests to the Sumsub API.
 pub mod client;
 
+/// The `api` module contains the `SumsubApi` trait for mocking the client.
+pub mod api;
+
+/// The `batch` module contains the options and outcomes of bounded-concurrency batches.
+pub mod batch;
+
 /// The `error` module defines the custom error types used in this crate.
 pub mod error;
 
+/// The `ids` module contains newtypes for the IDs used by the API.
+pub mod ids;
+
+/// The `integrity` module contains integrity checks for downloaded files.
+pub mod integrity;
+
 /// The `models` module contains the data structures used for API requests
 /// and responses.
 pub mod models;
@@ -46,3 +62,83 @@ pub mod device_intelligence;
 
 /// The `webhooks` module contains functionality for handling Sumsub webhooks.
 pub mod webhooks;
+
+/// The `hooks` module contains the API for registering request hooks on the client.
+pub mod hooks;
+
+/// The `options` module contains per-call request options.
+pub mod options;
+
+/// The `metrics` module contains the `MetricsSink` trait for exporting request metrics.
+pub mod metrics;
+
+/// The `multipart` module contains the builder for signed multipart uploads.
+mod multipart;
+
+/// The `policy` module contains per-endpoint-class timeout and retry policies.
+pub mod policy;
+
+/// The `pool` module contains a pool of clients for several Sumsub apps.
+pub mod pool;
+
+/// The `query` module contains the builder for percent-encoded query strings.
+mod query;
+
+/// The `rate_limit` module contains the client-side rate limiter.
+mod rate_limit;
+
+/// The `circuit_breaker` module contains the circuit breaker for failing fast during outages.
+mod circuit_breaker;
+
+/// The `tags` module contains the tag dictionaries used for local tag validation.
+pub mod tags;
+
+/// The `tokens` module contains the `TokenManager` for caching WebSDK access tokens.
+pub mod tokens;
+
+/// The `sandbox` module contains helpers for generating Sandbox applicants.
+pub mod sandbox;
+
+/// The `export` module contains data structures for exporting applicant data.
+pub mod export;
+
+/// The `archive` module contains utilities for building applicant archives.
+#[cfg(feature = "zip")]
+pub mod archive;
+
+/// The `flow` module contains high-level KYC and KYB verification flows.
+#[cfg(feature = "flow")]
+pub mod flow;
+
+/// The `response` module contains the `ApiResponse` wrapper for response diagnostics.
+pub mod response;
+
+/// The `dashboard` module contains a builder for Sumsub dashboard deep links.
+pub mod dashboard;
+
+/// The `signing` module contains Sumsub's request signature scheme.
+pub mod signing;
+
+/// The `sync` module contains the data structures for incremental syncs.
+pub mod sync;
+
+/// The `health` module contains a background watcher for the API health status.
+pub mod health;
+
+/// The JSON types that appear in the public API, re-exported from
+/// `serde_json`.
+///
+/// Use these instead of depending on `serde_json` directly, so that the
+/// types always match the version used by this crate.
+pub mod json {
+    pub use serde_json::{json, Map, Number, Value};
+}
+
+/// The HTTP types that appear in the public API, re-exported from `reqwest`.
+///
+/// Use these instead of depending on `reqwest` directly, so that the types
+/// always match the version used by this crate.
+pub mod http {
+    pub use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+    pub use reqwest::{Client, Method, Response, StatusCode, Url};
+}
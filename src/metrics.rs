@@ -0,0 +1,95 @@
+// src/metrics.rs
+
+//! This module contains the `MetricsSink` trait, which lets users export
+//! per-request metrics (e.g., to Prometheus or StatsD) without the crate
+//! depending on a metrics backend.
+
+use reqwest::{Method, StatusCode};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Receives the metrics of every request sent by the `Client`.
+pub trait MetricsSink: Send + Sync {
+    /// Called once per request, after the response headers were received or
+    /// the request failed.
+    fn record(&self, metrics: &RequestMetrics);
+
+    /// Called after `record` when a request took longer than the latency
+    /// budget of its endpoint class (see
+    /// [`EndpointPolicy::latency_budget`](crate::policy::EndpointPolicy::latency_budget)).
+    /// Does nothing by default.
+    fn record_slow_call(&self, metrics: &RequestMetrics, budget: Duration) {
+        let _ = (metrics, budget);
+    }
+}
+
+/// The metrics of a single request.
+#[derive(Debug, Clone)]
+pub struct RequestMetrics {
+    /// The HTTP method of the request.
+    pub method: Method,
+    /// The request path without the query string, with segments that look
+    /// like IDs replaced by `{id}`, e.g., `/resources/applicants/{id}/status`.
+    pub endpoint: String,
+    /// The HTTP status of the response, or `None` if no response was received.
+    pub status: Option<StatusCode>,
+    /// The time elapsed between sending the request and receiving the
+    /// response headers or the error.
+    pub latency: Duration,
+}
+
+/// A `MetricsSink` that discards all metrics. This is the default.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopMetricsSink;
+
+impl MetricsSink for NoopMetricsSink {
+    fn record(&self, _metrics: &RequestMetrics) {}
+}
+
+/// The metrics sink registered on a client.
+#[derive(Clone)]
+pub(crate) struct Metrics(Arc<dyn MetricsSink>);
+
+impl Metrics {
+    pub(crate) fn new(sink: Arc<dyn MetricsSink>) -> Self {
+        Self(sink)
+    }
+
+    pub(crate) fn record(&self, metrics: &RequestMetrics) {
+        self.0.record(metrics);
+    }
+
+    pub(crate) fn record_slow_call(&self, metrics: &RequestMetrics, budget: Duration) {
+        self.0.record_slow_call(metrics, budget);
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self(Arc::new(NoopMetricsSink))
+    }
+}
+
+impl fmt::Debug for Metrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Metrics")
+    }
+}
+
+/// Normalizes a request path into an endpoint name with a bounded number of
+/// values: the query string is dropped, and segments containing digits, `=`,
+/// or `@` (IDs, matrix parameters, and emails) are replaced by `{id}`.
+pub(crate) fn endpoint_name(path: &str) -> String {
+    let path = path.split('?').next().unwrap_or(path);
+    path.split('/')
+        .map(|segment| {
+            if segment.chars().any(|c| c.is_ascii_digit() || c == '=' || c == '@') {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
@@ -2,10 +2,11 @@
 
 //! This module will contain data structures for miscellaneous endpoints.
 
+use crate::ids::{ApplicantId, ExternalUserId, ImageId};
 use serde::Deserialize;
 
 /// Represents an audit trail event.
-#[derive(Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AuditTrailEvent {
     pub ts: String,
@@ -20,22 +21,103 @@ pub struct AuditTrailEvent {
     pub x_client_id: Option<String>,
     pub correlation_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub applicant_id: Option<String>,
+    pub applicant_id: Option<ApplicantId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub external_user_id: Option<String>,
+    pub external_user_id: Option<ExternalUserId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub image_id: Option<String>,
+    pub image_id: Option<ImageId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 }
 
 use serde::Serialize;
+use std::collections::BTreeMap;
 use crate::actions::RequiredIdDocs;
+use crate::error::SumsubError;
+
+/// Represents a member of the team (an agent) in the Sumsub dashboard.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TeamMember {
+    pub id: String,
+    pub email: String,
+    pub name: Option<String>,
+    /// The role of the member, e.g., `admin` or `compliance_officer`.
+    pub role: String,
+    #[serde(default)]
+    pub active: bool,
+}
 
 /// Represents the health status of the API.
 #[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
 pub struct ApiHealthStatus {
+    /// The overall status as reported by the API, e.g., `ok`.
     pub status: String,
+    /// The statuses of individual components, if reported.
+    #[serde(default)]
+    pub components: Vec<ComponentHealthStatus>,
+    /// The ongoing incident, if any.
+    pub incident: Option<HealthIncident>,
+}
+
+impl ApiHealthStatus {
+    /// The overall health, taking the worst of the reported status and the
+    /// statuses of all components.
+    pub fn health(&self) -> Health {
+        self.components
+            .iter()
+            .map(ComponentHealthStatus::health)
+            .fold(Health::from_status(&self.status), Ord::max)
+    }
+}
+
+/// The health status of a single API component.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentHealthStatus {
+    pub name: String,
+    pub status: String,
+}
+
+impl ComponentHealthStatus {
+    /// The health of the component.
+    pub fn health(&self) -> Health {
+        Health::from_status(&self.status)
+    }
+}
+
+/// An incident affecting the API.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthIncident {
+    pub id: Option<String>,
+    pub title: Option<String>,
+    pub status: Option<String>,
+    pub started_at: Option<String>,
+    pub url: Option<String>,
+}
+
+/// The health of the API or one of its components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Health {
+    /// Fully operational.
+    Ok,
+    /// Operational with reduced performance or partial outages. Statuses not
+    /// known to this crate are treated as degraded.
+    Degraded,
+    /// Not operational.
+    Down,
+}
+
+impl Health {
+    fn from_status(status: &str) -> Self {
+        match status.to_ascii_lowercase().as_str() {
+            "ok" | "up" | "operational" | "healthy" => Health::Ok,
+            "down" | "outage" | "major_outage" | "unavailable" => Health::Down,
+            _ => Health::Degraded,
+        }
+    }
 }
 
 #[derive(Serialize, Debug)]
@@ -43,7 +125,7 @@ pub struct ApiHealthStatus {
 pub struct GenerateAccessTokenRequest<'a> {
     pub level_name: &'a str,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub external_user_id: Option<&'a str>,
+    pub external_user_id: Option<&'a ExternalUserId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ttl_in_secs: Option<u64>,
 }
@@ -53,7 +135,7 @@ pub struct GenerateAccessTokenRequest<'a> {
 pub struct GenerateWebsdkLinkRequest<'a> {
     pub level_name: &'a str,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub external_user_id: Option<&'a str>,
+    pub external_user_id: Option<&'a ExternalUserId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub ttl_in_secs: Option<u64>,
 }
@@ -67,13 +149,99 @@ pub struct GenerateWebsdkLinkResponse {
 #[serde(rename_all = "camelCase")]
 pub struct NewApplicantAccessTokenResponse {
     pub token: String,
-    pub user_id: String,
+    pub user_id: ExternalUserId,
 }
 
+/// A language code for messages sent to applicants: an ISO 639-1 code, e.g.,
+/// `en`, optionally followed by a region, e.g., `pt-BR`.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct LanguageCode(String);
+
+impl LanguageCode {
+    /// Creates a language code, returning a `SumsubError::Validation` if it
+    /// is not of the form `xx` or `xx-YY`.
+    pub fn new(code: &str) -> Result<Self, SumsubError> {
+        let (language, region) = match code.split_once('-') {
+            Some((language, region)) => (language, Some(region)),
+            None => (code, None),
+        };
+        let valid = language.len() == 2
+            && language.chars().all(|c| c.is_ascii_lowercase())
+            && region.is_none_or(|region| region.len() == 2 && region.chars().all(|c| c.is_ascii_uppercase()));
+        if !valid {
+            return Err(SumsubError::Validation(format!("invalid language code: {:?}", code)));
+        }
+        Ok(Self(code.to_string()))
+    }
+
+    /// The language code as a string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::str::FromStr for LanguageCode {
+    type Err = SumsubError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Self::new(code)
+    }
+}
+
+impl std::fmt::Display for LanguageCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A request to send a verification email or SMS to an applicant.
+///
+/// # Example
+///
+/// ```
+/// use sumsub_api::misc::{LanguageCode, SendVerificationMessageRequest};
+///
+/// let request = SendVerificationMessageRequest::new(LanguageCode::new("de")?)
+///     .template("kyc-reminder")
+///     .variable("firstName", "Anna");
+/// # Ok::<(), sumsub_api::error::SumsubError>(())
+/// ```
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct SendVerificationMessageRequest<'a> {
-    pub lang: &'a str,
+    /// The language of the message.
+    pub lang: LanguageCode,
+    /// The name of a message template configured in the dashboard. The
+    /// default template of the level is used if `None`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_name: Option<&'a str>,
+    /// The values substituted for the variables of the template.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<&'a str, &'a str>,
+}
+
+impl<'a> SendVerificationMessageRequest<'a> {
+    /// Creates a request for the default template in the given language.
+    pub fn new(lang: LanguageCode) -> Self {
+        Self {
+            lang,
+            template_name: None,
+            variables: BTreeMap::new(),
+        }
+    }
+
+    /// Selects a message template by name.
+    pub fn template(mut self, template_name: &'a str) -> Self {
+        self.template_name = Some(template_name);
+        self
+    }
+
+    /// Sets the value of a template variable.
+    pub fn variable(mut self, name: &'a str, value: &'a str) -> Self {
+        self.variables.insert(name, value);
+        self
+    }
 }
 
 
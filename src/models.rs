@@ -6,13 +6,14 @@
 
 use serde::{Deserialize, Serialize};
 use crate::kyb::CompanyInfo;
+use crate::ids::{ApplicantId, ExternalUserId};
 
 /// Represents the request to create a new applicant.
 #[derive(Serialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateApplicantRequest {
     /// A unique identifier for the applicant in your system.
-    pub external_user_id: String,
+    pub external_user_id: ExternalUserId,
     /// The applicant's email address.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub email: Option<String>,
@@ -28,6 +29,19 @@ pub struct CreateApplicantRequest {
     pub applicant_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub info: Option<Info>,
+    /// A key for segmenting applicants by onboarding source or channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_key: Option<String>,
+    /// Custom key-value metadata, e.g., the integration that created the applicant.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub metadata: Vec<ApplicantMetadata>,
+}
+
+/// A custom key-value pair attached to an applicant.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ApplicantMetadata {
+    pub key: String,
+    pub value: String,
 }
 
 /// Represents the fixed information about an applicant.
@@ -54,6 +68,42 @@ pub struct FixedInfo {
     pub company_info: Option<CompanyInfo>,
 }
 
+impl FixedInfo {
+    /// Returns the fields of `self` that are set and differ from `current`,
+    /// or `None` if `current` already matches.
+    ///
+    /// Fields that are `None` in `self` are left unchanged.
+    pub fn changes_from(self, current: &FixedInfo) -> Option<FixedInfo> {
+        fn changed<T: PartialEq>(desired: Option<T>, current: &Option<T>) -> Option<T> {
+            desired.filter(|value| current.as_ref() != Some(value))
+        }
+
+        let company_info = self.company_info.filter(|desired| {
+            let current = current.company_info.as_ref().map(serde_json::to_value);
+            let desired = serde_json::to_value(desired);
+            match (current, desired) {
+                (Some(Ok(current)), Ok(desired)) => current != desired,
+                _ => true,
+            }
+        });
+        let changes = FixedInfo {
+            country: changed(self.country, &current.country),
+            first_name: changed(self.first_name, &current.first_name),
+            last_name: changed(self.last_name, &current.last_name),
+            dob: changed(self.dob, &current.dob),
+            place_of_birth: changed(self.place_of_birth, &current.place_of_birth),
+            company_info,
+        };
+        let unchanged = changes.country.is_none()
+            && changes.first_name.is_none()
+            && changes.last_name.is_none()
+            && changes.dob.is_none()
+            && changes.place_of_birth.is_none()
+            && changes.company_info.is_none();
+        (!unchanged).then_some(changes)
+    }
+}
+
 /// Represents the information about an applicant.
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
@@ -113,7 +163,7 @@ pub struct Address {
 #[serde(rename_all = "camelCase")]
 pub struct Applicant {
     /// The unique identifier of the applicant.
-    pub id: String,
+    pub id: ApplicantId,
     /// The timestamp of when the applicant was created.
     pub created_at: String,
     /// The ID of the client that the applicant belongs to.
@@ -121,13 +171,18 @@ pub struct Applicant {
     /// The ID of the inspection associated with the applicant.
     pub inspection_id: String,
     /// The external user ID of the applicant.
-    pub external_user_id: String,
+    pub external_user_id: ExternalUserId,
     /// The applicant's email address.
     pub email: Option<String>,
     /// The applicant's phone number.
     pub phone: Option<String>,
     /// The platform from which the applicant was created.
-    pub applicant_platform: Option<String>,
+    pub applicant_platform: Option<ApplicantPlatform>,
+    /// The key for segmenting applicants by onboarding source or channel.
+    pub source_key: Option<String>,
+    /// Custom key-value metadata attached to the applicant.
+    #[serde(default)]
+    pub metadata: Vec<ApplicantMetadata>,
     /// The review status of the applicant.
     pub review: Review,
     /// The type of the applicant (e.g., "individual" or "company").
@@ -135,10 +190,86 @@ pub struct Applicant {
     pub applicant_type: String,
 }
 
+/// The platform from which an applicant was created.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(from = "String")]
+pub enum ApplicantPlatform {
+    /// Created through the API.
+    Api,
+    /// Created through the WebSDK, including on mobile browsers.
+    WebSdk,
+    /// Created through the iOS or Android MobileSDK.
+    MobileSdk,
+    /// A platform not known to this crate.
+    Other(String),
+}
+
+impl From<String> for ApplicantPlatform {
+    fn from(platform: String) -> Self {
+        match platform.to_ascii_lowercase().as_str() {
+            "api" => ApplicantPlatform::Api,
+            "web" | "websdk" | "mobileweb" => ApplicantPlatform::WebSdk,
+            "ios" | "android" | "mobilesdk" => ApplicantPlatform::MobileSdk,
+            _ => ApplicantPlatform::Other(platform),
+        }
+    }
+}
+
 /// Represents the review status of an applicant.
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct Review {
     /// The review status of the applicant (e.g., "completed", "pending").
     pub review_status: String,
+    /// The agent who made the decision, if it was made manually.
+    pub reviewed_by: Option<crate::applicants::Agent>,
+}
+
+/// A numeric score (e.g., a transaction risk score or an AML match strength),
+/// keeping the number exactly as the API sent it next to its parsed value.
+///
+/// With the `raw-numbers` feature, [`Score::raw`] is the literal from the
+/// response (e.g., `"0.70"`), suitable for audit-grade storage. Without it,
+/// the literal is not preserved by the JSON parser and `raw` is the shortest
+/// representation of the parsed value (e.g., `"0.7"`).
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(try_from = "serde_json::Number")]
+pub struct Score {
+    value: f64,
+    raw: String,
+}
+
+impl Score {
+    /// The parsed value.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// The number as sent by the API. See the type-level documentation.
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl TryFrom<serde_json::Number> for Score {
+    type Error = String;
+
+    fn try_from(number: serde_json::Number) -> Result<Self, Self::Error> {
+        let value = number
+            .as_f64()
+            .ok_or_else(|| format!("score {} is not representable as f64", number))?;
+        Ok(Score { value, raw: number.to_string() })
+    }
+}
+
+impl From<Score> for f64 {
+    fn from(score: Score) -> Self {
+        score.value
+    }
+}
+
+impl std::fmt::Display for Score {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.raw)
+    }
 }
@@ -0,0 +1,125 @@
+// src/multipart.rs
+
+//! This module contains the builder for `multipart/form-data` bodies.
+//!
+//! Sumsub signs requests over the raw body, including the multipart framing,
+//! so uploads are built in memory instead of being encoded by `reqwest` while
+//! they are sent. The boundary is derived from the content of the form, so
+//! the same form is always encoded the same way and the boundary never occurs
+//! inside a part. The built body is plain bytes, so the retry layer resends
+//! the same body and signs each attempt again, like any JSON body.
+
+use crate::error::SumsubError;
+use reqwest::header::HeaderValue;
+use sha2::{Digest, Sha256};
+
+/// A `multipart/form-data` form, encoded by [`MultipartForm::finish`].
+#[derive(Debug, Default)]
+pub(crate) struct MultipartForm {
+    parts: Vec<Part>,
+}
+
+#[derive(Debug)]
+struct Part {
+    /// The part headers, without the boundary line.
+    headers: String,
+    content: Vec<u8>,
+}
+
+/// An encoded `multipart/form-data` body and its content type.
+#[derive(Debug, Clone)]
+pub(crate) struct MultipartBody {
+    pub(crate) content_type: HeaderValue,
+    pub(crate) bytes: Vec<u8>,
+}
+
+impl MultipartForm {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a text part, e.g., JSON metadata.
+    pub(crate) fn text(mut self, name: &str, value: &str) -> Self {
+        self.parts.push(Part {
+            headers: disposition(name, None),
+            content: value.as_bytes().to_vec(),
+        });
+        self
+    }
+
+    /// Adds a file part.
+    pub(crate) fn file(
+        mut self,
+        name: &str,
+        content: Vec<u8>,
+        file_name: &str,
+        mime_type: &str,
+    ) -> Result<Self, SumsubError> {
+        let is_valid_mime = mime_type.split_once('/').is_some_and(|(type_, subtype)| {
+            !type_.is_empty() && !subtype.is_empty()
+        }) && HeaderValue::from_str(mime_type).is_ok();
+        if !is_valid_mime {
+            return Err(SumsubError::MimeError(format!("invalid MIME type: {:?}", mime_type)));
+        }
+        self.parts.push(Part {
+            headers: format!("{}Content-Type: {}\r\n", disposition(name, Some(file_name)), mime_type),
+            content,
+        });
+        Ok(self)
+    }
+
+    /// Encodes the form with a boundary that occurs in none of its parts.
+    pub(crate) fn finish(self) -> MultipartBody {
+        let mut hasher = Sha256::new();
+        for part in &self.parts {
+            hasher.update((part.headers.len() as u64).to_be_bytes());
+            hasher.update(part.headers.as_bytes());
+            hasher.update((part.content.len() as u64).to_be_bytes());
+            hasher.update(&part.content);
+        }
+        let mut digest = hasher.finalize();
+        let mut boundary = format!("sumsub-rs-{}", hex::encode(&digest[..16]));
+        while self.parts.iter().any(|part| {
+            contains(part.headers.as_bytes(), boundary.as_bytes()) || contains(&part.content, boundary.as_bytes())
+        }) {
+            digest = Sha256::digest(digest);
+            boundary = format!("sumsub-rs-{}", hex::encode(&digest[..16]));
+        }
+
+        let mut bytes = Vec::new();
+        for part in &self.parts {
+            bytes.extend_from_slice(format!("--{}\r\n{}\r\n", boundary, part.headers).as_bytes());
+            bytes.extend_from_slice(&part.content);
+            bytes.extend_from_slice(b"\r\n");
+        }
+        bytes.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+
+        MultipartBody {
+            content_type: HeaderValue::from_str(&format!("multipart/form-data; boundary={}", boundary))
+                .expect("a hex boundary is a valid header value"),
+            bytes,
+        }
+    }
+}
+
+/// Returns the `Content-Disposition` header of a part.
+fn disposition(name: &str, file_name: Option<&str>) -> String {
+    let mut disposition = format!("form-data; name=\"{}\"", escape(name));
+    if let Some(file_name) = file_name {
+        disposition.push_str(&format!("; filename=\"{}\"", escape(file_name)));
+    }
+    format!("Content-Disposition: {}\r\n", disposition)
+}
+
+/// Returns `true` if `needle` occurs in `haystack`.
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|window| window == needle)
+}
+
+/// Escapes a quoted header parameter the way browsers do.
+fn escape(value: &str) -> String {
+    value
+        .replace('"', "%22")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
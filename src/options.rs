@@ -0,0 +1,111 @@
+// src/options.rs
+
+//! This module contains `RequestOptions`, which customize individual calls
+//! made through the `Client`.
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use std::time::Duration;
+
+/// The header carrying the idempotency key of requests that create
+/// applicants, transactions, or documents. The server decides whether to
+/// honour it.
+pub const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// Options applied to the requests of a single call.
+///
+/// Extra query parameters are appended to the path before the request is
+/// signed, so the signature stays valid. Extra headers are not part of the
+/// signature; authentication headers cannot be overridden. A timeout
+/// overrides both the client-wide timeout and the endpoint policy timeout.
+///
+/// # Example
+///
+/// ```
+/// use reqwest::header::{HeaderName, HeaderValue};
+/// use sumsub_api::client::Client;
+/// use sumsub_api::options::RequestOptions;
+///
+/// let client = Client::new("YOUR_APP_TOKEN".to_string(), "YOUR_SECRET_KEY".to_string());
+/// let options = RequestOptions::new()
+///     .header(HeaderName::from_static("x-impersonate-client"), HeaderValue::from_static("client-id"))
+///     .query("sourceKey", "eu")
+///     .timeout(std::time::Duration::from_secs(60));
+/// let scoped = client.with_options(options);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    pub(crate) headers: HeaderMap,
+    pub(crate) query: Vec<(String, String)>,
+    pub(crate) locale: Option<String>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) idempotency_key: Option<String>,
+    /// The idempotency key sent by the current call, set by the `Client`
+    /// for calls that create applicants, transactions, or documents.
+    pub(crate) call_idempotency_key: Option<String>,
+}
+
+impl RequestOptions {
+    /// Creates empty request options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a header to every request.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+
+    /// Sets the locale sent as the `Accept-Language` header, overriding the
+    /// locale set with [`Client::with_locale`](crate::client::Client::with_locale).
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Adds a query parameter to every request.
+    pub fn query(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sets the timeout of every request, from sending it until the response
+    /// body has been read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the key sent in the [`IDEMPOTENCY_KEY_HEADER`] header by calls
+    /// that create an applicant, submit a transaction, or upload a document.
+    /// Other calls do not send it.
+    ///
+    /// Every such call made through the client returned by
+    /// [`Client::with_options`](crate::client::Client::with_options) sends
+    /// this key, so scope a client to a single operation:
+    /// `client.with_options(RequestOptions::new().idempotency_key(key)).create_applicant(...)`.
+    /// Calls through other clients send a generated key if
+    /// [`ClientBuilder::idempotency_keys`](crate::client::ClientBuilder::idempotency_keys)
+    /// is enabled, or none. The crate only sends the header and does not
+    /// deduplicate calls itself; whether a repeated key is rejected or
+    /// ignored is up to the server.
+    ///
+    /// Use a key that identifies the operation, e.g., a UUID stored with the
+    /// pending operation.
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    /// Appends the extra query parameters to `path`, percent-encoding them.
+    pub(crate) fn apply_query(&self, path: &str) -> String {
+        let mut path = path.to_string();
+        for (key, value) in &self.query {
+            path.push(if path.contains('?') { '&' } else { '?' });
+            path.push_str(&urlencoding::encode(key));
+            path.push('=');
+            path.push_str(&urlencoding::encode(value));
+        }
+        path
+    }
+}
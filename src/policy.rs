@@ -0,0 +1,185 @@
+// src/policy.rs
+
+//! This module contains the timeout and retry policies applied to classes of
+//! endpoints.
+
+use crate::error::SumsubError;
+use reqwest::Method;
+use std::future::Future;
+use std::time::Duration;
+
+/// A class of endpoints with similar latency and safety characteristics.
+///
+/// The client selects the class of each request from its method and path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EndpointClass {
+    /// Fast `GET` requests for metadata, e.g., applicant data and statuses.
+    Read,
+    /// `GET` requests for files, e.g., images, videos, and reports.
+    Download,
+    /// Requests that change state (`POST`, `PATCH`, `PUT`, `DELETE`).
+    Write,
+}
+
+impl EndpointClass {
+    /// Classifies a request by its method and path.
+    pub(crate) fn classify(method: &Method, path: &str) -> Self {
+        if method != Method::GET {
+            return EndpointClass::Write;
+        }
+        let path = path.split('?').next().unwrap_or(path);
+        let is_download = path.ends_with(".pdf")
+            || path.ends_with(".zip")
+            || path.ends_with("/video")
+            || path.contains("/resource/")
+            || path.contains("/images/")
+            || path.contains("/report.");
+        if is_download {
+            EndpointClass::Download
+        } else {
+            EndpointClass::Read
+        }
+    }
+}
+
+/// The timeout and retry policy for a class of endpoints.
+///
+/// Requests are retried after network errors, rate limiting (429), and server
+/// errors (5xx), waiting `retry_delay` before the first retry and doubling it
+/// for every further retry. If the response has a `Retry-After` header, its
+/// delay is used instead, up to `max_retry_after` (one minute by default).
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use sumsub_api::client::Client;
+/// use sumsub_api::policy::{EndpointClass, EndpointPolicy};
+///
+/// let client = Client::builder("YOUR_APP_TOKEN", "YOUR_SECRET_KEY")
+///     .policy(EndpointClass::Read, EndpointPolicy::new().timeout(Duration::from_secs(5)).max_retries(2))
+///     .policy(EndpointClass::Download, EndpointPolicy::new().timeout(Duration::from_secs(120)))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct EndpointPolicy {
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) max_retries: u32,
+    pub(crate) retry_delay: Duration,
+    pub(crate) max_retry_after: Duration,
+    pub(crate) latency_budget: Option<Duration>,
+}
+
+impl Default for EndpointPolicy {
+    fn default() -> Self {
+        Self {
+            timeout: None,
+            max_retries: 0,
+            retry_delay: Duration::from_millis(500),
+            max_retry_after: Duration::from_secs(60),
+            latency_budget: None,
+        }
+    }
+}
+
+impl EndpointPolicy {
+    /// Creates a policy without a timeout of its own and without retries.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the timeout of each attempt, overriding the client-wide timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the maximum number of retries after the first attempt.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn retry_delay(mut self, retry_delay: Duration) -> Self {
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Sets the longest wait before a retry that a `Retry-After` header can
+    /// ask for. Longer delays are shortened to this, so that a misbehaving
+    /// server or proxy cannot stall the client for hours.
+    pub fn max_retry_after(mut self, max_retry_after: Duration) -> Self {
+        self.max_retry_after = max_retry_after;
+        self
+    }
+
+    /// Sets the latency above which an attempt is reported as slow, well
+    /// below the timeout, to notice slowdowns of the API before requests
+    /// time out. Slow attempts are reported to
+    /// [`MetricsSink::record_slow_call`](crate::metrics::MetricsSink::record_slow_call)
+    /// and, with the `tracing` feature, logged as warnings.
+    pub fn latency_budget(mut self, latency_budget: Duration) -> Self {
+        self.latency_budget = Some(latency_budget);
+        self
+    }
+}
+
+/// The policies of all endpoint classes.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Policies {
+    read: EndpointPolicy,
+    download: EndpointPolicy,
+    write: EndpointPolicy,
+}
+
+impl Policies {
+    pub(crate) fn get(&self, class: EndpointClass) -> &EndpointPolicy {
+        match class {
+            EndpointClass::Read => &self.read,
+            EndpointClass::Download => &self.download,
+            EndpointClass::Write => &self.write,
+        }
+    }
+
+    pub(crate) fn set(&mut self, class: EndpointClass, policy: EndpointPolicy) {
+        match class {
+            EndpointClass::Read => self.read = policy,
+            EndpointClass::Download => self.download = policy,
+            EndpointClass::Write => self.write = policy,
+        }
+    }
+}
+
+/// Returns the delay before retry number `retry` (counting from 0):
+/// `retry_delay`, doubled for every earlier retry. Saturates instead of
+/// overflowing.
+pub(crate) fn backoff(retry_delay: Duration, retry: u32) -> Duration {
+    retry_delay.saturating_mul(2u32.saturating_pow(retry))
+}
+
+/// Runs `attempt` until it succeeds, fails with an error that is not
+/// retryable (see [`SumsubError::is_retryable`]), or `max_retries` retries
+/// were made, waiting [`backoff`] between attempts. Returns the last result
+/// and the number of attempts.
+pub(crate) async fn retry<T, F, Fut>(
+    max_retries: u32,
+    retry_delay: Duration,
+    mut attempt: F,
+) -> (Result<T, SumsubError>, u32)
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, SumsubError>>,
+{
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        match attempt().await {
+            Err(e) if e.is_retryable() && attempts <= max_retries => {
+                tokio::time::sleep(backoff(retry_delay, attempts - 1)).await;
+            }
+            result => return (result, attempts),
+        }
+    }
+}
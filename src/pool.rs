@@ -0,0 +1,167 @@
+// src/pool.rs
+
+//! This module contains the `ClientPool`, which routes calls to one of
+//! several Sumsub apps by key.
+
+use crate::client::{default_http_client, Client, ClientBuilder};
+use crate::error::SumsubError;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// Supplies the credentials of Sumsub apps that are not registered up front,
+/// e.g., one sub-account per tenant loaded from a database or secret store.
+///
+/// It is implemented for closures taking the key and returning a builder.
+pub trait CredentialProvider: Send + Sync {
+    /// Returns a builder with the credentials and settings of the app for
+    /// `key` (e.g., a source key or tenant ID), or `None` if the key is unknown.
+    fn client_builder(&self, key: &str) -> Option<ClientBuilder>;
+}
+
+impl<F> CredentialProvider for F
+where
+    F: Fn(&str) -> Option<ClientBuilder> + Send + Sync,
+{
+    fn client_builder(&self, key: &str) -> Option<ClientBuilder> {
+        self(key)
+    }
+}
+
+/// The credential provider of a pool and the clients built from it.
+#[derive(Clone)]
+struct Provider {
+    provider: Arc<dyn CredentialProvider>,
+    clients: Arc<Mutex<HashMap<String, Client>>>,
+}
+
+impl fmt::Debug for Provider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Provider")
+    }
+}
+
+/// A set of clients for several Sumsub apps (e.g., one per product line or
+/// brand), selected by key and sharing one HTTP connection pool.
+///
+/// # Example
+///
+/// ```
+/// use sumsub_api::client::Client;
+/// use sumsub_api::pool::ClientPool;
+///
+/// let mut pool = ClientPool::new();
+/// pool.add("cards", Client::builder("CARDS_APP_TOKEN", "CARDS_SECRET_KEY")).unwrap();
+/// pool.add("crypto", Client::builder("CRYPTO_APP_TOKEN", "CRYPTO_SECRET_KEY")).unwrap();
+///
+/// let client = pool.get("crypto").unwrap();
+/// ```
+///
+/// Apps can also be resolved on first use with a [`CredentialProvider`]:
+///
+/// ```
+/// use sumsub_api::client::Client;
+/// use sumsub_api::pool::ClientPool;
+///
+/// # fn load_credentials(tenant_id: &str) -> Option<(String, String)> { None }
+/// let pool = ClientPool::new().with_provider(|tenant_id: &str| {
+///     let (app_token, secret_key) = load_credentials(tenant_id)?;
+///     Some(Client::builder(app_token, secret_key))
+/// });
+///
+/// let client = pool.client("tenant-42");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ClientPool {
+    http_client: reqwest::Client,
+    clients: HashMap<String, Client>,
+    provider: Option<Provider>,
+}
+
+impl Default for ClientPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ClientPool {
+    /// Creates an empty pool with a default HTTP client.
+    pub fn new() -> Self {
+        Self::with_http_client(
+            default_http_client()
+                .build()
+                .expect("failed to build the default HTTP client"),
+        )
+    }
+
+    /// Creates an empty pool whose clients share `http_client`. See
+    /// [`ClientBuilder::http_client`] for how it should be configured.
+    pub fn with_http_client(http_client: reqwest::Client) -> Self {
+        Self {
+            http_client,
+            clients: HashMap::new(),
+            provider: None,
+        }
+    }
+
+    /// Sets the provider of credentials for keys without a registered client.
+    /// Clients built from it share the pool's HTTP client and are cached by
+    /// key, also in clones of the pool.
+    pub fn with_provider(mut self, provider: impl CredentialProvider + 'static) -> Self {
+        self.provider = Some(Provider {
+            provider: Arc::new(provider),
+            clients: Arc::default(),
+        });
+        self
+    }
+
+    /// Builds a client with the pool's HTTP client and registers it under
+    /// `key`, replacing any client registered under the same key.
+    ///
+    /// Returns the error of [`ClientBuilder::build`], e.g., if the builder
+    /// has a connect timeout, which cannot be applied to a shared HTTP client.
+    pub fn add(&mut self, key: impl Into<String>, builder: ClientBuilder) -> Result<(), SumsubError> {
+        let client = builder.http_client(self.http_client.clone()).build()?;
+        self.clients.insert(key.into(), client);
+        Ok(())
+    }
+
+    /// Returns the client registered under `key`, or a
+    /// `SumsubError::Configuration` if there is none.
+    pub fn get(&self, key: &str) -> Result<&Client, SumsubError> {
+        self.clients
+            .get(key)
+            .ok_or_else(|| SumsubError::Configuration(format!("no Sumsub client for key {:?}", key)))
+    }
+
+    /// The keys of all registered clients, in no particular order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.clients.keys().map(String::as_str)
+    }
+
+    /// Returns the client for `key`: the registered client, or one built
+    /// from the credential provider on first use.
+    ///
+    /// Returns a `SumsubError::Configuration` if neither knows the key, and
+    /// the error of [`ClientBuilder::build`] if the provided builder is invalid.
+    pub fn client(&self, key: &str) -> Result<Client, SumsubError> {
+        if let Some(client) = self.clients.get(key) {
+            return Ok(client.clone());
+        }
+        let Some(provider) = &self.provider else {
+            return self.get(key).cloned();
+        };
+        if let Some(client) = provider.clients.lock().unwrap_or_else(|e| e.into_inner()).get(key) {
+            return Ok(client.clone());
+        }
+        // Built without holding the lock, as the provider may be slow.
+        let client = provider
+            .provider
+            .client_builder(key)
+            .ok_or_else(|| SumsubError::Configuration(format!("no Sumsub credentials for key {:?}", key)))?
+            .http_client(self.http_client.clone())
+            .build()?;
+        let mut clients = provider.clients.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(clients.entry(key.to_string()).or_insert(client).clone())
+    }
+}
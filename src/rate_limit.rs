@@ -0,0 +1,55 @@
+// src/rate_limit.rs
+
+//! This module contains the token-bucket rate limiter used by the `Client` to
+//! stay within the Sumsub API limits.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A token bucket refilled at `rate` tokens per second, holding at most
+/// `burst` tokens.
+///
+/// Each request takes one token. When the bucket is empty, the token is
+/// reserved anyway and the request waits until it would have been refilled,
+/// so concurrent callers are served in the order they arrived.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    rate: f64,
+    burst: f64,
+    state: Mutex<Bucket>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a full bucket. `rate` must be positive and `burst` at least 1.
+    pub(crate) fn new(rate: f64, burst: u32) -> Self {
+        Self {
+            rate,
+            burst: f64::from(burst),
+            state: Mutex::new(Bucket {
+                tokens: f64::from(burst),
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a request may be sent.
+    pub(crate) async fn acquire(&self) {
+        let wait = {
+            let mut bucket = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            let now = Instant::now();
+            let refill = now.duration_since(bucket.last_refill).as_secs_f64() * self.rate;
+            bucket.tokens = (bucket.tokens + refill).min(self.burst) - 1.0;
+            bucket.last_refill = now;
+            (bucket.tokens < 0.0).then(|| Duration::from_secs_f64(-bucket.tokens / self.rate))
+        };
+        if let Some(wait) = wait {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
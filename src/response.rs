@@ -0,0 +1,94 @@
+// src/response.rs
+
+//! This module contains the `ApiResponse` wrapper, which exposes the
+//! diagnostics the API returns alongside successful responses.
+
+use serde::Deserialize;
+
+/// The name of the response header carrying the request's correlation ID.
+pub(crate) const CORRELATION_ID_HEADER: &str = "x-correlation-id";
+
+/// The name of the response header carrying the ID Sumsub assigned to the request.
+pub(crate) const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// The body of a download, streamed in chunks as they arrive.
+///
+/// Returned by the `*_stream` methods of the `Client`, e.g.,
+/// [`Client::get_liveness_video_stream`](crate::client::Client::get_liveness_video_stream).
+pub type ByteStream = futures::stream::BoxStream<'static, Result<bytes::Bytes, crate::error::SumsubError>>;
+
+/// A successful response together with its diagnostics.
+///
+/// Returned by the `*_with_diagnostics` methods of the `Client`, e.g.,
+/// [`Client::get_applicant_data_with_diagnostics`](crate::client::Client::get_applicant_data_with_diagnostics).
+#[derive(Debug, Clone)]
+pub struct ApiResponse<T> {
+    /// The response data.
+    pub data: T,
+    /// Warnings returned alongside the data, e.g., image quality warnings or
+    /// records skipped during an import.
+    pub warnings: Vec<ApiWarning>,
+    /// The correlation ID of the request, useful when contacting Sumsub support.
+    pub correlation_id: Option<String>,
+    /// The URL the response was received from, after following redirects.
+    pub url: String,
+    /// The ID Sumsub assigned to the request (`X-Request-Id`), if returned.
+    pub request_id: Option<String>,
+    /// The rate limit state reported with the response.
+    pub rate_limit: RateLimitStatus,
+}
+
+impl<T> ApiResponse<T> {
+    /// Replaces the data, keeping the diagnostics.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> ApiResponse<U> {
+        ApiResponse {
+            data: f(self.data),
+            warnings: self.warnings,
+            correlation_id: self.correlation_id,
+            url: self.url,
+            request_id: self.request_id,
+            rate_limit: self.rate_limit,
+        }
+    }
+}
+
+/// The rate limit state reported in the `X-RateLimit-*` headers of a
+/// response. Each field is `None` if its header is missing.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// The number of requests allowed in the current window.
+    pub limit: Option<u32>,
+    /// The number of requests left in the current window.
+    pub remaining: Option<u32>,
+    /// The number of seconds until the window resets.
+    pub reset_secs: Option<u64>,
+}
+
+/// A warning returned alongside a successful response.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "RawWarning")]
+pub struct ApiWarning {
+    pub code: Option<String>,
+    pub message: String,
+}
+
+/// Warnings are returned either as plain messages or as objects.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawWarning {
+    Message(String),
+    Detailed {
+        code: Option<String>,
+        #[serde(alias = "description")]
+        message: String,
+    },
+}
+
+impl From<RawWarning> for ApiWarning {
+    fn from(raw: RawWarning) -> Self {
+        match raw {
+            RawWarning::Message(message) => ApiWarning { code: None, message },
+            RawWarning::Detailed { code, message } => ApiWarning { code, message },
+        }
+    }
+}
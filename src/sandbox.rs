@@ -0,0 +1,68 @@
+// src/sandbox.rs
+
+//! This module contains helpers for generating applicants in the Sandbox
+//! environment, for deterministic end-to-end tests.
+
+use crate::applicants::IdDocSetType;
+
+/// The state a sandbox applicant should end up in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SandboxOutcome {
+    /// The applicant is approved (`GREEN`).
+    Approved,
+    /// The applicant is rejected (`RED`) with the given reject labels.
+    Rejected {
+        reject_labels: Vec<String>,
+        /// `true` for a final rejection, `false` to allow the applicant to retry.
+        final_reject: bool,
+    },
+    /// The applicant is submitted for review without a simulated decision,
+    /// so it stays in the review queue.
+    OnHold,
+}
+
+impl SandboxOutcome {
+    /// Returns the review answer to simulate, or `None` if no review should be simulated.
+    pub(crate) fn review_answer(&self) -> Option<&'static str> {
+        match self {
+            SandboxOutcome::Approved => Some("GREEN"),
+            SandboxOutcome::Rejected { .. } => Some("RED"),
+            SandboxOutcome::OnHold => None,
+        }
+    }
+
+    /// Returns the reject type to simulate for rejections.
+    pub(crate) fn review_reject_type(&self) -> Option<&'static str> {
+        match self {
+            SandboxOutcome::Rejected { final_reject: true, .. } => Some("FINAL"),
+            SandboxOutcome::Rejected { final_reject: false, .. } => Some("RETRY"),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the reject labels that make Sumsub ask for `doc_set` again, used
+/// when a simulated rejection does not specify its own labels.
+pub(crate) fn default_reject_labels(doc_set: &IdDocSetType) -> Vec<&'static str> {
+    match doc_set {
+        IdDocSetType::Identity => vec!["UNSATISFACTORY_PHOTOS"],
+        IdDocSetType::Selfie => vec!["BAD_SELFIE"],
+        IdDocSetType::ProofOfResidence => vec!["BAD_PROOF_OF_ADDRESS"],
+        IdDocSetType::Custom(_) => vec!["DOCUMENT_PAGE_MISSING"],
+    }
+}
+
+/// A 1x1 PNG image uploaded as a stub document for sandbox applicants.
+pub(crate) const STUB_IMAGE: &[u8] = &[
+    0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x48, 0x44, 0x52,
+    0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x08, 0x06, 0x00, 0x00, 0x00, 0x1F, 0x15, 0xC4,
+    0x89, 0x00, 0x00, 0x00, 0x0D, 0x49, 0x44, 0x41, 0x54, 0x78, 0x9C, 0x63, 0xF8, 0xFF, 0xFF, 0x3F,
+    0x00, 0x05, 0xFE, 0x02, 0xFE, 0xA7, 0x35, 0x81, 0x84, 0x00, 0x00, 0x00, 0x00, 0x49, 0x45, 0x4E,
+    0x44, 0xAE, 0x42, 0x60, 0x82,
+];
+
+/// The document types uploaded for sandbox applicants.
+pub(crate) const STUB_DOC_TYPES: [&str; 2] = ["PASSPORT", "SELFIE"];
+
+/// The country used for stub documents when the request does not specify one.
+pub(crate) const DEFAULT_COUNTRY: &str = "GBR";
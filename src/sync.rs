@@ -0,0 +1,120 @@
+// src/sync.rs
+
+//! This module contains the data structures for incrementally syncing
+//! applicants and transactions, e.g., into a data warehouse.
+
+/// The records changed since a point in time, and where the next sync should start.
+///
+/// Syncs are at-least-once: records changed at or after `next_since` are
+/// returned again by the next sync, so consumers should upsert by ID.
+///
+/// The list endpoints are paged by offset, so a record changed while a sync
+/// is paging can move to a page that was already read and be missed. To
+/// pick it up, `next_since` never moves past the start of the sync (less
+/// [`SYNC_OVERLAP_SECS`]); records changed since then are returned by the
+/// next sync.
+#[derive(Debug)]
+pub struct SyncBatch<T> {
+    /// The changed records, in the order they were returned by the API.
+    pub items: Vec<T>,
+    /// The timestamp (`YYYY-MM-DD HH:MM:SS`, UTC) to pass to the next sync:
+    /// the latest change among `items`, at most the start of the sync less
+    /// [`SYNC_OVERLAP_SECS`], and never before the previous timestamp.
+    pub next_since: String,
+}
+
+/// How many seconds before the start of a sync `next_since` is kept, to
+/// cover records changed during the sync and clock differences with the API.
+pub const SYNC_OVERLAP_SECS: i64 = 60;
+
+/// Returns the time a record returned by a list endpoint was last changed.
+pub(crate) fn changed_at(item: &serde_json::Value) -> Option<&str> {
+    item.get("updatedAt")
+        .or_else(|| item.get("createdAt"))
+        .and_then(|value| value.as_str())
+}
+
+/// Parses a timestamp given as `YYYY-MM-DD HH:MM:SS` (UTC) or in ISO 8601
+/// (e.g., `2024-02-01T10:00:00.123Z` or `2024-02-01T12:00:00+02:00`) into
+/// Unix seconds.
+pub(crate) fn parse_timestamp(value: &str) -> Option<i64> {
+    let (date_time, rest) = (value.get(..19)?, &value[19..]);
+    let bytes = date_time.as_bytes();
+    let separators = [(4, b'-'), (7, b'-'), (10, b' '), (13, b':'), (16, b':')];
+    if !separators
+        .iter()
+        .all(|&(i, c)| bytes[i] == c || (i == 10 && bytes[i] == b'T'))
+    {
+        return None;
+    }
+    let number = |start: usize, len: usize| -> Option<i64> {
+        let digits = &date_time[start..start + len];
+        digits.bytes().all(|b| b.is_ascii_digit()).then(|| digits.parse().ok())?
+    };
+    let (year, month, day) = (number(0, 4)?, number(5, 2)?, number(8, 2)?);
+    let (hour, minute, second) = (number(11, 2)?, number(14, 2)?, number(17, 2)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let rest = match rest.strip_prefix('.') {
+        Some(fraction) => fraction.trim_start_matches(|c: char| c.is_ascii_digit()),
+        None => rest,
+    };
+    let offset = match rest {
+        "" | "Z" => 0,
+        _ => {
+            let sign = match rest.as_bytes()[0] {
+                b'+' => 1,
+                b'-' => -1,
+                _ => return None,
+            };
+            let offset = rest[1..].replace(':', "");
+            if offset.len() != 4 || !offset.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            sign * (offset[..2].parse::<i64>().ok()? * 3600 + offset[2..].parse::<i64>().ok()? * 60)
+        }
+    };
+    Some(days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second - offset)
+}
+
+/// Formats Unix seconds as `YYYY-MM-DD HH:MM:SS` (UTC).
+pub(crate) fn format_timestamp(secs: i64) -> String {
+    let (year, month, day) = civil_from_days(secs.div_euclid(86_400));
+    let time = secs.rem_euclid(86_400);
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+/// The number of days since 1970-01-01 of a date in the proleptic Gregorian
+/// calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The inverse of `days_from_civil`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
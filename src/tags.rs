@@ -0,0 +1,58 @@
+// src/tags.rs
+
+//! This module contains the tag dictionaries used to validate tags locally
+//! before they are sent to the API.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+/// The kind of object a tag is attached to. Each kind has its own dictionary
+/// of tags configured in the Sumsub dashboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TagKind {
+    Applicant,
+    Transaction,
+}
+
+impl TagKind {
+    /// Returns the path of the dictionary endpoint.
+    pub(crate) fn dictionary_path(self) -> &'static str {
+        match self {
+            TagKind::Applicant => "/resources/applicants/-/tags",
+            TagKind::Transaction => "/resources/kyt/txns/-/tags",
+        }
+    }
+}
+
+/// The tag dictionaries fetched so far, shared by all clones of a `Client`.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TagCache {
+    dictionaries: Arc<Mutex<HashMap<TagKind, Arc<HashSet<String>>>>>,
+}
+
+impl TagCache {
+    pub(crate) fn get(&self, kind: TagKind) -> Option<Arc<HashSet<String>>> {
+        self.dictionaries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&kind)
+            .cloned()
+    }
+
+    pub(crate) fn set(&self, kind: TagKind, tags: Vec<String>) -> Arc<HashSet<String>> {
+        let tags = Arc::new(tags.into_iter().collect::<HashSet<_>>());
+        self.dictionaries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(kind, tags.clone());
+        tags
+    }
+}
+
+/// Returns the tags that are not in `dictionary`.
+pub(crate) fn unknown_tags<'a>(dictionary: &HashSet<String>, tags: &[&'a str]) -> Vec<&'a str> {
+    tags.iter()
+        .copied()
+        .filter(|tag| !dictionary.contains(*tag))
+        .collect()
+}
@@ -0,0 +1,148 @@
+// src/tokens.rs
+
+//! This module contains the `TokenManager`, which caches WebSDK access
+//! tokens until shortly before they expire.
+
+use crate::client::Client;
+use crate::error::SumsubError;
+use crate::ids::{ApplicantId, ExternalUserId};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// The lifetime Sumsub gives access tokens when none is requested.
+const DEFAULT_TOKEN_TTL: Duration = Duration::from_secs(600);
+
+/// Whom a token was issued for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Subject {
+    ExternalUser(ExternalUserId),
+    Applicant(ApplicantId),
+}
+
+#[derive(Debug)]
+struct CachedToken {
+    token: String,
+    expires_at: Instant,
+}
+
+/// Issues WebSDK access tokens and caches them per applicant and level,
+/// generating a new token once a cached one is about to expire.
+///
+/// Concurrent requests for the same uncached token may each generate one;
+/// the last one generated is cached.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use sumsub_api::client::Client;
+/// use sumsub_api::ids::ExternalUserId;
+/// use sumsub_api::tokens::TokenManager;
+///
+/// # async fn run(client: Client) -> Result<(), sumsub_api::error::SumsubError> {
+/// let tokens = TokenManager::new(client).ttl(Duration::from_secs(1800));
+/// let token = tokens
+///     .token_for_external_user(&ExternalUserId::from("user-42"), "basic-kyc-level")
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TokenManager {
+    client: Client,
+    ttl: Duration,
+    refresh_margin: Duration,
+    cache: Mutex<HashMap<(Subject, String), CachedToken>>,
+}
+
+impl TokenManager {
+    /// Creates a manager issuing tokens with Sumsub's default lifetime of
+    /// 10 minutes, refreshed 30 seconds before they expire.
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            ttl: DEFAULT_TOKEN_TTL,
+            refresh_margin: Duration::from_secs(30),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Sets the lifetime requested for tokens issued by external user ID.
+    /// Tokens for existing applicants always have Sumsub's default lifetime.
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets how long before expiry a cached token is replaced, leaving the
+    /// caller enough time to hand it to the WebSDK.
+    pub fn refresh_margin(mut self, refresh_margin: Duration) -> Self {
+        self.refresh_margin = refresh_margin;
+        self
+    }
+
+    /// Returns a token for the applicant with the given external user ID,
+    /// who is created on first use. See [`Client::generate_token_for_new_applicant`].
+    pub async fn token_for_external_user(
+        &self,
+        external_user_id: &ExternalUserId,
+        level_name: &str,
+    ) -> Result<String, SumsubError> {
+        let key = (Subject::ExternalUser(external_user_id.clone()), level_name.to_string());
+        if let Some(token) = self.cached(&key) {
+            return Ok(token);
+        }
+        let response = self
+            .client
+            .generate_token_for_new_applicant(Some(level_name), Some(external_user_id), Some(self.ttl.as_secs()))
+            .await?;
+        Ok(self.store(key, response.token, self.ttl))
+    }
+
+    /// Returns a token for an existing applicant. See
+    /// [`Client::generate_token_for_existing_applicant`].
+    pub async fn token_for_applicant(
+        &self,
+        applicant_id: &ApplicantId,
+        level_name: &str,
+    ) -> Result<String, SumsubError> {
+        let key = (Subject::Applicant(applicant_id.clone()), level_name.to_string());
+        if let Some(token) = self.cached(&key) {
+            return Ok(token);
+        }
+        let token = self
+            .client
+            .generate_token_for_existing_applicant(applicant_id, level_name)
+            .await?;
+        Ok(self.store(key, token, DEFAULT_TOKEN_TTL))
+    }
+
+    /// Removes all cached tokens, e.g., after the applicant's level changed.
+    pub fn clear(&self) {
+        self.cache.lock().unwrap_or_else(|e| e.into_inner()).clear();
+    }
+
+    fn cached(&self, key: &(Subject, String)) -> Option<String> {
+        let cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        cache
+            .get(key)
+            .filter(|cached| Instant::now() + self.refresh_margin < cached.expires_at)
+            .map(|cached| cached.token.clone())
+    }
+
+    /// Caches a token issued now, dropping expired entries.
+    fn store(&self, key: (Subject, String), token: String, ttl: Duration) -> String {
+        let now = Instant::now();
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        cache.retain(|_, cached| cached.expires_at > now);
+        cache.insert(
+            key,
+            CachedToken {
+                token: token.clone(),
+                expires_at: now + ttl,
+            },
+        );
+        token
+    }
+}
@@ -2,6 +2,8 @@
 
 //! This module will contain the data structures for transaction monitoring.
 
+use crate::error::SumsubError;
+use crate::ids::{ApplicantId, ExternalUserId, TxnId};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -9,7 +11,7 @@ use std::collections::HashMap;
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct SubmitTransactionRequest {
-    pub txn_id: String,
+    pub txn_id: TxnId,
     pub txn_date: String,
     #[serde(rename = "type")]
     pub txn_type: String,
@@ -24,13 +26,22 @@ pub struct SubmitTransactionRequest {
     pub props: Option<HashMap<String, String>>,
 }
 
+/// The type of a transaction participant.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionApplicantType {
+    #[default]
+    Individual,
+    Company,
+}
+
 /// Represents the applicant or counterparty in a transaction.
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionApplicant {
     #[serde(rename = "type")]
-    pub applicant_type: String,
-    pub external_user_id: String,
+    pub applicant_type: TransactionApplicantType,
+    pub external_user_id: ExternalUserId,
     pub full_name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub place_of_birth: Option<String>,
@@ -46,6 +57,28 @@ pub struct TransactionApplicant {
     pub payment_method: Option<PaymentMethod>,
 }
 
+impl TransactionApplicant {
+    /// Creates an individual participant with the given external user ID and full name.
+    pub fn individual(external_user_id: impl Into<ExternalUserId>, full_name: impl Into<String>) -> Self {
+        Self {
+            applicant_type: TransactionApplicantType::Individual,
+            external_user_id: external_user_id.into(),
+            full_name: full_name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a company participant with the given external user ID and company name.
+    pub fn company(external_user_id: impl Into<ExternalUserId>, company_name: impl Into<String>) -> Self {
+        Self {
+            applicant_type: TransactionApplicantType::Company,
+            external_user_id: external_user_id.into(),
+            full_name: company_name.into(),
+            ..Default::default()
+        }
+    }
+}
+
 /// Represents the address of a transaction participant.
 #[derive(Serialize, Deserialize, Debug, Default)]
 #[serde(rename_all = "camelCase")]
@@ -146,7 +179,162 @@ pub struct TransactionInfo {
 #[serde(rename_all = "camelCase")]
 pub struct CryptoParams {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub crypto_chain: Option<String>,
+    pub crypto_chain: Option<CryptoChain>,
+}
+
+/// A blockchain network, identified by its Sumsub chain code (e.g., `ETH`).
+///
+/// A chain is not a currency: `ETH` is the Ethereum network, on which
+/// currencies such as `ETH` and `USDT` are transferred.
+///
+/// Parsing with [`str::parse`] accepts the chain codes and common names
+/// (e.g., `ethereum`) case-insensitively and rejects anything else, so typos
+/// fail locally. Chain codes in responses that are not known to this crate
+/// deserialize into [`CryptoChain::Unknown`].
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(into = "String")]
+pub enum CryptoChain {
+    Btc,
+    Eth,
+    Trx,
+    Sol,
+    Bsc,
+    Polygon,
+    Ltc,
+    Xrp,
+    Ton,
+    /// A chain code not known to this crate, as received from the API.
+    /// [`CryptoChain::validate_address`] rejects it.
+    Unknown(String),
+}
+
+impl CryptoChain {
+    /// The Sumsub chain code, e.g., `ETH`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            CryptoChain::Btc => "BTC",
+            CryptoChain::Eth => "ETH",
+            CryptoChain::Trx => "TRX",
+            CryptoChain::Sol => "SOL",
+            CryptoChain::Bsc => "BSC",
+            CryptoChain::Polygon => "MATIC",
+            CryptoChain::Ltc => "LTC",
+            CryptoChain::Xrp => "XRP",
+            CryptoChain::Ton => "TON",
+            CryptoChain::Unknown(code) => code,
+        }
+    }
+
+    fn known(code: &str) -> Option<Self> {
+        let chain = match code.to_ascii_lowercase().as_str() {
+            "btc" | "bitcoin" => CryptoChain::Btc,
+            "eth" | "ethereum" => CryptoChain::Eth,
+            "trx" | "tron" => CryptoChain::Trx,
+            "sol" | "solana" => CryptoChain::Sol,
+            "bsc" | "bnb" => CryptoChain::Bsc,
+            "matic" | "polygon" => CryptoChain::Polygon,
+            "ltc" | "litecoin" => CryptoChain::Ltc,
+            "xrp" | "ripple" => CryptoChain::Xrp,
+            "ton" => CryptoChain::Ton,
+            _ => return None,
+        };
+        Some(chain)
+    }
+}
+
+impl CryptoChain {
+    /// Checks the format of a wallet address on this chain:
+    ///
+    /// * `Btc`: a Base58Check address (`1...` or `3...`) or a SegWit address
+    ///   (`bc1...`), including the checksum.
+    /// * `Eth`, `Bsc`, `Polygon`: `0x` followed by 40 hex digits, with a valid
+    ///   EIP-55 checksum if the address is mixed-case.
+    /// * `Trx`: a Base58Check address starting with `T`, including the checksum.
+    ///
+    /// Addresses on other known chains are not checked. An unknown chain is
+    /// an error, since it may be a typo.
+    pub fn validate_address(&self, address: &str) -> Result<(), SumsubError> {
+        let valid = match self {
+            CryptoChain::Unknown(code) => {
+                return Err(SumsubError::Validation(format!("unknown crypto chain: {}", code)));
+            }
+            CryptoChain::Btc => is_valid_btc_address(address),
+            CryptoChain::Eth | CryptoChain::Bsc | CryptoChain::Polygon => is_valid_evm_address(address),
+            CryptoChain::Trx => {
+                address.starts_with('T') && base58check_version(address) == Some(0x41)
+            }
+            _ => true,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(SumsubError::Validation(format!("invalid {} address: {}", self, address)))
+        }
+    }
+}
+
+/// Decodes a Base58Check address with a 20-byte payload and returns its version byte.
+fn base58check_version(address: &str) -> Option<u8> {
+    let decoded = bs58::decode(address).with_check(None).into_vec().ok()?;
+    (decoded.len() == 21).then(|| decoded[0])
+}
+
+fn is_valid_btc_address(address: &str) -> bool {
+    if address.get(..3).is_some_and(|p| p.eq_ignore_ascii_case("bc1")) {
+        return matches!(bech32::segwit::decode(address), Ok((hrp, _, _)) if hrp == bech32::hrp::BC);
+    }
+    matches!(base58check_version(address), Some(0x00 | 0x05))
+}
+
+fn is_valid_evm_address(address: &str) -> bool {
+    use sha3::{Digest, Keccak256};
+
+    let Some(hex) = address.strip_prefix("0x") else {
+        return false;
+    };
+    if hex.len() != 40 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return false;
+    }
+    let has_lower = hex.chars().any(|c| c.is_ascii_lowercase());
+    let has_upper = hex.chars().any(|c| c.is_ascii_uppercase());
+    if !(has_lower && has_upper) {
+        return true;
+    }
+    let hash = Keccak256::digest(hex.to_ascii_lowercase().as_bytes());
+    hex.chars().enumerate().all(|(i, c)| {
+        let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+        !c.is_ascii_alphabetic() || c.is_ascii_uppercase() == (nibble >= 8)
+    })
+}
+
+impl std::fmt::Display for CryptoChain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for CryptoChain {
+    type Err = SumsubError;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Self::known(code).ok_or_else(|| SumsubError::Validation(format!("unknown crypto chain: {}", code)))
+    }
+}
+
+impl<'de> Deserialize<'de> for CryptoChain {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        Ok(Self::known(&code).unwrap_or(CryptoChain::Unknown(code)))
+    }
+}
+
+impl From<CryptoChain> for String {
+    fn from(chain: CryptoChain) -> Self {
+        match chain {
+            CryptoChain::Unknown(code) => code,
+            chain => chain.as_str().to_string(),
+        }
+    }
 }
 
 /// Represents the information about a financial institution.
@@ -181,8 +369,8 @@ pub struct SubmitTransactionResponse {
     pub id: String,
     pub created_at: String,
     pub client_id: String,
-    pub applicant_id: String,
-    pub txn_id: String,
+    pub applicant_id: ApplicantId,
+    pub txn_id: TxnId,
     #[serde(rename = "type")]
     pub txn_type: String,
     pub review: TransactionReview,
@@ -198,8 +386,8 @@ pub struct SubmitTransactionResponse {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ScoringResult {
-    pub score: f64,
-    pub dry_score: f64,
+    pub score: crate::models::Score,
+    pub dry_score: crate::models::Score,
     pub matched_rules: Vec<MatchedRule>,
     pub action: String,
     pub rule_cnt: u32,
@@ -214,11 +402,39 @@ pub struct MatchedRule {
     pub name: String,
     pub revision: u32,
     pub title: String,
-    pub score: f64,
+    pub score: crate::models::Score,
     pub dry_run: bool,
     pub action: String,
 }
 
+/// The aggregated transaction monitoring state of an applicant.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct KytApplicantSummary {
+    /// The current risk score of the applicant.
+    pub risk_score: Option<f64>,
+    /// The current risk level of the applicant (e.g., `low`, `high`).
+    pub risk_level: Option<String>,
+    /// The total number of transactions submitted for the applicant.
+    #[serde(default)]
+    pub total_txn_cnt: u32,
+    /// The number of transactions currently on hold.
+    #[serde(default)]
+    pub on_hold_txn_cnt: u32,
+    /// The number of times each rule was triggered by the applicant's transactions.
+    #[serde(default)]
+    pub triggered_rules: Vec<TriggeredRuleCount>,
+}
+
+/// The number of times a rule was triggered.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggeredRuleCount {
+    pub id: String,
+    pub name: String,
+    pub count: u32,
+}
+
 /// Represents the Travel Rule information of a transaction.
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
@@ -237,7 +453,7 @@ pub struct TravelRuleInfo {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct TravelRuleParticipant {
-    pub external_user_id: String,
+    pub external_user_id: ExternalUserId,
     pub full_name: String,
 }
 
@@ -273,7 +489,7 @@ pub struct DeleteTransactionResponse {
 #[serde(rename_all = "camelCase")]
 pub struct BulkTransactionImportRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub applicant_id: Option<String>,
+    pub applicant_id: Option<ApplicantId>,
     pub data: SubmitTransactionRequest,
 }
 
@@ -289,12 +505,13 @@ pub enum TransactionReviewAction {
     Reject,
 }
 
-impl ToString for TransactionReviewAction {
-    fn to_string(&self) -> String {
-        match self {
-            TransactionReviewAction::Approve => "approve".to_string(),
-            TransactionReviewAction::Reject => "reject".to_string(),
-        }
+impl std::fmt::Display for TransactionReviewAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            TransactionReviewAction::Approve => "approve",
+            TransactionReviewAction::Reject => "reject",
+        };
+        f.write_str(s)
     }
 }
 
@@ -326,6 +543,29 @@ pub struct AvailableCurrenciesResponse {
     pub currencies: Vec<String>,
 }
 
+impl AvailableCurrenciesResponse {
+    /// Returns `true` if the currency code (e.g., `USDT`) is among the
+    /// available currencies, ignoring case.
+    ///
+    /// The list contains currencies, not chains; check chains with
+    /// [`CryptoChain`] instead.
+    pub fn supports(&self, currency: &str) -> bool {
+        self.currencies
+            .iter()
+            .any(|available| available.eq_ignore_ascii_case(currency))
+    }
+
+    /// Returns a `SumsubError::Validation` if the currency code is not among
+    /// the available currencies.
+    pub fn validate_currency(&self, currency: &str) -> Result<(), SumsubError> {
+        if self.supports(currency) {
+            Ok(())
+        } else {
+            Err(SumsubError::Validation(format!("currency {} is not available", currency)))
+        }
+    }
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct AddTransactionTagsRequest<'a> {
@@ -349,7 +589,7 @@ pub struct RemoveTransactionTagsRequest<'a> {
 pub struct TransactionNote {
     pub id: String,
     pub created_at: String,
-    pub txn_id: String,
+    pub txn_id: TxnId,
     pub agent: crate::applicants::Agent,
     pub note: String,
 }
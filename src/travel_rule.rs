@@ -1,10 +1,15 @@
+use crate::error::SumsubError;
+use crate::ids::{ApplicantId, TxnId};
+use crate::query::Query;
+use crate::transactions::CryptoChain;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Serialize, Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct InitiateSdkRequest {
-    pub txn_id: String,
-    pub user_id: String,
+    pub txn_id: TxnId,
+    pub user_id: crate::ids::ExternalUserId,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -26,12 +31,13 @@ pub enum OwnershipStatus {
     Rejected,
 }
 
-impl ToString for OwnershipStatus {
-    fn to_string(&self) -> String {
-        match self {
-            OwnershipStatus::Confirmed => "confirmed".to_string(),
-            OwnershipStatus::Rejected => "rejected".to_string(),
-        }
+impl std::fmt::Display for OwnershipStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            OwnershipStatus::Confirmed => "confirmed",
+            OwnershipStatus::Rejected => "rejected",
+        };
+        f.write_str(s)
     }
 }
 
@@ -47,7 +53,15 @@ pub struct ConfirmWalletOwnershipRequest {
 pub struct ImportWalletAddressesRequest {
     pub address: String,
     pub currency: String,
-    pub network: String,
+    pub network: CryptoChain,
+}
+
+impl ImportWalletAddressesRequest {
+    /// Checks the format of the address on its network.
+    /// See [`CryptoChain::validate_address`].
+    pub fn validate(&self) -> Result<(), SumsubError> {
+        self.network.validate_address(&self.address)
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -94,3 +108,49 @@ pub struct Vasp {
     pub logo: String,
     pub is_test: bool,
 }
+
+/// A Travel Rule transfer: the exchange of originator and beneficiary data
+/// with the counterparty VASP for a transaction.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TravelRuleTransfer {
+    pub id: String,
+    /// The ID of the KYT transaction the transfer belongs to.
+    pub txn_id: Option<TxnId>,
+    pub applicant_id: Option<ApplicantId>,
+    /// The status of the transfer, e.g., `awaitingCounterparty` or `completed`.
+    pub status: String,
+    /// `in` for incoming and `out` for outgoing transfers.
+    pub direction: Option<String>,
+    pub counterparty_vasp_id: Option<String>,
+    pub counterparty_vasp_name: Option<String>,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+    /// Any other fields returned by the API.
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+/// Filters for listing Travel Rule transfers.
+#[derive(Debug, Clone, Default)]
+pub struct TransferFilter {
+    /// Only include transfers with this status, e.g., `awaitingCounterparty`.
+    pub status: Option<String>,
+    /// Only include transfers with this counterparty VASP (see [`Vasp::id`]).
+    pub counterparty_vasp_id: Option<String>,
+    /// Only include transfers created at or after this time (`YYYY-MM-DD HH:MM:SS`).
+    pub since: Option<String>,
+    /// Only include transfers created before this time (`YYYY-MM-DD HH:MM:SS`).
+    pub until: Option<String>,
+}
+
+impl TransferFilter {
+    /// Appends the filter parameters to `query`.
+    pub(crate) fn apply(&self, query: Query) -> Query {
+        query
+            .opt_param("status", self.status.as_ref())
+            .opt_param("counterpartyVaspId", self.counterparty_vasp_id.as_ref())
+            .opt_param("createdAtFrom", self.since.as_ref())
+            .opt_param("createdAtTo", self.until.as_ref())
+    }
+}
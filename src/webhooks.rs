@@ -3,8 +3,9 @@
 //! This module contains functionality for handling Sumsub webhooks, including
 //! signature verification and payload deserialization.
 
+use crate::ids::{ApplicantId, ExternalUserId};
 use hmac::{Hmac, Mac};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use sha1::Sha1;
 
 type HmacSha1 = Hmac<Sha1>;
@@ -33,9 +34,56 @@ pub fn verify_signature(secret_key: &str, payload: &[u8], signature: &str) -> Re
     mac.verify_slice(&decoded_signature).map_err(|_| "Invalid signature")
 }
 
+/// A bundled, real-world webhook payload sample.
+#[derive(Debug, Clone, Copy)]
+pub struct WebhookSample {
+    /// The webhook event type (the `type` field of the payload).
+    pub event_type: &'static str,
+    /// The raw JSON payload.
+    pub json: &'static str,
+}
+
+/// The bundled webhook samples, one per supported event type.
+const SAMPLES: [WebhookSample; 2] = [
+    WebhookSample {
+        event_type: "applicantReviewed",
+        json: include_str!("../webhook_samples/applicant_reviewed.json"),
+    },
+    WebhookSample {
+        event_type: "applicantPending",
+        json: include_str!("../webhook_samples/applicant_pending.json"),
+    },
+];
+
+/// Iterates over the bundled webhook payload samples, one per event type
+/// supported by [`WebhookPayload`].
+///
+/// Useful for property and round-trip tests of webhook handlers.
+pub fn samples() -> impl Iterator<Item = WebhookSample> {
+    SAMPLES.into_iter()
+}
+
+/// Asserts that every bundled webhook sample deserializes into a [`WebhookPayload`].
+///
+/// Downstream applications can call this from their own test suites to detect
+/// incompatibilities when upgrading the crate.
+///
+/// # Panics
+///
+/// Panics with the event type and the deserialization error of the first
+/// sample that fails to parse.
+pub fn assert_parses_all() {
+    for sample in samples() {
+        if let Err(e) = serde_json::from_str::<WebhookPayload>(sample.json) {
+            panic!("webhook sample `{}` failed to parse: {}", sample.event_type, e);
+        }
+    }
+}
+
 /// Represents the different types of webhook payloads.
 #[derive(Deserialize, Debug)]
 #[serde(tag = "type", rename_all = "camelCase")]
+#[allow(clippy::large_enum_variant)]
 pub enum WebhookPayload {
     ApplicantReviewed(ApplicantReviewedPayload),
     ApplicantPending(ApplicantPendingPayload),
@@ -46,25 +94,58 @@ pub enum WebhookPayload {
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplicantReviewedPayload {
-    pub applicant_id: String,
+    pub applicant_id: ApplicantId,
     pub inspection_id: String,
     pub correlation_id: String,
     pub level_name: String,
-    pub external_user_id: Option<String>,
+    pub external_user_id: Option<ExternalUserId>,
     pub review: WebhookReview,
     pub created_at: String,
     pub applicant_type: String,
 }
 
+/// The data related to an `applicantReviewed` webhook, as fetched by
+/// [`Client::get_review_context`](crate::client::Client::get_review_context).
+#[derive(Debug)]
+pub struct ReviewContext {
+    pub applicant: crate::models::Applicant,
+    /// All moderation states of the applicant, oldest first.
+    pub moderation_states: Vec<crate::applicants::ModerationState>,
+    /// The latest check results per verification step, keyed by `idDocSetType`.
+    pub step_results: std::collections::HashMap<String, Option<crate::applicants::RequiredDocStepStatus>>,
+}
+
+/// A request to deliver past webhooks again, e.g., events missed during an
+/// outage. Webhooks matching all given criteria are resent.
+///
+/// Used with [`Client::resend_webhooks`](crate::client::Client::resend_webhooks).
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookResendRequest {
+    /// Only resend webhooks created at or after this time (`YYYY-MM-DD HH:MM:SS`).
+    #[serde(rename = "createdAtFrom", skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+    /// Only resend webhooks created before this time (`YYYY-MM-DD HH:MM:SS`).
+    #[serde(rename = "createdAtTo", skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+    /// Only resend webhooks of these applicants. All applicants if empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub applicant_ids: Vec<ApplicantId>,
+    /// Only resend webhooks of these types (e.g., `applicantReviewed`). All
+    /// types if empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub types: Vec<String>,
+}
+
 /// Payload for the `applicantPending` webhook.
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct ApplicantPendingPayload {
-    pub applicant_id: String,
+    pub applicant_id: ApplicantId,
     pub inspection_id: String,
     pub correlation_id: String,
     pub level_name: String,
-    pub external_user_id: Option<String>,
+    pub external_user_id: Option<ExternalUserId>,
     pub created_at: String,
 }
 
@@ -92,6 +173,25 @@ pub struct WebhookReviewResult {
     pub moderation_comment: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_comment: Option<String>,
-    #[serde(rename = "rRejectType", skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "rRejectType", alias = "reviewRejectType", skip_serializing_if = "Option::is_none")]
     pub review_reject_type: Option<String>,
 }
+
+impl WebhookReviewResult {
+    /// Returns `true` if the applicant was approved (`GREEN`).
+    pub fn is_approved(&self) -> bool {
+        self.review_answer == "GREEN"
+    }
+
+    /// Returns `true` if the applicant was rejected (`RED`) and may not resubmit
+    /// documents (`FINAL`).
+    pub fn is_final_reject(&self) -> bool {
+        self.review_answer == "RED" && self.review_reject_type.as_deref() == Some("FINAL")
+    }
+
+    /// Returns `true` if the applicant was rejected (`RED`) but may resubmit
+    /// documents (`RETRY`).
+    pub fn retry_allowed(&self) -> bool {
+        self.review_answer == "RED" && self.review_reject_type.as_deref() == Some("RETRY")
+    }
+}
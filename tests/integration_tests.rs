@@ -1,17 +1,21 @@
 use sumsub_api::client::Client;
 use sumsub_api::error::SumsubError;
-use sumsub_api::models::{CreateApplicantRequest, FixedInfo};
-use sumsub_api::applicants::AddDocumentMetadata;
+use sumsub_api::models::{ApplicantPlatform, CreateApplicantRequest, FixedInfo};
+use sumsub_api::applicants::{AddDocumentMetadata, AmlReportFormat, ApplicantStatus, EventFilter, DataCategory, RecordAgreementRequest};
+use sumsub_api::hooks;
 use sumsub_api::webhooks;
-use sumsub_api::actions::AddActionImageMetadata;
-use sumsub_api::transactions::TransactionReviewAction;
-use sumsub_api::travel_rule::UpdateWalletAddressRequest;
-use sumsub_api::device_intelligence::{PlatformEvent, DeviceFingerprint};
+use sumsub_api::actions::{ActionFilter, AddActionImageMetadata, CreateApplicantActionRequest, PaymentSource, PaymentSourceFixedInfo, PaymentSourceType};
+use sumsub_api::transactions::{CryptoChain, CryptoParams, TransactionApplicant, TransactionApplicantType, TransactionReviewAction};
+use sumsub_api::travel_rule::{ImportWalletAddressesRequest, UpdateWalletAddressRequest};
+use sumsub_api::device_intelligence::{CounterpartyDevice, DeviceFingerprint, DeviceTokenRequest, PlatformEvent};
+use sumsub_api::policy::{EndpointClass, EndpointPolicy};
+use sumsub_api::checks::{CheckType, StartCheckRequest};
+use sumsub_api::api::SumsubApi;
+use sumsub_api::ids::{ActionId, ApplicantId, ExternalUserId, ImageId, TxnId};
 
-use mockito;
 use uuid::Uuid;
-use hex;
 use serde_json::json;
+use futures::TryStreamExt;
 
 // Helper function to generate HMAC-SHA1 signature for testing
 fn generate_webhook_signature(secret_key: &str, payload: &str) -> String {
@@ -53,8 +57,8 @@ async fn test_create_and_get_applicant() {
     let url = server.url();
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
-    let external_user_id = Uuid::new_v4().to_string();
-    let applicant_id = Uuid::new_v4().to_string();
+    let external_user_id = ExternalUserId::from(Uuid::new_v4().to_string());
+    let applicant_id = ApplicantId::from(Uuid::new_v4().to_string());
     let inspection_id = Uuid::new_v4().to_string();
     let level_name = "basic-kyc";
 
@@ -84,6 +88,7 @@ async fn test_create_and_get_applicant() {
 
     let created_applicant = client.create_applicant(create_applicant_request, level_name).await.unwrap();
     assert_eq!(created_applicant.id, applicant_id);
+    assert_eq!(created_applicant.applicant_platform, Some(ApplicantPlatform::Api));
 
     mock_create.assert_async().await;
 
@@ -105,7 +110,7 @@ async fn test_get_applicant_data_not_found() {
     let url = server.url();
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
-    let applicant_id = "non_existent_id";
+    let applicant_id = &ApplicantId::from("non_existent_id");
 
     let mock = server.mock("GET", &format!("/resources/applicants/{}/one", applicant_id)[..])
         .with_status(404)
@@ -117,8 +122,8 @@ async fn test_get_applicant_data_not_found() {
 
     assert!(result.is_err());
     match result.err().unwrap() {
-        SumsubError::ApiError { status, .. } => assert_eq!(status, 404),
-        _ => panic!("Expected ApiError"),
+        e @ SumsubError::NotFound { .. } => assert_eq!(e.status(), Some(404)),
+        _ => panic!("Expected NotFound"),
     }
 }
 
@@ -128,7 +133,7 @@ async fn test_add_verification_document() {
     let url = server.url();
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
-    let applicant_id = "some_applicant_id";
+    let applicant_id = &ApplicantId::from("some_applicant_id");
 
     let mock = server.mock("POST", &format!("/resources/applicants/{}/docsets/-", applicant_id)[..])
         .with_status(201)
@@ -222,7 +227,7 @@ async fn test_move_applicant_to_level() {
     let url = server.url();
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
-    let applicant_id = "some_applicant_id";
+    let applicant_id = &ApplicantId::from("some_applicant_id");
     let level_name = "new-level";
 
     let mock = server.mock("POST", &format!("/resources/applicants/{}/moveToLevel?levelName={}", applicant_id, level_name)[..])
@@ -241,7 +246,7 @@ async fn test_update_applicant_fixed_info() {
     let url = server.url();
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
-    let applicant_id = "some_applicant_id";
+    let applicant_id = &ApplicantId::from("some_applicant_id");
     let fixed_info = FixedInfo {
         first_name: Some("Jane".to_string()),
         ..Default::default()
@@ -263,7 +268,7 @@ async fn test_get_applicant_status() {
     let url = server.url();
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
-    let applicant_id = "some_applicant_id";
+    let applicant_id = &ApplicantId::from("some_applicant_id");
     let response_body = serde_json::json!({
         "createDate": "2023-10-26T10:00:00Z",
         "reviewStatus": "completed"
@@ -289,8 +294,8 @@ async fn test_add_image_to_action() {
     let url = server.url();
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
-    let action_id = "some_action_id";
-    let image_id = "some_image_id";
+    let action_id = &ActionId::from("some_action_id");
+    let image_id = &ImageId::from("some_image_id");
 
     let response_body = serde_json::json!([{
         "imageId": image_id,
@@ -329,8 +334,8 @@ async fn test_get_image_from_action() {
     let url = server.url();
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
-    let action_id = "some_action_id";
-    let image_id = "some_image_id";
+    let action_id = &ActionId::from("some_action_id");
+    let image_id = &ImageId::from("some_image_id");
     let image_content = vec![1, 2, 3, 4, 5];
 
     let mock = server.mock("GET", &format!("/resources/applicantActions/{}/images/{}", action_id, image_id)[..])
@@ -339,7 +344,7 @@ async fn test_get_image_from_action() {
         .with_body(&image_content)
         .create_async().await;
 
-    let result = client.get_image_from_action(action_id, image_id).await;
+    let result = client.get_image_from_action(&ActionId::from(action_id), &ImageId::from(image_id)).await;
 
     mock.assert_async().await;
     assert!(result.is_ok());
@@ -352,7 +357,7 @@ async fn test_review_transaction() {
     let url = server.url();
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
-    let txn_id = "some_txn_id";
+    let txn_id = &TxnId::from("some_txn_id");
     let response_body = serde_json::json!({
         "id": "some_id",
         "createdAt": "2023-10-26T10:00:00Z",
@@ -393,7 +398,7 @@ async fn test_rescore_transaction() {
     let url = server.url();
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
-    let txn_id = "some_txn_id";
+    let txn_id = &TxnId::from("some_txn_id");
     let response_body = serde_json::json!({
         "id": "some_id",
         "createdAt": "2023-10-26T10:00:00Z",
@@ -454,19 +459,33 @@ async fn test_generate_device_intelligence_token() {
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
     let token = "some_device_token";
-    let response_body = serde_json::json!({ "token": token });
+    let response_body = serde_json::json!({
+        "token": token,
+        "userId": "user@example.com",
+        "ttlInSecs": 600,
+        "sdkKey": "sdk_key"
+    });
 
-    let mock = server.mock("POST", "/resources/accessTokens?type=device")
+    let mock = server.mock("POST", "/resources/accessTokens?type=device&userId=user%40example.com")
+        .match_body(mockito::Matcher::Json(json!({ "lang": "en" })))
         .with_status(200)
         .with_header("content-type", "application/json")
         .with_body(response_body.to_string())
         .create_async().await;
 
-    let result = client.generate_device_intelligence_token(Some("en")).await;
+    let request = DeviceTokenRequest {
+        lang: Some("en"),
+        external_user_id: Some(&ExternalUserId::from("user@example.com")),
+        ..Default::default()
+    };
+    let result = client.generate_device_intelligence_token(request).await;
 
     mock.assert_async().await;
-    assert!(result.is_ok());
-    assert_eq!(result.unwrap(), token);
+    let result = result.unwrap();
+    assert_eq!(result.token, token);
+    assert_eq!(result.user_id.as_deref(), Some("user@example.com"));
+    assert_eq!(result.ttl_in_secs, Some(600));
+    assert_eq!(result.sdk_data["sdkKey"], "sdk_key");
 }
 
 #[tokio::test]
@@ -475,7 +494,7 @@ async fn test_send_platform_event() {
     let url = server.url();
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
-    let applicant_id = "some_applicant_id";
+    let applicant_id = &ApplicantId::from("some_applicant_id");
     let event = PlatformEvent {
         event_type: "login",
         event_timestamp: "2023-10-26T10:00:00Z",
@@ -501,7 +520,7 @@ async fn test_send_financial_transaction_with_device() {
     let url = server.url();
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
-    let txn_id = "some_txn_id";
+    let txn_id = &TxnId::from("some_txn_id");
     let fingerprint = "some_fingerprint";
 
     let mock = server.mock("POST", &format!("/resources/kyt/txns/{}/data/applicant/device", txn_id)[..])
@@ -577,7 +596,7 @@ async fn test_add_applicant_tags() {
     let url = server.url();
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
-    let applicant_id = "some_applicant_id";
+    let applicant_id = &ApplicantId::from("some_applicant_id");
     let tags = vec!["tag1", "tag2"];
 
     let mock = server.mock("POST", &format!("/resources/applicants/{}/tags", applicant_id)[..])
@@ -596,7 +615,7 @@ async fn test_add_transaction_tags() {
     let url = server.url();
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
-    let txn_id = "some_txn_id";
+    let txn_id = &TxnId::from("some_txn_id");
     let tags = vec!["tag1", "tag2"];
 
     let mock = server.mock("POST", &format!("/resources/kyt/txns/{}/tags", txn_id)[..])
@@ -615,7 +634,7 @@ async fn test_add_applicant_note() {
     let url = server.url();
     let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
 
-    let applicant_id = "some_applicant_id";
+    let applicant_id = &ApplicantId::from("some_applicant_id");
     let note_text = "This is a test note.";
     let response_body = json!({
         "id": "some_note_id",
@@ -642,3 +661,4069 @@ async fn test_add_applicant_note() {
     let note = result.unwrap();
     assert_eq!(note.note, note_text);
 }
+
+#[tokio::test]
+async fn test_record_applicant_agreement() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("some_applicant_id");
+    let response_body = json!({
+        "createdAt": "2023-10-26T10:00:00Z",
+        "acceptedAt": "2023-10-26T10:00:00Z",
+        "source": "api",
+        "targets": ["tos_v3", "privacy_v2"]
+    });
+
+    let mock = server.mock("POST", &format!("/resources/applicants/{}/agreement", applicant_id)[..])
+        .match_body(mockito::Matcher::Json(json!({ "targets": ["tos_v3", "privacy_v2"], "source": "api" })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(response_body.to_string())
+        .create_async().await;
+
+    let request = RecordAgreementRequest {
+        targets: vec!["tos_v3", "privacy_v2"],
+        accepted_at: None,
+        source: Some("api"),
+    };
+    let result = client.record_applicant_agreement(applicant_id, request).await;
+
+    mock.assert_async().await;
+    assert!(result.is_ok());
+    let agreement = result.unwrap();
+    assert_eq!(agreement.targets, vec!["tos_v3", "privacy_v2"]);
+}
+
+#[tokio::test]
+async fn test_get_required_docs_status_detailed() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("some_applicant_id");
+    let response_body = json!({
+        "IDENTITY": {
+            "reviewResult": {
+                "reviewAnswer": "RED",
+                "rejectLabels": ["BAD_QUALITY"],
+                "reviewRejectType": "RETRY"
+            },
+            "country": "USA",
+            "idDocType": "ID_CARD",
+            "imageIds": [101, 102],
+            "imageReviewResults": {
+                "101": { "reviewAnswer": "GREEN" },
+                "102": { "reviewAnswer": "RED", "rejectLabels": ["BAD_QUALITY"] }
+            },
+            "forbidden": false
+        },
+        "SELFIE": null
+    });
+
+    let mock = server.mock("GET", &format!("/resources/applicants/{}/requiredIdDocsStatus", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(response_body.to_string())
+        .create_async().await;
+
+    let result = client.get_required_docs_status_detailed(applicant_id).await;
+
+    mock.assert_async().await;
+    assert!(result.is_ok(), "{:?}", result.err());
+    let steps = result.unwrap();
+    let identity = steps["IDENTITY"].as_ref().unwrap();
+    assert!(steps["SELFIE"].is_none());
+    assert_eq!(identity.rejected_image_ids(), vec![ImageId::from("102")]);
+    let back_side = identity.image_review_result(&ImageId::from("102")).unwrap();
+    assert_eq!(back_side.reject_labels.as_deref(), Some(&["BAD_QUALITY".to_string()][..]));
+}
+
+#[tokio::test]
+async fn test_deactivate_applicant_data() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("some_applicant_id");
+    let response_body = json!([
+        { "imageId": "1", "inspectionId": "insp", "idDocType": "PASSPORT", "addedAt": "2023-10-26T10:00:00Z" },
+        { "imageId": "2", "inspectionId": "insp", "idDocType": "UTILITY_BILL", "addedAt": "2023-10-26T10:00:00Z" },
+        { "imageId": "3", "inspectionId": "insp", "idDocType": "BANK_STATEMENT", "addedAt": "2023-10-26T10:00:00Z" }
+    ]);
+
+    let mock_info = server.mock("GET", &format!("/resources/applicants/{}/info/images", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(response_body.to_string())
+        .create_async().await;
+    let mock_delete_2 = server.mock("DELETE", &format!("/resources/applicants/{}/images/2", applicant_id)[..])
+        .with_status(200)
+        .create_async().await;
+    let mock_delete_3 = server.mock("DELETE", &format!("/resources/applicants/{}/images/3", applicant_id)[..])
+        .with_status(500)
+        .create_async().await;
+
+    let outcomes = client.deactivate_applicant_data(applicant_id, DataCategory::ProofOfAddress).await.unwrap();
+
+    mock_info.assert_async().await;
+    mock_delete_2.assert_async().await;
+    mock_delete_3.assert_async().await;
+    let image_ids: Vec<_> = outcomes.iter().map(|o| o.image_id.as_str()).collect();
+    assert_eq!(image_ids, ["2", "3"]);
+    assert_eq!(outcomes[1].id_doc_type, "BANK_STATEMENT");
+    assert!(outcomes[0].result.is_ok());
+    assert!(outcomes[1].result.is_err());
+}
+
+#[tokio::test]
+async fn test_get_all_applicant_moderation_states() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("some_applicant_id");
+    let state = |created_at: &str| json!({
+        "createdAt": created_at,
+        "clientId": "some_client_id",
+        "inspectionId": "some_inspection_id",
+        "applicantId": applicant_id,
+        "levelName": "basic-kyc"
+    });
+    let first_page: Vec<_> = (0..100)
+        .map(|i| state(&format!("2023-10-{:02} 10:00:00", 28 - i % 28)))
+        .collect();
+    let second_page = vec![state("2023-09-01 10:00:00")];
+
+    let mock_first = server.mock("GET", &format!("/resources/moderationStates/-;applicantId={}?offset=0&limit=100", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!(first_page).to_string())
+        .create_async().await;
+    let mock_second = server.mock("GET", &format!("/resources/moderationStates/-;applicantId={}?offset=100&limit=100", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!(second_page).to_string())
+        .create_async().await;
+
+    let result = client.get_all_applicant_moderation_states(applicant_id).await;
+
+    mock_first.assert_async().await;
+    mock_second.assert_async().await;
+    let states = result.unwrap();
+    assert_eq!(states.len(), 101);
+    assert_eq!(states[0].created_at, "2023-09-01 10:00:00");
+    assert!(states.windows(2).all(|w| w[0].created_at <= w[1].created_at));
+}
+
+#[tokio::test]
+async fn test_cloned_client_across_tasks() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let mock = server.mock("GET", "/resources/status/api")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"status": "ok"}"#)
+        .expect(2)
+        .create_async().await;
+
+    let handles: Vec<_> = (0..2)
+        .map(|_| {
+            let client = client.clone();
+            tokio::spawn(async move { client.get_api_health_status().await })
+        })
+        .collect();
+    for handle in handles {
+        assert!(handle.await.unwrap().is_ok());
+    }
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_request_hooks() {
+    use reqwest::header::{HeaderName, HeaderValue};
+    use std::sync::{Arc, Mutex};
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let recorder = seen.clone();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url)
+        .with_hook(hooks::before_send(|request| {
+            request.insert_header(HeaderName::from_static("x-audit-id"), HeaderValue::from_static("audit-1"));
+            request.insert_header(HeaderName::from_static("x-app-token"), HeaderValue::from_static("hijacked"));
+        }))
+        .with_hook(hooks::after_receive(move |request, response| {
+            recorder.lock().unwrap().push((
+                request.headers()["x-app-token"].to_str().unwrap().to_string(),
+                request.headers()["x-audit-id"].to_str().unwrap().to_string(),
+                response.status().as_u16(),
+            ));
+        }));
+
+    let mock = server.mock("GET", "/resources/status/api")
+        .match_header("x-audit-id", "audit-1")
+        .match_header("x-app-token", "app_token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"status": "ok"}"#)
+        .create_async().await;
+
+    let result = client.get_api_health_status().await;
+
+    mock.assert_async().await;
+    assert!(result.is_ok());
+    let seen = seen.lock().unwrap();
+    assert_eq!(*seen, vec![("[REDACTED]".to_string(), "audit-1".to_string(), 200)]);
+}
+
+#[tokio::test]
+async fn test_request_options() {
+    use reqwest::header::{HeaderName, HeaderValue};
+    use sumsub_api::options::RequestOptions;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let options = RequestOptions::new()
+        .header(HeaderName::from_static("x-impersonate-client"), HeaderValue::from_static("client-id"))
+        .query("source Key", "eu+1");
+
+    let mock = server.mock("POST", "/resources/applicants/some_applicant_id/moveToLevel?levelName=new-level&source%20Key=eu%2B1")
+        .match_header("x-impersonate-client", "client-id")
+        .with_status(200)
+        .create_async().await;
+
+    let result = client
+        .with_options(options)
+        .move_applicant_to_level(&ApplicantId::from("some_applicant_id"), "new-level")
+        .await;
+
+    mock.assert_async().await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_create_sandbox_applicant() {
+    use sumsub_api::sandbox::SandboxOutcome;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("some_applicant_id");
+    let applicant = |review_status: &str| json!({
+        "id": applicant_id,
+        "createdAt": "2023-10-26T10:00:00Z",
+        "clientId": "some_client_id",
+        "inspectionId": "some_inspection_id",
+        "externalUserId": "some_external_id",
+        "review": { "reviewStatus": review_status },
+        "type": "individual"
+    });
+
+    let mock_create = server.mock("POST", "/resources/applicants?levelName=basic-kyc")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(applicant("init").to_string())
+        .create_async().await;
+    let mock_docs = server.mock("POST", &format!("/resources/applicants/{}/docsets/-", applicant_id)[..])
+        .with_status(201)
+        .expect(2)
+        .create_async().await;
+    let mock_pending = server.mock("POST", &format!("/resources/applicants/{}/status/pending", applicant_id)[..])
+        .with_status(200)
+        .create_async().await;
+    let mock_review = server.mock("POST", &format!("/resources/applicants/{}/sandbox/status/testCompleted", applicant_id)[..])
+        .match_body(mockito::Matcher::Json(json!({
+            "reviewAnswer": "RED",
+            "rejectLabels": ["FORGERY"],
+            "reviewRejectType": "FINAL"
+        })))
+        .with_status(200)
+        .create_async().await;
+    let mock_get = server.mock("GET", &format!("/resources/applicants/{}/one", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(applicant("completed").to_string())
+        .create_async().await;
+
+    let request = CreateApplicantRequest {
+        external_user_id: "some_external_id".into(),
+        ..Default::default()
+    };
+    let outcome = SandboxOutcome::Rejected {
+        reject_labels: vec!["FORGERY".to_string()],
+        final_reject: true,
+    };
+    let result = client.create_sandbox_applicant(request, "basic-kyc", outcome).await;
+
+    mock_create.assert_async().await;
+    mock_docs.assert_async().await;
+    mock_pending.assert_async().await;
+    mock_review.assert_async().await;
+    mock_get.assert_async().await;
+    assert_eq!(result.unwrap().review.review_status, "completed");
+}
+
+#[test]
+fn test_webhook_samples_parse() {
+    webhooks::assert_parses_all();
+
+    let event_types: Vec<_> = webhooks::samples().map(|sample| sample.event_type).collect();
+    assert_eq!(event_types, vec!["applicantReviewed", "applicantPending"]);
+
+    for sample in webhooks::samples() {
+        let value: serde_json::Value = serde_json::from_str(sample.json).unwrap();
+        assert_eq!(value["type"], sample.event_type);
+    }
+}
+
+#[tokio::test]
+async fn test_export_applicant_bundle() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("some_applicant_id");
+    let dest_dir = std::env::temp_dir().join(format!("sumsub-export-{}", Uuid::new_v4()));
+    let applicant_body = json!({ "id": applicant_id });
+    let images_body = json!([
+        { "imageId": "11", "inspectionId": "insp", "idDocType": "PASSPORT", "addedAt": "2023-10-26T10:00:00Z" },
+        { "imageId": "12", "inspectionId": "insp", "idDocType": "../../SELFIE", "addedAt": "2023-10-26T10:00:00Z" }
+    ]);
+
+    let mock_applicant = server.mock("GET", &format!("/resources/applicants/{}/one", applicant_id)[..])
+        .with_status(200)
+        .with_body(applicant_body.to_string())
+        .create_async().await;
+    let mock_images_info = server.mock("GET", &format!("/resources/applicants/{}/info/images", applicant_id)[..])
+        .with_status(200)
+        .with_body(images_body.to_string())
+        .create_async().await;
+    let mock_image = server.mock("GET", &format!("/resources/applicants/{}/images/insp/11", applicant_id)[..])
+        .with_status(200)
+        .with_body([0xFF, 0xD8, 0xFF, 0xE0])
+        .create_async().await;
+    let mock_selfie = server.mock("GET", &format!("/resources/applicants/{}/images/insp/12", applicant_id)[..])
+        .with_status(200)
+        .with_body(b"\x89PNG")
+        .create_async().await;
+    let mock_video = server.mock("GET", &format!("/resources/applicants/{}/info/facemap/video", applicant_id)[..])
+        .with_status(404)
+        .create_async().await;
+    let mock_report = server.mock("GET", &format!("/resources/applicants/{}/requiredIdDocsStatus.pdf", applicant_id)[..])
+        .with_status(200)
+        .with_body("%PDF-1.4")
+        .create_async().await;
+
+    let result = client.export_applicant_bundle(applicant_id, &dest_dir).await;
+
+    mock_applicant.assert_async().await;
+    mock_images_info.assert_async().await;
+    mock_image.assert_async().await;
+    mock_selfie.assert_async().await;
+    mock_video.assert_async().await;
+    mock_report.assert_async().await;
+
+    let mut bundle = result.unwrap();
+    assert_eq!(std::fs::read_to_string(&bundle.applicant_json).unwrap(), applicant_body.to_string());
+    bundle.images.sort();
+    assert_eq!(
+        bundle.images,
+        vec![dest_dir.join("images").join("PASSPORT_11.jpg"), dest_dir.join("images").join("______SELFIE_12.png")]
+    );
+    assert!(bundle.liveness_video.is_none());
+    assert_eq!(std::fs::read(&bundle.report).unwrap(), b"%PDF-1.4");
+
+    std::fs::remove_dir_all(&dest_dir).unwrap();
+}
+
+#[cfg(feature = "zip")]
+#[test]
+fn test_archive_builder() {
+    use std::io::Read;
+    use sumsub_api::applicants::IngestApplicant;
+    use sumsub_api::archive::{ArchiveBuilder, ArchiveDocument};
+
+    let applicant = IngestApplicant {
+        external_user_id: "user-123".into(),
+        email: Some("user@example.com".to_string()),
+        phone: None,
+        info: None,
+    };
+    let document = ArchiveDocument {
+        id_doc_type: "PASSPORT".to_string(),
+        country: "GBR".to_string(),
+        id_doc_sub_type: None,
+        file_name: "passport.jpg".to_string(),
+    };
+
+    let bytes = ArchiveBuilder::new(applicant)
+        .document(document.clone(), b"passport".to_vec())
+        .build()
+        .unwrap();
+
+    let mut zip = zip::ZipArchive::new(std::io::Cursor::new(bytes)).unwrap();
+    let mut manifest = String::new();
+    zip.by_name("applicant.json").unwrap().read_to_string(&mut manifest).unwrap();
+    let manifest: serde_json::Value = serde_json::from_str(&manifest).unwrap();
+    assert_eq!(manifest["applicant"]["externalUserId"], "user-123");
+    assert_eq!(manifest["documents"][0]["idDocType"], "PASSPORT");
+    assert_eq!(manifest["documents"][0]["fileName"], "passport.jpg");
+
+    let mut content = Vec::new();
+    zip.by_name("documents/passport.jpg").unwrap().read_to_end(&mut content).unwrap();
+    assert_eq!(content, b"passport");
+
+    let duplicate = ArchiveBuilder::new(IngestApplicant {
+        external_user_id: "user-456".into(),
+        email: None,
+        phone: None,
+        info: None,
+    })
+    .document(document.clone(), vec![1])
+    .document(document.clone(), vec![2])
+    .build();
+    assert!(matches!(duplicate, Err(SumsubError::Archive(_))));
+
+    for file_name in ["../passport.jpg", "/etc/passport.jpg", "scans/passport.jpg", "scans\\passport.jpg", "..", ""] {
+        let unsafe_name = ArchiveBuilder::new(IngestApplicant {
+            external_user_id: "user-789".into(),
+            email: None,
+            phone: None,
+            info: None,
+        })
+        .document(ArchiveDocument { file_name: file_name.to_string(), ..document.clone() }, vec![1])
+        .build();
+        assert!(matches!(unsafe_name, Err(SumsubError::Archive(_))), "{file_name:?} was accepted");
+    }
+}
+
+#[tokio::test]
+async fn test_ingest_completed_applicants() {
+    use sumsub_api::applicants::{BulkIngestOptions, IngestApplicant, IngestCompletedRequest, IngestReview};
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let mock_ok = server.mock("POST", "/resources/applicants/-/ingestCompleted")
+        .match_body(mockito::Matcher::PartialJson(json!({ "applicant": { "externalUserId": "user-1" } })))
+        .with_status(200)
+        .expect(1)
+        .create_async().await;
+    let mock_invalid = server.mock("POST", "/resources/applicants/-/ingestCompleted")
+        .match_body(mockito::Matcher::PartialJson(json!({ "applicant": { "externalUserId": "user-2" } })))
+        .with_status(400)
+        .with_body(r#"{"description": "Invalid level"}"#)
+        .expect(1)
+        .create_async().await;
+    let mock_unavailable = server.mock("POST", "/resources/applicants/-/ingestCompleted")
+        .match_body(mockito::Matcher::PartialJson(json!({ "applicant": { "externalUserId": "user-3" } })))
+        .with_status(503)
+        .expect(2)
+        .create_async().await;
+
+    let requests = ["user-1", "user-2", "user-3"].into_iter().map(|id| IngestCompletedRequest {
+        applicant: IngestApplicant {
+            external_user_id: id.into(),
+            email: None,
+            phone: None,
+            info: None,
+        },
+        review: IngestReview {
+            level_name: "basic-kyc-level".to_string(),
+            review_answer: "GREEN".to_string(),
+            reject_labels: None,
+            moderation_comment: None,
+            client_comment: None,
+        },
+        doc_sets: None,
+    });
+    assert_eq!(BulkIngestOptions::default().max_retries, 0);
+    let options = BulkIngestOptions {
+        chunk_size: 2,
+        max_retries: 1,
+        retry_delay: std::time::Duration::from_millis(1),
+    };
+
+    let outcomes = client.ingest_completed_applicants(requests, options).await;
+
+    mock_ok.assert_async().await;
+    mock_invalid.assert_async().await;
+    mock_unavailable.assert_async().await;
+
+    let ids: Vec<_> = outcomes.iter().map(|o| o.external_user_id.as_str()).collect();
+    assert_eq!(ids, ["user-1", "user-2", "user-3"]);
+    assert!(outcomes[0].result.is_ok());
+    assert_eq!(outcomes[0].attempts, 1);
+    assert!(matches!(outcomes[1].result, Err(SumsubError::ApiError { status: 400, .. })));
+    assert_eq!(outcomes[1].attempts, 1);
+    assert!(matches!(outcomes[2].result, Err(SumsubError::ApiError { status: 503, .. })));
+    assert_eq!(outcomes[2].attempts, 2);
+}
+
+#[tokio::test]
+async fn test_get_ocr_fields_from_company_documents() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("company-applicant");
+    let mock = server.mock("GET", &format!("/resources/applicants/{}/info/companyInfo/ocr", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "registrationNumber": "12345678",
+            "companyName": "Acme Ltd",
+            "address": "1 High Street, London",
+            "incorporatedOn": "2010-05-01",
+            "confidence": { "registrationNumber": 0.98, "companyName": 0.91 },
+            "taxNumber": "GB123"
+        }).to_string())
+        .create_async().await;
+
+    let result = client.get_ocr_fields_from_company_documents(applicant_id).await;
+
+    mock.assert_async().await;
+    let ocr = result.unwrap();
+    assert_eq!(ocr.registration_number.as_deref(), Some("12345678"));
+    assert_eq!(ocr.legal_name.as_deref(), Some("Acme Ltd"));
+    assert_eq!(ocr.incorporation_date.as_deref(), Some("2010-05-01"));
+    assert_eq!(ocr.confidence_of("registrationNumber"), Some(0.98));
+    assert_eq!(ocr.confidence_of("address"), None);
+    assert_eq!(ocr.extras["taxNumber"], "GB123");
+}
+
+#[tokio::test]
+async fn test_get_id_doc_ocr_results() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("ocr-applicant");
+    let mock = server.mock("GET", &format!("/resources/applicants/{}/info/idDocs/ocr", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "items": [{
+                "imageId": "11",
+                "idDocType": "PASSPORT",
+                "country": "GBR",
+                "fields": {
+                    "firstName": "JOHN",
+                    "lastName": "Smyth",
+                    "dob": "1990-01-01",
+                    "number": "123456789",
+                    "personalNumber": "X1"
+                },
+                "mrz": { "line1": "P<GBRSMYTH<<JOHN", "line2": "1234567890GBR" }
+            }]
+        }).to_string())
+        .create_async().await;
+
+    let result = client.get_id_doc_ocr_results(applicant_id).await;
+
+    mock.assert_async().await;
+    let results = result.unwrap();
+    assert_eq!(results.len(), 1);
+    let fields = &results[0].fields;
+    assert_eq!(fields.number.as_deref(), Some("123456789"));
+    assert_eq!(fields.extras["personalNumber"], "X1");
+    assert_eq!(results[0].mrz.as_ref().unwrap().line1.as_deref(), Some("P<GBRSMYTH<<JOHN"));
+
+    let provided = FixedInfo {
+        first_name: Some("John".to_string()),
+        last_name: Some("Smith".to_string()),
+        dob: Some("1990-01-01".to_string()),
+        ..Default::default()
+    };
+    assert_eq!(fields.mismatches(&provided), vec!["lastName"]);
+}
+
+#[tokio::test]
+async fn test_get_latest_face_match_and_liveness_results() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("face-applicant");
+    let mock_face_match = server.mock("GET", "/resources/checks/latest")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("type".into(), "FACE_MATCH".into()),
+            mockito::Matcher::UrlEncoded("applicantId".into(), applicant_id.to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "applicantId": applicant_id,
+            "answer": "GREEN",
+            "similarity": 0.93,
+            "threshold": 0.8
+        }).to_string())
+        .create_async().await;
+    let mock_liveness = server.mock("GET", "/resources/checks/latest")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("type".into(), "LIVENESS".into()),
+            mockito::Matcher::UrlEncoded("applicantId".into(), applicant_id.to_string()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "applicantId": applicant_id,
+            "answer": "RED",
+            "score": 0.12,
+            "rejectLabels": ["SELFIE_MISMATCH"],
+            "spoofAttempts": ["SCREEN_REPLAY"]
+        }).to_string())
+        .create_async().await;
+
+    let face_match = client.get_latest_face_match_result(applicant_id).await.unwrap();
+    let liveness = client.get_latest_liveness_result(applicant_id).await.unwrap();
+
+    mock_face_match.assert_async().await;
+    mock_liveness.assert_async().await;
+    assert!(face_match.is_match());
+    assert_eq!(face_match.similarity, 0.93);
+    assert!(!liveness.is_live());
+    assert_eq!(liveness.spoof_attempts, vec!["SCREEN_REPLAY"]);
+}
+
+#[tokio::test]
+async fn test_image_transform() {
+    use sumsub_api::applicants::{CropArea, ImageTransform};
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("transform-applicant");
+    let transform = ImageTransform {
+        rotation: Some(90),
+        crop: Some(CropArea { x: 10, y: 20, width: 300, height: 200 }),
+    };
+    let mock_set = server.mock("PATCH", &format!("/resources/applicants/{}/images/11/transform", applicant_id)[..])
+        .match_body(mockito::Matcher::Json(json!({
+            "rotation": 90,
+            "crop": { "x": 10, "y": 20, "width": 300, "height": 200 }
+        })))
+        .with_status(200)
+        .create_async().await;
+    let mock_info = server.mock("GET", &format!("/resources/applicants/{}/info/images", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([
+            {
+                "imageId": "11",
+                "inspectionId": "insp",
+                "idDocType": "PASSPORT",
+                "addedAt": "2024-01-01 00:00:00",
+                "transform": { "rotation": 90, "crop": { "x": 10, "y": 20, "width": 300, "height": 200 } }
+            },
+            { "imageId": "12", "inspectionId": "insp", "idDocType": "SELFIE", "addedAt": "2024-01-01 00:00:00" }
+        ]).to_string())
+        .create_async().await;
+
+    client.set_image_transform(&ApplicantId::from(applicant_id), &ImageId::from("11"), &transform).await.unwrap();
+    let images = client.get_document_images_info(applicant_id).await.unwrap();
+
+    mock_set.assert_async().await;
+    mock_info.assert_async().await;
+    assert_eq!(images[0].transform.as_ref(), Some(&transform));
+    assert!(images[1].transform.is_none());
+}
+
+#[tokio::test]
+async fn test_deactivate_and_rerequest_doc_set() {
+    use sumsub_api::applicants::IdDocSetType;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("doc_set_applicant");
+    let mock_info = server.mock("GET", &format!("/resources/applicants/{}/info/images", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([
+            { "imageId": "1", "inspectionId": "insp", "idDocType": "PASSPORT", "addedAt": "2023-10-26T10:00:00Z" },
+            { "imageId": "2", "inspectionId": "insp", "idDocType": "UTILITY_BILL", "addedAt": "2023-10-26T10:00:00Z" },
+            { "imageId": "3", "inspectionId": "insp", "idDocType": "OTHER", "addedAt": "2023-10-26T10:00:00Z" }
+        ]).to_string())
+        .expect(2)
+        .create_async().await;
+    // The custom step's document type differs from its idDocSetType.
+    let mock_status = server.mock("GET", &format!("/resources/applicants/{}/requiredIdDocsStatus", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "IDENTITY": { "idDocType": "PASSPORT", "imageIds": [1] },
+            "PROOF_OF_RESIDENCE": { "idDocType": "UTILITY_BILL", "imageIds": [2] },
+            "SOURCE_OF_FUNDS": { "idDocType": "OTHER", "imageIds": [3] },
+            "SELFIE": null
+        }).to_string())
+        .expect(2)
+        .create_async().await;
+    let mock_delete_2 = server.mock("DELETE", &format!("/resources/applicants/{}/images/2", applicant_id)[..])
+        .with_status(200)
+        .create_async().await;
+    let mock_delete_3 = server.mock("DELETE", &format!("/resources/applicants/{}/images/3", applicant_id)[..])
+        .with_status(200)
+        .create_async().await;
+    let mock_reset = server.mock("POST", &format!("/resources/applicants/{}/resetStep/PROOF_OF_RESIDENCE", applicant_id)[..])
+        .with_status(200)
+        .create_async().await;
+
+    let deactivated = client.deactivate_doc_set(applicant_id, &IdDocSetType::ProofOfResidence).await.unwrap();
+    client.rerequest_doc_set(applicant_id, &IdDocSetType::ProofOfResidence).await.unwrap();
+    let custom = IdDocSetType::Custom("SOURCE_OF_FUNDS".to_string());
+    let deactivated_custom = client.deactivate_doc_set(applicant_id, &custom).await.unwrap();
+
+    mock_info.assert_async().await;
+    mock_status.assert_async().await;
+    mock_delete_2.assert_async().await;
+    mock_delete_3.assert_async().await;
+    mock_reset.assert_async().await;
+    assert_eq!(deactivated.len(), 1);
+    assert_eq!(deactivated[0].image_id.as_str(), "2");
+    assert!(deactivated[0].result.is_ok());
+    assert_eq!(deactivated_custom.len(), 1);
+    assert_eq!(deactivated_custom[0].image_id.as_str(), "3");
+    assert_eq!(deactivated_custom[0].id_doc_type, "OTHER");
+}
+
+#[tokio::test]
+async fn test_create_applicant_with_source_tracking() {
+    use sumsub_api::models::ApplicantMetadata;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let metadata = vec![ApplicantMetadata { key: "integration".to_string(), value: "partner-x".to_string() }];
+    let request = CreateApplicantRequest {
+        external_user_id: "source_user".into(),
+        source_key: Some("paid-campaign".to_string()),
+        metadata: metadata.clone(),
+        ..Default::default()
+    };
+    let mock = server.mock("POST", "/resources/applicants?levelName=basic-kyc")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "sourceKey": "paid-campaign",
+            "metadata": [{ "key": "integration", "value": "partner-x" }]
+        })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "id": "source_applicant",
+            "createdAt": "2023-10-26T10:00:00Z",
+            "clientId": "some_client_id",
+            "inspectionId": "some_inspection_id",
+            "externalUserId": "source_user",
+            "review": { "reviewStatus": "init" },
+            "type": "individual",
+            "applicantPlatform": "iOS",
+            "sourceKey": "paid-campaign",
+            "metadata": [{ "key": "integration", "value": "partner-x" }]
+        }).to_string())
+        .create_async().await;
+
+    let applicant = client.create_applicant(request, "basic-kyc").await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(applicant.applicant_platform, Some(ApplicantPlatform::MobileSdk));
+    assert_eq!(applicant.source_key.as_deref(), Some("paid-campaign"));
+    assert_eq!(applicant.metadata, metadata);
+}
+
+#[cfg(feature = "flow")]
+#[tokio::test]
+async fn test_kyc_flow() {
+    use sumsub_api::flow::{KycFlow, VerificationState};
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("5cb56e8e0a975a35f333cb83");
+    let applicant_body = json!({
+        "id": applicant_id,
+        "createdAt": "2023-10-26T10:00:00Z",
+        "clientId": "some_client_id",
+        "inspectionId": "some_inspection_id",
+        "externalUserId": "flow_user",
+        "review": { "reviewStatus": "init" },
+        "type": "individual"
+    });
+    let mock_create = server.mock("POST", "/resources/applicants?levelName=basic-kyc-level")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(applicant_body.to_string())
+        .create_async().await;
+    let mock_token = server.mock("POST", &format!("/resources/applicants/{}/accessTokens?levelName=basic-kyc-level", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"token": "websdk-token"}"#)
+        .create_async().await;
+    let mock_status = server.mock("GET", &format!("/resources/applicants/{}/status", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"createDate": "2023-10-26T10:00:00Z", "reviewStatus": "init"}"#)
+        .create_async().await;
+    let mock_get = server.mock("GET", &format!("/resources/applicants/{}/one", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(applicant_body.to_string())
+        .create_async().await;
+
+    let request = CreateApplicantRequest {
+        external_user_id: "flow_user".into(),
+        ..Default::default()
+    };
+    let mut flow = KycFlow::start(&client, request, "basic-kyc-level").await.unwrap();
+
+    assert_eq!(flow.applicant_id(), applicant_id);
+    assert_eq!(flow.access_token(), "websdk-token");
+    assert_eq!(*flow.state(), VerificationState::AwaitingApplicant);
+
+    let sample = webhooks::samples().find(|s| s.event_type == "applicantReviewed").unwrap();
+    let other: webhooks::WebhookPayload =
+        serde_json::from_str(&sample.json.replace(applicant_id.as_str(), "another_applicant")).unwrap();
+    assert!(!flow.apply_webhook(&other));
+
+    let reviewed: webhooks::WebhookPayload = serde_json::from_str(sample.json).unwrap();
+    assert!(flow.apply_webhook(&reviewed));
+    assert_eq!(*flow.state(), VerificationState::Rejected {
+        reject_labels: vec!["UNSATISFACTORY_PHOTOS".to_string(), "SCREENSHOTS".to_string()],
+        can_retry: true,
+    });
+    assert!(flow.state().is_decided());
+
+    let applicant = flow.results().await.unwrap();
+    assert_eq!(applicant.id, applicant_id);
+
+    mock_create.assert_async().await;
+    mock_token.assert_async().await;
+    mock_status.assert_async().await;
+    mock_get.assert_async().await;
+}
+
+#[cfg(feature = "flow")]
+#[tokio::test]
+async fn test_kyb_flow() {
+    use sumsub_api::flow::{KybFlow, VerificationState};
+    use sumsub_api::kyb::BeneficiaryType;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant = |id: &str, applicant_type: &str| json!({
+        "id": id,
+        "createdAt": "2023-10-26T10:00:00Z",
+        "clientId": "some_client_id",
+        "inspectionId": "some_inspection_id",
+        "externalUserId": id,
+        "review": { "reviewStatus": "init" },
+        "type": applicant_type
+    }).to_string();
+    let mock_company = server.mock("POST", "/resources/applicants?levelName=kyb-level")
+        .match_body(mockito::Matcher::PartialJson(json!({ "type": "company" })))
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(applicant("company_id", "company"))
+        .create_async().await;
+    let mock_ubo = server.mock("POST", "/resources/applicants?levelName=ubo-level")
+        .with_status(201)
+        .with_header("content-type", "application/json")
+        .with_body(applicant("ubo_id", "individual"))
+        .create_async().await;
+    let mock_link = server.mock("POST", "/resources/applicants/company_id/fixedInfo/companyInfo/beneficiaries")
+        .match_body(mockito::Matcher::Json(json!({ "applicantId": "ubo_id", "types": ["ubo"], "shareSize": 60.0 })))
+        .with_status(200)
+        .create_async().await;
+    let mock_company_status = server.mock("GET", "/resources/applicants/company_id/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"createDate": "2023-10-26", "reviewStatus": "completed", "reviewResult": {"reviewAnswer": "GREEN"}}"#)
+        .create_async().await;
+    let mock_ubo_status = server.mock("GET", "/resources/applicants/ubo_id/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"createDate": "2023-10-26", "reviewStatus": "pending"}"#)
+        .create_async().await;
+
+    let mut flow = KybFlow::start(&client, CreateApplicantRequest {
+        external_user_id: "company_id".into(),
+        ..Default::default()
+    }, "kyb-level").await.unwrap();
+    let ubo_id = flow.add_beneficiary(CreateApplicantRequest {
+        external_user_id: "ubo_id".into(),
+        ..Default::default()
+    }, "ubo-level", vec![BeneficiaryType::Ubo], Some(60.0)).await.unwrap();
+    let status = flow.status().await.unwrap();
+
+    mock_company.assert_async().await;
+    mock_ubo.assert_async().await;
+    mock_link.assert_async().await;
+    mock_company_status.assert_async().await;
+    mock_ubo_status.assert_async().await;
+    assert_eq!(flow.applicant_id(), "company_id");
+    assert_eq!(ubo_id, "ubo_id");
+    assert_eq!(flow.beneficiary_ids(), ["ubo_id"]);
+    assert_eq!(status.company, VerificationState::Approved);
+    assert_eq!(status.beneficiaries[0].state, VerificationState::Pending);
+    assert_eq!(status.overall(), VerificationState::Pending);
+}
+
+#[tokio::test]
+async fn test_get_beneficiary_readiness() {
+    use sumsub_api::applicants::VerificationState;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let mock_company = server.mock("GET", "/resources/applicants/company_id/one")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "id": "company_id",
+            "fixedInfo": {
+                "companyInfo": {
+                    "companyName": "Acme Ltd",
+                    "beneficiaries": [
+                        { "id": "link_1", "applicantId": "ubo_1", "types": ["ubo"], "shareSize": 60.0 },
+                        { "id": "link_2", "applicantId": "ubo_2", "types": ["director"] },
+                        { "id": "link_3", "applicantId": "ubo_3", "types": ["shareholder"], "shareSize": 40.0 },
+                        { "id": "link_4", "types": ["representative"] },
+                        { "id": "link_5", "applicantId": "ubo5", "types": ["director"] }
+                    ]
+                }
+            }
+        }).to_string())
+        .create_async().await;
+    let mut status = |id: &str, body: &str| {
+        server.mock("GET", &format!("/resources/applicants/{}/status", id)[..])
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body)
+    };
+    let mock_1 = status("ubo_1", r#"{"createDate": "2023-10-26", "reviewStatus": "completed", "reviewResult": {"reviewAnswer": "GREEN"}}"#)
+        .create_async().await;
+    let mock_2 = status("ubo_2", r#"{"createDate": "2023-10-26", "reviewStatus": "pending"}"#)
+        .create_async().await;
+    let mock_3 = status("ubo_3", r#"{"createDate": "2023-10-26", "reviewStatus": "completed", "reviewResult": {"reviewAnswer": "RED", "rejectLabels": ["FORGERY"], "reviewRejectType": "FINAL"}}"#)
+        .create_async().await;
+    let mock_5 = server.mock("GET", "/resources/applicants/ubo5/status")
+        .with_status(500)
+        .create_async().await;
+
+    let report = client.get_beneficiary_readiness(&ApplicantId::from("company_id")).await.unwrap();
+
+    mock_company.assert_async().await;
+    mock_1.assert_async().await;
+    mock_2.assert_async().await;
+    mock_3.assert_async().await;
+    mock_5.assert_async().await;
+    assert!(!report.is_ready());
+    let ids = |it: &mut dyn Iterator<Item = &sumsub_api::kyb::BeneficiaryReadiness>| {
+        it.map(|b| b.beneficiary.id.clone()).collect::<Vec<_>>()
+    };
+    assert_eq!(ids(&mut report.approved()), ["link_1"]);
+    assert_eq!(ids(&mut report.pending()), ["link_2", "link_4"]);
+    assert_eq!(ids(&mut report.rejected()), ["link_3"]);
+    assert_eq!(ids(&mut report.failed()), ["link_5"]);
+    assert_eq!(report.beneficiaries[3].beneficiary.applicant_id, None);
+    assert_eq!(report.beneficiaries[3].state.as_ref().unwrap(), &VerificationState::AwaitingApplicant);
+    assert_eq!(report.beneficiaries[2].state.as_ref().unwrap(), &VerificationState::Rejected {
+        reject_labels: vec!["FORGERY".to_string()],
+        can_retry: false,
+    });
+}
+
+#[tokio::test]
+async fn test_link_beneficiary_validation() {
+    use sumsub_api::kyb::{BeneficiaryType, ExistingBeneficiary, LinkBeneficiaryRequest};
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let mock = server.mock("POST", "/resources/applicants/company_id/fixedInfo/companyInfo/beneficiaries")
+        .match_body(mockito::Matcher::Json(json!({
+            "applicantId": "ubo_id",
+            "types": ["ubo", "director"],
+            "shareSize": 30.0
+        })))
+        .with_status(200)
+        .expect(1)
+        .create_async().await;
+
+    let link = |types: Vec<BeneficiaryType>, share_size: Option<f64>| {
+        LinkBeneficiaryRequest::Existing(ExistingBeneficiary {
+            applicant_id: "ubo_id".into(),
+            types,
+            share_size,
+        })
+    };
+
+    client.link_beneficiary(&ApplicantId::from("company_id"), link(vec![BeneficiaryType::Ubo, BeneficiaryType::Director], Some(30.0))).await.unwrap();
+
+    let invalid = [
+        link(vec![], None),
+        link(vec![BeneficiaryType::Ubo, BeneficiaryType::Ubo], None),
+        link(vec![BeneficiaryType::Director], Some(10.0)),
+        link(vec![BeneficiaryType::Shareholder], Some(120.0)),
+        link(vec![BeneficiaryType::Unknown], None),
+    ];
+    for request in invalid {
+        let result = client.link_beneficiary(&ApplicantId::from("company_id"), request).await;
+        assert!(matches!(result, Err(SumsubError::Validation(_))));
+    }
+
+    mock.assert_async().await;
+
+    let linked: sumsub_api::kyb::LinkedBeneficiary = serde_json::from_value(json!({
+        "id": "link_id",
+        "applicantId": "ubo_id",
+        "types": ["ubo", "signatory"]
+    })).unwrap();
+    assert_eq!(linked.types, [BeneficiaryType::Ubo, BeneficiaryType::Unknown]);
+}
+
+#[tokio::test]
+async fn test_search_company_registry() {
+    use sumsub_api::kyb::CompanySearchQuery;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let mock = server.mock("GET", "/resources/kyb/companies/search")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("country".into(), "GBR".into()),
+            mockito::Matcher::UrlEncoded("name".into(), "Acme & Sons".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "items": [{
+                "companyName": "ACME & SONS LTD",
+                "companyNumber": "12345678",
+                "country": "GBR",
+                "status": "active",
+                "type": "Private limited company",
+                "incorporatedOn": "2010-05-01",
+                "legalAddressStructured": { "country": "GBR", "town": "London" }
+            }]
+        }).to_string())
+        .create_async().await;
+
+    let query = CompanySearchQuery {
+        name: Some("Acme & Sons".to_string()),
+        country: "GBR".to_string(),
+        ..Default::default()
+    };
+    let results = client.search_company_registry(&query).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].company_number, "12345678");
+    assert_eq!(results[0].legal_address_structured.as_ref().unwrap().town.as_deref(), Some("London"));
+}
+
+#[tokio::test]
+async fn test_screen_company_persons() {
+    use sha2::{Digest, Sha256};
+    use sumsub_api::kyb::PersonRole;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let mock_check = server.mock("GET", "/resources/checks/latest?type=COMPANY&applicantId=company_id")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "checks": [{
+                "answer": "GREEN",
+                "createdAt": "2023-10-26 10:00:00",
+                "companyCheckInfo": {
+                    "companyName": "Acme Ltd",
+                    "companyNumber": "12345678",
+                    "status": "active",
+                    "type": "ltd",
+                    "source": "registry",
+                    "sourceUrl": "https://example.com",
+                    "officers": [
+                        { "fullName": "Jane Mary Doe", "type": "director", "country": "GBR" },
+                        { "fullName": "Madonna", "type": "director" }
+                    ],
+                    "significantPersons": [
+                        { "fullName": "John Roe", "type": "ubo", "dob": "1970-01-01" },
+                        { "fullName": "jane  mary doe", "type": "ubo" }
+                    ]
+                }
+            }]
+        }).to_string())
+        .create_async().await;
+
+    let external_user_id = |name: &str, dob: &str| {
+        let digest = Sha256::digest(format!("{}\n{}", name, dob));
+        format!("company_id-person-{}", hex::encode(&digest[..8]))
+    };
+    let applicant = |id: &str, external_user_id: &str| json!({
+        "id": id,
+        "createdAt": "2023-10-26T10:00:00Z",
+        "clientId": "some_client_id",
+        "inspectionId": "some_inspection_id",
+        "externalUserId": external_user_id,
+        "review": { "reviewStatus": "init" },
+        "type": "individual"
+    }).to_string();
+
+    let mut mocks = Vec::new();
+    let persons = [
+        ("person0", "jane mary doe", "", json!({ "firstName": "Jane Mary", "lastName": "Doe", "country": "GBR" }), json!([])),
+        ("person1", "madonna", "", json!({ "firstName": "Madonna" }), json!([])),
+        ("person2", "john roe", "1970-01-01", json!({ "firstName": "John", "lastName": "Roe" }), json!([{
+            "id": "hit_1",
+            "hitIdInSource": "src_1",
+            "sourceName": "sanctions",
+            "createdAtMs": 1,
+            "review": { "status": "pending" },
+            "matchInfo": { "matchTypes": ["name"], "matchStrength": 0.95 },
+            "data": {}
+        }])),
+    ];
+    for (person_id, name, dob, fixed_info, hits) in persons {
+        let external_user_id = external_user_id(name, dob);
+        let create = server.mock("POST", "/resources/applicants?levelName=aml-level")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "externalUserId": external_user_id,
+                "fixedInfo": fixed_info
+            })));
+        if person_id == "person2" {
+            // The applicant exists from an earlier screening and is reused.
+            mocks.push(create
+                .with_status(409)
+                .with_body(json!({
+                    "description": format!("Applicant with external user id '{}' already exists: {}", external_user_id, person_id),
+                    "code": 409
+                }).to_string())
+                .create_async().await);
+            mocks.push(server.mock("GET", &format!("/resources/applicants/{}/one", person_id)[..])
+                .with_status(200)
+                .with_body(applicant(person_id, &external_user_id))
+                .create_async().await);
+        } else {
+            mocks.push(create
+                .with_status(201)
+                .with_body(applicant(person_id, &external_user_id))
+                .create_async().await);
+        }
+        mocks.push(server.mock("POST", &format!("/resources/applicants/{}/aml", person_id)[..])
+            .with_status(200)
+            .expect(1)
+            .create_async().await);
+        mocks.push(server.mock("GET", &format!("/resources/applicants/{}/aml", person_id)[..])
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({
+                "applicant": {
+                    "id": person_id,
+                    "inspectionId": "some_inspection_id",
+                    "createdAtMs": 1,
+                    "info": { "firstName": name, "lastName": "", "country": "GBR" },
+                    "hits": hits
+                }
+            }).to_string())
+            .expect(1)
+            .create_async().await);
+    }
+
+    let screenings = client.screen_company_persons(&ApplicantId::from("company_id"), "aml-level").await.unwrap();
+    assert_eq!(screenings.len(), 3);
+    assert_eq!(screenings[0].person.roles, [PersonRole::Officer, PersonRole::SignificantPerson]);
+    assert_eq!(screenings[2].applicant_id, "person2");
+
+    let report = client.get_person_screening_report(&screenings).await.unwrap();
+
+    mock_check.assert_async().await;
+    for mock in &mocks {
+        mock.assert_async().await;
+    }
+    assert_eq!(report.screenings.len(), 3);
+    assert_eq!(report.total_hits(), 1);
+    let flagged: Vec<_> = report.with_hits().map(|s| s.person.full_name.as_str()).collect();
+    assert_eq!(flagged, ["John Roe"]);
+}
+
+#[cfg(feature = "raw-api")]
+#[tokio::test]
+async fn test_add_verification_document_with_diagnostics() {
+    use sumsub_api::response::ApiWarning;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("some_applicant_id");
+    let mock = server.mock("POST", &format!("/resources/applicants/{}/docsets/-", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-correlation-id", "req-123")
+        .with_body(json!({
+            "idDocType": "PASSPORT",
+            "country": "USA",
+            "warnings": [
+                "Image is blurry",
+                { "code": "GLARE", "description": "Glare detected" }
+            ]
+        }).to_string())
+        .create_async().await;
+
+    let metadata = AddDocumentMetadata {
+        id_doc_type: "PASSPORT",
+        country: "USA",
+        first_name: None,
+        middle_name: None,
+        last_name: None,
+        dob: None,
+        place_of_birth: None,
+        issued_date: None,
+        valid_until: None,
+        number: None,
+        sub_type: None,
+        id_doc_sub_type: None,
+    };
+    let response = client
+        .add_verification_document_with_diagnostics(applicant_id, metadata, vec![1, 2, 3], "passport.jpg", "image/jpeg")
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.correlation_id.as_deref(), Some("req-123"));
+    assert_eq!(response.warnings, vec![
+        ApiWarning { code: None, message: "Image is blurry".to_string() },
+        ApiWarning { code: Some("GLARE".to_string()), message: "Glare detected".to_string() },
+    ]);
+    assert_eq!(response.data["idDocType"], "PASSPORT");
+    assert!(response.data.get("warnings").is_none());
+}
+
+#[tokio::test]
+async fn test_import_applicant_profile_from_archive_file_retries_once() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let path = std::env::temp_dir().join(format!("sumsub-archive-{}.zip", Uuid::new_v4()));
+    std::fs::write(&path, b"PK\x03\x04archive").unwrap();
+
+    let mock = server.mock("POST", "/resources/applicants/-/ingest")
+        .match_body(mockito::Matcher::Regex("PK\x03\x04archive".to_string()))
+        .with_status(503)
+        .expect(2)
+        .create_async().await;
+
+    let result = client.import_applicant_profile_from_archive_file(&path).await;
+
+    mock.assert_async().await;
+    assert!(matches!(result, Err(SumsubError::ApiError { status: 503, .. })));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[tokio::test]
+async fn test_get_all_applicant_notes() {
+    use sumsub_api::applicants::NoteFilter;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("notes_applicant");
+    let note = |n: u32| json!({
+        "id": format!("note_{}", n),
+        "createdAt": "2024-01-02 10:00:00",
+        "applicantId": applicant_id,
+        "agent": { "clientId": "client", "email": "reviewer@example.com" },
+        "note": "Checked",
+        "attachments": [{
+            "id": "att",
+            "fileName": "proof.pdf",
+            "createdAt": "2024-01-02 10:00:00",
+            "contentType": "application/pdf",
+            "size": 2048
+        }]
+    });
+    let query = |offset: &str| mockito::Matcher::AllOf(vec![
+        mockito::Matcher::UrlEncoded("offset".into(), offset.into()),
+        mockito::Matcher::UrlEncoded("limit".into(), "100".into()),
+        mockito::Matcher::UrlEncoded("createdAtFrom".into(), "2024-01-01 00:00:00".into()),
+        mockito::Matcher::UrlEncoded("agentEmail".into(), "reviewer@example.com".into()),
+    ]);
+    let mock_first = server.mock("GET", &format!("/resources/applicants/{}/notes", applicant_id)[..])
+        .match_query(query("0"))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::Value::Array((0..100).map(note).collect()).to_string())
+        .create_async().await;
+    let mock_second = server.mock("GET", &format!("/resources/applicants/{}/notes", applicant_id)[..])
+        .match_query(query("100"))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([note(100)]).to_string())
+        .create_async().await;
+
+    let filter = NoteFilter {
+        created_from: Some("2024-01-01 00:00:00".to_string()),
+        agent_email: Some("reviewer@example.com".to_string()),
+        ..Default::default()
+    };
+    let notes = client.get_all_applicant_notes(applicant_id, &filter).await.unwrap();
+
+    mock_first.assert_async().await;
+    mock_second.assert_async().await;
+    assert_eq!(notes.len(), 101);
+    assert_eq!(notes[0].attachments[0].content_type.as_deref(), Some("application/pdf"));
+    assert_eq!(notes[0].attachments[0].size, Some(2048));
+}
+
+#[tokio::test]
+async fn test_agent_identity() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let mock_members = server.mock("GET", "/resources/clients/-/members")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([
+            { "id": "agent_1", "email": "reviewer@example.com", "name": "Rita Reviewer", "role": "compliance_officer", "active": true }
+        ]).to_string())
+        .create_async().await;
+    let mock_status = server.mock("GET", "/resources/applicants/agent_applicant/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "createDate": "2024-01-01 00:00:00",
+            "reviewStatus": "completed",
+            "reviewResult": {
+                "reviewAnswer": "GREEN",
+                "reviewedBy": { "clientId": "client", "email": "reviewer@example.com", "id": "agent_1", "role": "compliance_officer" }
+            }
+        }).to_string())
+        .create_async().await;
+
+    let members = client.get_team_members().await.unwrap();
+    let status = client.get_applicant_status(&ApplicantId::from("agent_applicant")).await.unwrap();
+
+    mock_members.assert_async().await;
+    mock_status.assert_async().await;
+    let reviewer = status.review_result.unwrap().reviewed_by.unwrap();
+    assert_eq!(reviewer.id.as_deref(), Some(members[0].id.as_str()));
+    assert_eq!(reviewer.role.as_deref(), Some("compliance_officer"));
+    assert!(members[0].active);
+}
+
+#[test]
+fn test_dashboard_links() {
+    use sumsub_api::dashboard::DashboardLinks;
+
+    let production = DashboardLinks::production();
+    assert_eq!(production.applicant(&ApplicantId::from("abc")), "https://cockpit.sumsub.com/checkus#/applicant/abc/basicInfo");
+    assert_eq!(production.aml_case(&ApplicantId::from("abc")), "https://cockpit.sumsub.com/checkus#/applicant/abc/aml");
+    assert_eq!(production.transaction(&TxnId::from("txn 1")), "https://cockpit.sumsub.com/checkus#/kyt/txns/txn%201");
+
+    let custom = DashboardLinks::new("https://dashboard.internal/", true);
+    assert_eq!(custom.applicant(&ApplicantId::from("abc")), "https://dashboard.internal?sandbox=true#/applicant/abc/basicInfo");
+}
+
+#[tokio::test]
+async fn test_get_review_context() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let sample = webhooks::samples().find(|s| s.event_type == "applicantReviewed").unwrap();
+    let payload = match serde_json::from_str(sample.json).unwrap() {
+        webhooks::WebhookPayload::ApplicantReviewed(payload) => payload,
+        other => panic!("unexpected payload: {:?}", other),
+    };
+    let applicant_id = payload.applicant_id.clone();
+
+    let mock_applicant = server.mock("GET", &format!("/resources/applicants/{}/one", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "id": applicant_id,
+            "createdAt": "2020-02-21 13:00:00",
+            "clientId": "coolClientId",
+            "inspectionId": "5cb56e8e0a975a35f333cb84",
+            "externalUserId": "externalUserId",
+            "review": { "reviewStatus": "completed" },
+            "type": "individual"
+        }).to_string())
+        .create_async().await;
+    let mock_states = server.mock("GET", &format!("/resources/moderationStates/-;applicantId={}", applicant_id)[..])
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([{
+            "createdAt": "2020-02-21 13:23:19",
+            "clientId": "coolClientId",
+            "inspectionId": "5cb56e8e0a975a35f333cb84",
+            "applicantId": applicant_id,
+            "levelName": "basic-kyc-level"
+        }]).to_string())
+        .create_async().await;
+    let mock_steps = server.mock("GET", &format!("/resources/applicants/{}/requiredIdDocsStatus", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "SELFIE": null }).to_string())
+        .create_async().await;
+
+    let context = client.get_review_context(&payload).await.unwrap();
+
+    mock_applicant.assert_async().await;
+    mock_states.assert_async().await;
+    mock_steps.assert_async().await;
+    assert_eq!(context.applicant.id, applicant_id);
+    assert_eq!(context.moderation_states.len(), 1);
+    assert!(context.step_results["SELFIE"].is_none());
+}
+
+#[tokio::test]
+async fn test_locale() {
+    use sumsub_api::options::RequestOptions;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url)
+        .with_locale("de");
+
+    let mock_default = server.mock("GET", "/resources/status/api")
+        .match_header("accept-language", "de")
+        .with_status(200)
+        .with_body(r#"{"status": "ok"}"#)
+        .expect(1)
+        .create_async().await;
+    let mock_override = server.mock("GET", "/resources/status/api")
+        .match_header("accept-language", "pt-BR")
+        .with_status(200)
+        .with_body(r#"{"status": "ok"}"#)
+        .expect(1)
+        .create_async().await;
+
+    client.get_api_health_status().await.unwrap();
+    client
+        .with_options(RequestOptions::new().locale("pt-BR"))
+        .get_api_health_status()
+        .await
+        .unwrap();
+
+    mock_default.assert_async().await;
+    mock_override.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_or_get_applicant_conflict() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let existing_id = "5cb56e8e0a975a35f333cb83";
+    let mock_create = server.mock("POST", "/resources/applicants?levelName=basic-kyc")
+        .with_status(409)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "description": format!("Applicant with external user id 'user-1' already exists: {}", existing_id),
+            "code": 409,
+            "correlationId": "req-1"
+        }).to_string())
+        .expect(2)
+        .create_async().await;
+    let mock_get = server.mock("GET", &format!("/resources/applicants/{}/one", existing_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "id": existing_id,
+            "createdAt": "2023-10-26T10:00:00Z",
+            "clientId": "some_client_id",
+            "inspectionId": "some_inspection_id",
+            "externalUserId": "user-1",
+            "review": { "reviewStatus": "init" },
+            "type": "individual"
+        }).to_string())
+        .create_async().await;
+
+    let request = || CreateApplicantRequest {
+        external_user_id: "user-1".into(),
+        ..Default::default()
+    };
+    let error = client.create_applicant(request(), "basic-kyc").await.unwrap_err();
+    assert!(matches!(error, SumsubError::AlreadyExists { ref existing_applicant_id } if existing_applicant_id == existing_id));
+
+    let applicant = client.create_or_get_applicant(request(), "basic-kyc").await.unwrap();
+    assert_eq!(applicant.id, existing_id);
+
+    mock_create.assert_async().await;
+    mock_get.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_ensure_fixed_info() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("fixed_info_applicant");
+    let mock_get = server.mock("GET", &format!("/resources/applicants/{}/one", applicant_id)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "id": applicant_id,
+            "fixedInfo": { "firstName": "John", "lastName": "Doe", "dob": "1990-01-01" }
+        }).to_string())
+        .expect(2)
+        .create_async().await;
+    let mock_patch = server.mock("PATCH", &format!("/resources/applicants/{}/fixedInfo", applicant_id)[..])
+        .match_body(mockito::Matcher::Json(json!({ "lastName": "Smith" })))
+        .with_status(200)
+        .expect(1)
+        .create_async().await;
+
+    let unchanged = FixedInfo {
+        first_name: Some("John".to_string()),
+        dob: Some("1990-01-01".to_string()),
+        ..Default::default()
+    };
+    assert!(!client.ensure_fixed_info(applicant_id, unchanged).await.unwrap());
+
+    let changed = FixedInfo {
+        first_name: Some("John".to_string()),
+        last_name: Some("Smith".to_string()),
+        ..Default::default()
+    };
+    assert!(client.ensure_fixed_info(applicant_id, changed).await.unwrap());
+
+    mock_get.assert_async().await;
+    mock_patch.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_applicant_action_validates_payment_source() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant_id = &ApplicantId::from("payment_applicant");
+    let mock = server.mock("POST", &format!("/resources/applicantActions/-/forApplicant/{}?levelName=payment-level", applicant_id)[..])
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "paymentSource": { "fixedInfo": { "type": "bank", "accountIdentifier": "GB82 WEST 1234 5698 7654 32" } }
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "id": "action_id",
+            "createdAt": "2024-01-01 00:00:00",
+            "clientId": "client",
+            "externalActionId": "ext-action",
+            "applicantId": applicant_id,
+            "type": "paymentMethod",
+            "review": {
+                "reviewId": "review",
+                "attemptId": "attempt",
+                "attemptCnt": 0,
+                "levelName": "payment-level",
+                "createDate": "2024-01-01 00:00:00",
+                "reviewStatus": "init"
+            }
+        }).to_string())
+        .expect(1)
+        .create_async().await;
+
+    let request = |payment_type, account_identifier: &str| CreateApplicantActionRequest {
+        external_action_id: "ext-action".to_string(),
+        payment_source: Some(PaymentSource {
+            fixed_info: PaymentSourceFixedInfo {
+                payment_type,
+                account_identifier: account_identifier.to_string(),
+                ..Default::default()
+            },
+        }),
+        ..Default::default()
+    };
+    assert_eq!(PaymentSource::default().fixed_info.payment_type, PaymentSourceType::Card);
+
+    let action = client
+        .create_applicant_action(applicant_id, "payment-level", request(PaymentSourceType::Bank, "GB82 WEST 1234 5698 7654 32"))
+        .await
+        .unwrap();
+    assert_eq!(action.id, "action_id");
+
+    for (payment_type, account_identifier) in [
+        (PaymentSourceType::Bank, "GB83 WEST 1234 5698 7654 32"),
+        (PaymentSourceType::Card, "4111 **** **** 11"),
+        (PaymentSourceType::EWallet, "john doe"),
+        (PaymentSourceType::Crypto, "0x12"),
+        (PaymentSourceType::Bank, "é1234"),
+        (PaymentSourceType::Unknown, " "),
+    ] {
+        let result = client
+            .create_applicant_action(applicant_id, "payment-level", request(payment_type, account_identifier))
+            .await;
+        assert!(matches!(result, Err(SumsubError::Validation(_))), "{}", account_identifier);
+    }
+
+    let unknown: PaymentSourceType = serde_json::from_str("\"voucher\"").unwrap();
+    assert_eq!(unknown, PaymentSourceType::Unknown);
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_applicant_actions_stream() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let action = |n: u32| json!({
+        "id": format!("action_{}", n),
+        "createdAt": "2024-01-01 00:00:00",
+        "clientId": "client",
+        "externalActionId": format!("ext_{}", n),
+        "applicantId": "actions_applicant",
+        "type": "paymentMethod",
+        "review": {
+            "reviewId": "review",
+            "attemptId": "attempt",
+            "attemptCnt": 0,
+            "levelName": "payment-level",
+            "createDate": "2024-01-01 00:00:00",
+            "reviewStatus": "pending"
+        }
+    });
+    let base = "/resources/applicantActions/-;applicantId=actions_applicant";
+    let mock_first = server.mock("GET", &format!("{}?offset=0&limit=100&reviewStatus=pending&createdAtFrom=2024-01-01%2000%3A00%3A00", base)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "items": (0..100).map(action).collect::<Vec<_>>(), "totalItems": 130 }).to_string())
+        .expect(1)
+        .create_async().await;
+    let mock_second = server.mock("GET", &format!("{}?offset=100&limit=100&reviewStatus=pending&createdAtFrom=2024-01-01%2000%3A00%3A00", base)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "items": (100..130).map(action).collect::<Vec<_>>(), "totalItems": 130 }).to_string())
+        .expect(1)
+        .create_async().await;
+
+    let filter = ActionFilter {
+        review_status: Some("pending".to_string()),
+        created_from: Some("2024-01-01 00:00:00".to_string()),
+        ..Default::default()
+    };
+    let actions: Vec<_> = client
+        .applicant_actions_stream(&ApplicantId::from("actions_applicant"), &filter)
+        .try_collect()
+        .await
+        .unwrap();
+    assert_eq!(actions.len(), 130);
+    assert_eq!(actions[129].id, "action_129");
+
+    mock_first.assert_async().await;
+    mock_second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_action_by_external_id() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let mock = server.mock("GET", "/resources/applicantActions/-;externalActionId=payment%2F42/one")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "id": "action_id",
+            "createdAt": "2024-01-01 00:00:00",
+            "clientId": "client",
+            "externalActionId": "payment/42",
+            "applicantId": "applicant_id",
+            "type": "paymentMethod",
+            "review": {
+                "reviewId": "review",
+                "attemptId": "attempt",
+                "attemptCnt": 0,
+                "levelName": "payment-level",
+                "createDate": "2024-01-01 00:00:00",
+                "reviewStatus": "completed"
+            }
+        }).to_string())
+        .create_async().await;
+
+    let action = client.get_action_by_external_id("payment/42").await.unwrap();
+    assert_eq!(action.id, "action_id");
+    assert_eq!(action.external_action_id, "payment/42");
+
+    mock.assert_async().await;
+}
+
+#[test]
+fn test_review_result_helpers() {
+    let status = |review_status: &str, result: serde_json::Value| -> ApplicantStatus {
+        serde_json::from_value(json!({
+            "createDate": "2024-01-01 00:00:00",
+            "reviewStatus": review_status,
+            "reviewResult": result
+        }))
+        .unwrap()
+    };
+
+    let approved = status("completed", json!({ "reviewAnswer": "GREEN" }));
+    assert!(approved.is_approved() && !approved.is_final_reject() && !approved.retry_allowed());
+
+    let final_reject = status("completed", json!({ "reviewAnswer": "RED", "reviewRejectType": "FINAL" }));
+    assert!(!final_reject.is_approved() && final_reject.is_final_reject() && !final_reject.retry_allowed());
+
+    let retry = status("completed", json!({ "reviewAnswer": "RED", "reviewRejectType": "RETRY" }));
+    assert!(!retry.is_approved() && !retry.is_final_reject() && retry.retry_allowed());
+
+    let stale = status("pending", json!({ "reviewAnswer": "GREEN" }));
+    assert!(!stale.is_approved());
+
+    let webhook: webhooks::WebhookReviewResult = serde_json::from_value(json!({
+        "reviewAnswer": "RED",
+        "reviewRejectType": "RETRY"
+    }))
+    .unwrap();
+    assert!(webhook.retry_allowed() && !webhook.is_final_reject() && !webhook.is_approved());
+}
+
+#[tokio::test]
+async fn test_get_aml_report() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let mock_pdf = server.mock("GET", "/resources/applicants/aml_applicant/aml/report.pdf")
+        .with_status(200)
+        .with_header("content-type", "application/pdf")
+        .with_body("%PDF-1.7")
+        .create_async().await;
+    let mock_json = server.mock("GET", "/resources/applicants/aml_applicant/aml/report.json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"hits":[]}"#)
+        .create_async().await;
+
+    let pdf = client.get_aml_report(&ApplicantId::from("aml_applicant"), AmlReportFormat::Pdf).await.unwrap();
+    assert_eq!(pdf, b"%PDF-1.7");
+    let export = client.get_aml_report(&ApplicantId::from("aml_applicant"), AmlReportFormat::Json).await.unwrap();
+    assert_eq!(export, br#"{"hits":[]}"#);
+
+    mock_pdf.assert_async().await;
+    mock_json.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_send_platform_events() {
+    use std::sync::{Arc, Mutex};
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let record = |event_type: &'static str| {
+        let received = received.clone();
+        move |request: &mockito::Request| {
+            let body: serde_json::Value = serde_json::from_slice(request.body().unwrap()).unwrap();
+            let matches = body["eventType"] == event_type;
+            if matches {
+                received.lock().unwrap().push(body["correlationId"].as_str().unwrap().to_string());
+            }
+            matches
+        }
+    };
+    let mock_ok = server.mock("POST", "/resources/applicants/events_applicant/platformEvents")
+        .match_request(record("login"))
+        .with_status(201)
+        .expect(24)
+        .create_async().await;
+    let mock_failed = server.mock("POST", "/resources/applicants/events_applicant/platformEvents")
+        .match_request(record("logout"))
+        .with_status(400)
+        .with_body("bad event")
+        .expect(1)
+        .create_async().await;
+
+    let correlation_ids: Vec<String> = (0..25).map(|n| format!("session-{}", n)).collect();
+    let events: Vec<PlatformEvent> = correlation_ids
+        .iter()
+        .enumerate()
+        .map(|(n, correlation_id)| PlatformEvent {
+            event_type: if n == 12 { "logout" } else { "login" },
+            event_timestamp: "2024-01-01T00:00:00Z",
+            correlation_id,
+            device: DeviceFingerprint { fingerprint: "fingerprint" },
+        })
+        .collect();
+
+    let results = client.send_platform_events(&ApplicantId::from("events_applicant"), &events).await;
+    assert_eq!(results.len(), 25);
+    for (n, result) in results.iter().enumerate() {
+        assert_eq!(result.is_err(), n == 12, "event {}", n);
+    }
+    assert_eq!(*received.lock().unwrap(), correlation_ids);
+
+    mock_ok.assert_async().await;
+    mock_failed.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_send_counterparty_device() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let mock = server.mock("POST", "/resources/kyt/txns/some_txn_id/data/counterparty/device")
+        .match_body(mockito::Matcher::Json(json!({
+            "fingerprint": "counterparty_fingerprint",
+            "ipAddress": "203.0.113.7"
+        })))
+        .with_status(201)
+        .create_async().await;
+
+    let device = CounterpartyDevice {
+        fingerprint: "counterparty_fingerprint",
+        ip_address: Some("203.0.113.7"),
+        ..Default::default()
+    };
+    let result = client.send_counterparty_device(&TxnId::from("some_txn_id"), device).await;
+
+    mock.assert_async().await;
+    assert!(result.is_ok());
+}
+
+#[test]
+fn test_transaction_applicant_presets() {
+    let individual = serde_json::to_value(TransactionApplicant::individual("buyer-1", "John Doe")).unwrap();
+    assert_eq!(individual, json!({
+        "type": "individual",
+        "externalUserId": "buyer-1",
+        "fullName": "John Doe"
+    }));
+
+    let company = TransactionApplicant::company("seller-1", "Acme Ltd");
+    assert_eq!(company.applicant_type, TransactionApplicantType::Company);
+    assert_eq!(serde_json::to_value(&company).unwrap()["type"], "company");
+
+    let parsed: TransactionApplicant = serde_json::from_value(json!({
+        "type": "company",
+        "externalUserId": "seller-1",
+        "fullName": "Acme Ltd"
+    }))
+    .unwrap();
+    assert_eq!(parsed.applicant_type, TransactionApplicantType::Company);
+}
+
+#[tokio::test]
+async fn test_client_builder_rate_limit() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(server.url())
+        .rate_limit(20.0, 2)
+        .build()
+        .unwrap();
+
+    let mock = server.mock("GET", "/resources/applicants/limited_applicant/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "createDate": "2024-01-01 00:00:00", "reviewStatus": "init" }).to_string())
+        .expect(6)
+        .create_async().await;
+
+    let applicant_id = ApplicantId::from("limited_applicant");
+    let started = std::time::Instant::now();
+    let results = futures::future::join_all(
+        (0..6).map(|_| client.get_applicant_status(&applicant_id)),
+    )
+    .await;
+    assert!(results.iter().all(Result::is_ok));
+    // Two requests use the burst; the other four wait 50ms each.
+    assert!(started.elapsed() >= std::time::Duration::from_millis(190));
+
+    mock.assert_async().await;
+
+    let invalid = Client::builder("app_token", "secret_key").rate_limit(0.0, 1).build();
+    assert!(matches!(invalid, Err(SumsubError::Validation(_))));
+}
+
+#[tokio::test]
+async fn test_crypto_chain_validation() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    assert_eq!("ethereum".parse::<CryptoChain>().unwrap(), CryptoChain::Eth);
+    assert_eq!("trx".parse::<CryptoChain>().unwrap(), CryptoChain::Trx);
+    assert!(matches!("EHT".parse::<CryptoChain>(), Err(SumsubError::Validation(_))));
+
+    let params = CryptoParams { crypto_chain: Some(CryptoChain::Polygon) };
+    assert_eq!(serde_json::to_value(&params).unwrap(), json!({ "cryptoChain": "MATIC" }));
+    let parsed: CryptoParams = serde_json::from_value(json!({ "cryptoChain": "AVAX" })).unwrap();
+    assert_eq!(parsed.crypto_chain, Some(CryptoChain::Unknown("AVAX".to_string())));
+    let typo: CryptoParams = serde_json::from_value(json!({ "cryptoChain": "EHT" })).unwrap();
+    let typo = typo.crypto_chain.unwrap();
+    assert!(matches!(typo.validate_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"), Err(SumsubError::Validation(_))));
+
+    let mock = server.mock("GET", "/resources/kyt/misc/availableCurrencies")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "currencies": ["BTC", "usdt", "USD"] }).to_string())
+        .create_async().await;
+
+    let available = client.get_available_currencies().await.unwrap();
+    assert!(available.supports("USDT"));
+    assert!(available.validate_currency("btc").is_ok());
+    assert!(matches!(available.validate_currency("SOL"), Err(SumsubError::Validation(_))));
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_client_builder_http_client_and_timeouts() {
+    let mut server = mockito::Server::new_async().await;
+    let http_client = reqwest::Client::builder().user_agent("custom-agent").build().unwrap();
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(server.url())
+        .http_client(http_client)
+        .default_header(
+            reqwest::header::HeaderName::from_static("x-tenant"),
+            reqwest::header::HeaderValue::from_static("tenant-1"),
+        )
+        .default_header(
+            reqwest::header::HeaderName::from_static("x-app-token"),
+            reqwest::header::HeaderValue::from_static("overridden"),
+        )
+        .timeout(std::time::Duration::from_millis(100))
+        .build()
+        .unwrap();
+
+    let mock = server.mock("GET", "/resources/applicants/builder_applicant/status")
+        .match_header("user-agent", "custom-agent")
+        .match_header("x-tenant", "tenant-1")
+        .match_header("x-app-token", "app_token")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "createDate": "2024-01-01 00:00:00", "reviewStatus": "init" }).to_string())
+        .create_async().await;
+    let slow_mock = server.mock("GET", "/resources/applicants/slow_applicant/status")
+        .with_status(200)
+        .with_chunked_body(|w| {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            w.write_all(b"{}")
+        })
+        .create_async().await;
+
+    assert!(client.get_applicant_status(&ApplicantId::from("builder_applicant")).await.is_ok());
+    let result = client.get_applicant_status(&ApplicantId::from("slow_applicant")).await;
+    assert!(matches!(result, Err(SumsubError::Reqwest(e)) if e.is_timeout()));
+
+    mock.assert_async().await;
+    slow_mock.assert_async().await;
+
+    let invalid = Client::builder("app_token", "secret_key")
+        .http_client(reqwest::Client::new())
+        .connect_timeout(std::time::Duration::from_secs(1))
+        .build();
+    assert!(matches!(invalid, Err(SumsubError::Validation(_))));
+}
+
+#[tokio::test]
+async fn test_wallet_address_validation() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    for (chain, address) in [
+        (CryptoChain::Btc, "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2"),
+        (CryptoChain::Btc, "3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy"),
+        (CryptoChain::Btc, "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"),
+        (CryptoChain::Eth, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"),
+        (CryptoChain::Eth, "0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+        (CryptoChain::Trx, "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6t"),
+    ] {
+        assert!(chain.validate_address(address).is_ok(), "{} {}", chain, address);
+    }
+    for (chain, address) in [
+        (CryptoChain::Btc, "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN3"),
+        (CryptoChain::Btc, "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdp"),
+        (CryptoChain::Eth, "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAeD"),
+        (CryptoChain::Eth, "5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+        (CryptoChain::Trx, "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2"),
+        (CryptoChain::Btc, "bcé1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq"),
+    ] {
+        assert!(chain.validate_address(address).is_err(), "{} {}", chain, address);
+    }
+
+    let mock = server.mock("POST", "/resources/kyt/txns/-/importAddress")
+        .expect(0)
+        .create_async().await;
+
+    let requests = vec![
+        ImportWalletAddressesRequest {
+            address: "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed".to_string(),
+            currency: "USDT".to_string(),
+            network: CryptoChain::Eth,
+        },
+        ImportWalletAddressesRequest {
+            address: "TR7NHqjeKQxGTCi8q8ZY4pL8otSzgjLj6".to_string(),
+            currency: "USDT".to_string(),
+            network: CryptoChain::Trx,
+        },
+    ];
+    match client.import_wallet_addresses_checked(requests).await {
+        Err(SumsubError::Validation(message)) => assert!(message.starts_with("row 1: "), "{}", message),
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_applicant_kyt_summary() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let mock = server.mock("GET", "/resources/applicants/kyt_applicant/kyt/summary")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "riskScore": 72.5,
+            "riskLevel": "high",
+            "totalTxnCnt": 1480,
+            "onHoldTxnCnt": 3,
+            "triggeredRules": [
+                { "id": "rule_1", "name": "velocity", "count": 12 },
+                { "id": "rule_2", "name": "high-risk-country", "count": 1 }
+            ]
+        }).to_string())
+        .create_async().await;
+
+    let summary = client.get_applicant_kyt_summary(&ApplicantId::from("kyt_applicant")).await.unwrap();
+    assert_eq!(summary.risk_score, Some(72.5));
+    assert_eq!(summary.on_hold_txn_cnt, 3);
+    assert_eq!(summary.triggered_rules.len(), 2);
+    assert_eq!(summary.triggered_rules[0].count, 12);
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_sync_applicants_and_transactions_since() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let applicant = |n: u32| json!({
+        "id": format!("applicant_{}", n),
+        "createdAt": "2024-01-01 00:00:00",
+        "updatedAt": format!("2024-02-01 00:{:02}:{:02}", n / 60, n % 60),
+        "clientId": "client",
+        "inspectionId": "inspection",
+        "externalUserId": format!("user_{}", n),
+        "review": { "reviewStatus": "completed" },
+        "type": "individual"
+    });
+    let since = "updatedAtFrom=2024-02-01%2000%3A00%3A00";
+    let mock_first = server.mock("GET", &format!("/resources/applicants/-/list?{}&offset=0&limit=100", since)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "items": (0..100).map(applicant).collect::<Vec<_>>() }).to_string())
+        .create_async().await;
+    let mock_second = server.mock("GET", &format!("/resources/applicants/-/list?{}&offset=100&limit=100", since)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "items": (100..105).map(applicant).collect::<Vec<_>>() }).to_string())
+        .create_async().await;
+    let mock_txns = server.mock("GET", &format!("/resources/kyt/txns/-/list?{}&offset=0&limit=100", since)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "items": [] }).to_string())
+        .create_async().await;
+
+    let batch = client.sync_applicants_since("2024-02-01 00:00:00").await.unwrap();
+    assert_eq!(batch.items.len(), 105);
+    assert_eq!(batch.next_since, "2024-02-01 00:01:44");
+
+    let batch = client.sync_transactions_since("2024-02-01 00:00:00").await.unwrap();
+    assert!(batch.items.is_empty());
+    assert_eq!(batch.next_since, "2024-02-01 00:00:00");
+
+    mock_first.assert_async().await;
+    mock_second.assert_async().await;
+    mock_txns.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_sync_next_since_compares_parsed_timestamps_and_stays_before_the_sync() {
+    use sumsub_api::signing::FixedClock;
+
+    let mut server = mockito::Server::new_async().await;
+    let applicant = |id: &str, updated_at: &str| json!({
+        "id": id,
+        "createdAt": "2024-01-01 00:00:00",
+        "updatedAt": updated_at,
+        "clientId": "client",
+        "inspectionId": "inspection",
+        "externalUserId": id,
+        "review": { "reviewStatus": "completed" },
+        "type": "individual"
+    });
+    let _mock = server.mock("GET", mockito::Matcher::Regex("^/resources/applicants/-/list".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "items": [
+            applicant("applicant-1", "2024-02-01 00:00:50"),
+            applicant("applicant-2", "2024-02-01T00:00:10Z"),
+            applicant("applicant-3", "2024-02-01T02:00:30+02:00")
+        ] }).to_string())
+        .create_async().await;
+
+    // 2030-01-01 00:00:00, long after the records changed.
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(server.url())
+        .clock(FixedClock::from_unix_secs(1_893_456_000))
+        .build()
+        .unwrap();
+    let batch = client.sync_applicants_since("2024-02-01 00:00:00").await.unwrap();
+    assert_eq!(batch.items.len(), 3);
+    assert_eq!(batch.next_since, "2024-02-01 00:00:50");
+
+    // 2024-02-01 00:01:00: records changed during the sync may have been
+    // skipped, so the next sync starts a minute before this one.
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(server.url())
+        .clock(FixedClock::from_unix_secs(1_706_745_660))
+        .build()
+        .unwrap();
+    let batch = client.sync_applicants_since("2024-02-01 00:00:00").await.unwrap();
+    assert_eq!(batch.next_since, "2024-02-01 00:00:00");
+
+    let invalid = client.sync_applicants_since("yesterday").await;
+    assert!(matches!(invalid, Err(SumsubError::Validation(_))));
+}
+
+#[tokio::test]
+async fn test_request_hook_on_error() {
+    use std::sync::{Arc, Mutex};
+
+    let failures = Arc::new(Mutex::new(Vec::new()));
+    let recorder = failures.clone();
+    let client = Client::builder("app_token", "secret_key")
+        .base_url("http://127.0.0.1:1")
+        .hook(hooks::on_error(move |request, error| {
+            recorder.lock().unwrap().push((request.url().to_string(), error.is_connect()));
+        }))
+        .build()
+        .unwrap();
+
+    let result = client.get_applicant_status(&ApplicantId::from("unreachable_applicant")).await;
+
+    assert!(matches!(result, Err(SumsubError::Reqwest(_))));
+    let failures = failures.lock().unwrap();
+    assert_eq!(
+        *failures,
+        vec![("http://127.0.0.1:1/resources/applicants/unreachable_applicant/status".to_string(), true)]
+    );
+}
+
+#[tokio::test]
+async fn test_get_all_applicant_events_filtered() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let event = |n: u32| json!({
+        "createdAt": format!("2024-03-01 10:{:02}:{:02}", n / 60, n % 60),
+        "event": "applicantReviewed",
+        "data": { "n": n }
+    });
+    let filter_query = "createdAtFrom=2024-03-01%2000%3A00%3A00&eventTypes=applicantReviewed%2CapplicantPending";
+    let mock_first = server.mock("GET", &format!("/resources/applicants/events_applicant/events?offset=0&limit=100&{}", filter_query)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!((0..100).map(event).collect::<Vec<_>>()).to_string())
+        .create_async().await;
+    let mock_second = server.mock("GET", &format!("/resources/applicants/events_applicant/events?offset=100&limit=100&{}", filter_query)[..])
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!((100..120).map(event).collect::<Vec<_>>()).to_string())
+        .create_async().await;
+
+    let filter = EventFilter {
+        since: Some("2024-03-01 00:00:00".to_string()),
+        event_types: vec!["applicantReviewed".to_string(), "applicantPending".to_string()],
+        ..Default::default()
+    };
+    let events = client.get_all_applicant_events(&ApplicantId::from("events_applicant"), &filter).await.unwrap();
+    assert_eq!(events.len(), 120);
+    assert_eq!(events[119].data["n"], 119);
+
+    mock_first.assert_async().await;
+    mock_second.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_api_health_status_components() {
+    use sumsub_api::misc::Health;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let mock = server.mock("GET", "/resources/status/api")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "status": "ok",
+            "components": [
+                { "name": "applicants", "status": "operational" },
+                { "name": "kyt", "status": "degraded" }
+            ],
+            "incident": { "id": "inc-1", "title": "Slow transaction scoring", "startedAt": "2024-05-01 09:00:00" }
+        }).to_string())
+        .create_async().await;
+
+    let health = client.get_api_health_status().await.unwrap();
+    assert_eq!(health.status, "ok");
+    assert_eq!(health.components[0].health(), Health::Ok);
+    assert_eq!(health.health(), Health::Degraded);
+    assert_eq!(health.incident.unwrap().title.as_deref(), Some("Slow transaction scoring"));
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_metrics_sink() {
+    use std::sync::{Arc, Mutex};
+    use sumsub_api::metrics::{MetricsSink, RequestMetrics};
+
+    struct Recorder(Arc<Mutex<Vec<RequestMetrics>>>);
+
+    impl MetricsSink for Recorder {
+        fn record(&self, metrics: &RequestMetrics) {
+            self.0.lock().unwrap().push(metrics.clone());
+        }
+    }
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let recorded = Arc::new(Mutex::new(Vec::new()));
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url)
+        .with_metrics(Recorder(recorded.clone()));
+
+    let mock_status = server.mock("GET", "/resources/applicants/5cb56e8e0a975a35f333cb83/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "createDate": "2024-01-01 00:00:00", "reviewStatus": "init" }).to_string())
+        .create_async().await;
+    let mock_actions = server.mock("GET", "/resources/applicantActions/-;externalActionId=payment-42/one")
+        .with_status(404)
+        .create_async().await;
+
+    client.get_applicant_status(&ApplicantId::from("5cb56e8e0a975a35f333cb83")).await.unwrap();
+    assert!(client.get_action_by_external_id("payment-42").await.is_err());
+
+    let recorded: Vec<_> = recorded
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|m| (m.method.to_string(), m.endpoint.clone(), m.status.map(|status| status.as_u16())))
+        .collect();
+    assert_eq!(
+        recorded,
+        vec![
+            ("GET".to_string(), "/resources/applicants/{id}/status".to_string(), Some(200)),
+            ("GET".to_string(), "/resources/applicantActions/{id}/one".to_string(), Some(404)),
+        ]
+    );
+
+    mock_status.assert_async().await;
+    mock_actions.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_health_watcher() {
+    use sumsub_api::health::HealthWatcher;
+    use sumsub_api::misc::Health;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let mock_ok = server.mock("GET", "/resources/status/api")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"status": "ok"}"#)
+        .expect_at_least(1)
+        .create_async().await;
+
+    let watcher = HealthWatcher::spawn(client, std::time::Duration::from_millis(20));
+    let mut health = watcher.subscribe();
+    health.changed().await.unwrap();
+    assert_eq!(*health.borrow_and_update(), Some(Health::Ok));
+
+    mock_ok.remove_async().await;
+    let mock_down = server.mock("GET", "/resources/status/api")
+        .with_status(503)
+        .expect_at_least(1)
+        .create_async().await;
+
+    tokio::time::timeout(std::time::Duration::from_secs(5), health.changed())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(watcher.latest(), Some(Health::Down));
+
+    mock_down.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_structured_api_error() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let mock_json = server.mock("GET", "/resources/applicants/json_error/status")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "description": "Invalid id '123'",
+            "code": 400,
+            "correlationId": "req-abc",
+            "errorCode": 1002,
+            "errorName": "invalid-id"
+        }).to_string())
+        .create_async().await;
+    let mock_text = server.mock("GET", "/resources/applicants/text_error/status")
+        .with_status(502)
+        .with_body("Bad Gateway")
+        .create_async().await;
+
+    match client.get_applicant_status(&ApplicantId::from("json_error")).await {
+        Err(SumsubError::ApiError { status, message, code, correlation_id }) => {
+            assert_eq!(status, 400);
+            assert_eq!(message, "Invalid id '123'");
+            assert_eq!(code, Some(1002));
+            assert_eq!(correlation_id.as_deref(), Some("req-abc"));
+        }
+        other => panic!("expected an API error, got {:?}", other),
+    }
+    match client.get_applicant_status(&ApplicantId::from("text_error")).await {
+        Err(SumsubError::ApiError { status, message, code, correlation_id }) => {
+            assert_eq!(status, 502);
+            assert_eq!(message, "Bad Gateway");
+            assert_eq!(code, None);
+            assert_eq!(correlation_id, None);
+        }
+        other => panic!("expected an API error, got {:?}", other),
+    }
+
+    mock_json.assert_async().await;
+    mock_text.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_error_variants_by_status() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url);
+
+    let mut mocks = Vec::new();
+    for (applicant_id, status) in [("unauthorized", 401), ("forbidden", 403), ("conflict", 409), ("limited", 429)] {
+        let mut mock = server.mock("GET", &format!("/resources/applicants/{}/status", applicant_id)[..])
+            .with_status(status)
+            .with_body(json!({ "description": "failed", "correlationId": "req-1" }).to_string());
+        if status == 429 {
+            mock = mock.with_header("retry-after", "7");
+        }
+        mocks.push(mock.create_async().await);
+    }
+
+    let result = client.get_applicant_status(&ApplicantId::from("unauthorized")).await;
+    assert!(matches!(result, Err(SumsubError::Unauthorized { status: 401, .. })));
+    let result = client.get_applicant_status(&ApplicantId::from("forbidden")).await;
+    assert!(matches!(result, Err(SumsubError::Unauthorized { status: 403, .. })));
+    let result = client.get_applicant_status(&ApplicantId::from("conflict")).await;
+    assert!(matches!(result, Err(SumsubError::Conflict { ref message, .. }) if message == "failed"));
+    match client.get_applicant_status(&ApplicantId::from("limited")).await {
+        Err(e @ SumsubError::RateLimited { .. }) => {
+            assert!(e.is_retryable());
+            assert!(matches!(e, SumsubError::RateLimited { retry_after: Some(d), .. } if d.as_secs() == 7));
+        }
+        other => panic!("expected RateLimited, got {:?}", other),
+    }
+
+    for mock in mocks {
+        mock.assert_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_endpoint_policies_retry_reads_but_not_writes() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(url)
+        .policy(EndpointClass::Read, EndpointPolicy::new().max_retries(2).retry_delay(std::time::Duration::from_millis(1)))
+        .build()
+        .unwrap();
+
+    let read_mock = server.mock("GET", "/resources/applicants/applicant-1/status")
+        .with_status(503)
+        .with_body("unavailable")
+        .expect(3)
+        .create_async().await;
+    let write_mock = server.mock("POST", "/resources/applicants/applicant-1/resetStep/IDENTITY")
+        .with_status(503)
+        .with_body("unavailable")
+        .expect(1)
+        .create_async().await;
+
+    let result = client.get_applicant_status(&ApplicantId::from("applicant-1")).await;
+    assert!(matches!(result, Err(SumsubError::ApiError { status: 503, .. })));
+    let result = client.reset_applicant_step(&ApplicantId::from("applicant-1"), "IDENTITY").await;
+    assert!(matches!(result, Err(SumsubError::ApiError { status: 503, .. })));
+
+    read_mock.assert_async().await;
+    write_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_redirect_to_foreign_host_strips_auth_headers() {
+    let mut server = mockito::Server::new_async().await;
+    let mut storage = mockito::Server::new_async().await;
+    let storage_url = format!("{}/bucket/report.pdf?signature=abc", storage.url());
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let api_mock = server.mock("GET", "/resources/applicants/applicant-1/requiredIdDocsStatus.pdf")
+        .match_header("x-app-token", "app_token")
+        .with_status(302)
+        .with_header("location", &storage_url)
+        .create_async().await;
+    let storage_mock = storage.mock("GET", "/bucket/report.pdf?signature=abc")
+        .match_header("x-app-token", mockito::Matcher::Missing)
+        .match_header("x-app-access-sig", mockito::Matcher::Missing)
+        .with_status(200)
+        .with_body("%PDF-1.4")
+        .create_async().await;
+
+    let report = client.get_verification_pdf_report_with_diagnostics(&ApplicantId::from("applicant-1")).await.unwrap();
+
+    api_mock.assert_async().await;
+    storage_mock.assert_async().await;
+    assert_eq!(report.data, b"%PDF-1.4");
+    assert_eq!(report.url, storage_url);
+}
+
+#[tokio::test]
+async fn test_retry_after_overrides_policy_delay() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(server.url())
+        .policy(EndpointClass::Read, EndpointPolicy::new().max_retries(1).retry_delay(std::time::Duration::from_secs(60)))
+        .build()
+        .unwrap();
+
+    let mock = server.mock("GET", "/resources/applicants/applicant-1/status")
+        .with_status(429)
+        .with_header("retry-after", "Wed, 21 Oct 2015 07:28:00 GMT")
+        .with_body("too many requests")
+        .expect(2)
+        .create_async().await;
+
+    let result = tokio::time::timeout(
+        std::time::Duration::from_secs(10),
+        client.get_applicant_status(&ApplicantId::from("applicant-1")),
+    )
+    .await
+    .expect("the Retry-After delay should replace the policy delay");
+
+    mock.assert_async().await;
+    let error = result.unwrap_err();
+    assert!(matches!(error, SumsubError::RateLimited { .. }));
+    assert_eq!(error.retry_after(), Some(std::time::Duration::ZERO));
+}
+
+#[tokio::test]
+async fn test_retry_after_is_clamped_to_the_policy_maximum() {
+    let mut server = mockito::Server::new_async().await;
+    let policy = EndpointPolicy::new()
+        .max_retries(1)
+        .max_retry_after(std::time::Duration::from_millis(100));
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(server.url())
+        .policy(EndpointClass::Read, policy)
+        .build()
+        .unwrap();
+
+    let mock = server.mock("GET", "/resources/applicants/applicant-1/status")
+        .with_status(503)
+        .with_header("retry-after", "86400")
+        .expect(2)
+        .create_async().await;
+
+    let result = tokio::time::timeout(
+        std::time::Duration::from_secs(10),
+        client.get_applicant_status(&ApplicantId::from("applicant-1")),
+    )
+    .await
+    .expect("the Retry-After delay should be clamped");
+
+    mock.assert_async().await;
+    assert!(matches!(result, Err(SumsubError::ApiError { status: 503, .. })));
+}
+
+#[tokio::test]
+async fn test_get_document_image_link() {
+    let mut server = mockito::Server::new_async().await;
+    let mut storage = mockito::Server::new_async().await;
+    let storage_url = format!("{}/images/image-1.jpg?expires=60", storage.url());
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let redirect_mock = server.mock("GET", "/resources/applicants/applicant-1/images/inspection-1/image-1")
+        .with_status(302)
+        .with_header("location", &storage_url)
+        .create_async().await;
+    let direct_mock = server.mock("GET", "/resources/applicants/applicant-1/images/inspection-1/image-2")
+        .with_status(200)
+        .with_header("content-type", "image/jpeg")
+        .with_body("jpeg bytes")
+        .create_async().await;
+    let storage_mock = storage.mock("GET", mockito::Matcher::Any)
+        .expect(0)
+        .create_async().await;
+
+    let link = client.get_document_image_link(&ApplicantId::from("applicant-1"), "inspection-1", &ImageId::from("image-1")).await.unwrap();
+    assert_eq!(link, Some(storage_url));
+    let link = client.get_document_image_link(&ApplicantId::from("applicant-1"), "inspection-1", &ImageId::from("image-2")).await.unwrap();
+    assert_eq!(link, None);
+
+    redirect_mock.assert_async().await;
+    direct_mock.assert_async().await;
+    storage_mock.assert_async().await;
+}
+
+#[test]
+fn test_client_debug_redacts_credentials() {
+    let client = Client::new("app-token-value".to_string(), "secret-key-value".to_string());
+    let builder = Client::builder("app-token-value", "secret-key-value");
+
+    for output in [format!("{:?}", client), format!("{:?}", builder)] {
+        assert!(!output.contains("app-token-value"), "{}", output);
+        assert!(!output.contains("secret-key-value"), "{}", output);
+    }
+}
+
+#[tokio::test]
+async fn test_get_review_bundle() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+    let applicant_id = &ApplicantId::from("applicant-1");
+
+    let mock_status = server.mock("GET", "/resources/applicants/applicant-1/status")
+        .with_status(200)
+        .with_body(json!({ "createDate": "2024-01-01 00:00:00", "reviewStatus": "pending" }).to_string())
+        .create_async().await;
+    let mock_states = server.mock("GET", "/resources/moderationStates/-;applicantId=applicant-1")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_body("[]")
+        .create_async().await;
+    let mock_images = server.mock("GET", "/resources/applicants/applicant-1/info/images")
+        .with_status(200)
+        .with_body(json!([{
+            "imageId": "image-1",
+            "inspectionId": "inspection-1",
+            "idDocType": "PASSPORT",
+            "addedAt": "2024-01-01 00:00:00"
+        }]).to_string())
+        .create_async().await;
+    let mock_notes = server.mock("GET", "/resources/applicants/applicant-1/notes")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_body("[]")
+        .create_async().await;
+    let mock_poa = server.mock("GET", "/resources/checks/latest")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("type".into(), "POA".into()),
+            mockito::Matcher::UrlEncoded("applicantId".into(), applicant_id.to_string()),
+        ]))
+        .with_status(200)
+        .with_body(json!({ "answer": "GREEN" }).to_string())
+        .create_async().await;
+    let mock_ip = server.mock("GET", "/resources/checks/latest")
+        .match_query(mockito::Matcher::UrlEncoded("type".into(), "IP_CHECK".into()))
+        .with_status(404)
+        .with_body(json!({ "description": "Check not found" }).to_string())
+        .create_async().await;
+
+    let bundle = client
+        .get_review_bundle(applicant_id, &[CheckType::Poa, CheckType::IpCheck])
+        .await
+        .unwrap();
+
+    for mock in [mock_status, mock_states, mock_images, mock_notes, mock_poa, mock_ip] {
+        mock.assert_async().await;
+    }
+    assert_eq!(bundle.status.review_status, "pending");
+    assert_eq!(bundle.images.len(), 1);
+    assert_eq!(bundle.check_results.len(), 1);
+    assert_eq!(bundle.check_results[&CheckType::Poa]["answer"], "GREEN");
+    assert!(bundle.moderation_states.is_empty() && bundle.notes.is_empty());
+}
+
+#[tokio::test]
+async fn test_clock_resync_on_skewed_rejection() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(server.url())
+        .clock_resync(true)
+        .build()
+        .unwrap();
+    let server_time = std::time::SystemTime::now() + std::time::Duration::from_secs(3600);
+
+    let mock_rejected = server.mock("GET", "/resources/applicants/applicant-1/status")
+        .with_status(401)
+        .with_header("date", &httpdate::fmt_http_date(server_time))
+        .with_body(json!({ "description": "Request timestamp is too old" }).to_string())
+        .expect(1)
+        .create_async().await;
+    let mock_accepted = server.mock("GET", "/resources/applicants/applicant-1/status")
+        .with_status(200)
+        .with_body(json!({ "createDate": "2024-01-01 00:00:00", "reviewStatus": "init" }).to_string())
+        .expect(1)
+        .create_async().await;
+
+    let status = client.get_applicant_status(&ApplicantId::from("applicant-1")).await.unwrap();
+
+    mock_rejected.assert_async().await;
+    mock_accepted.assert_async().await;
+    assert_eq!(status.review_status, "init");
+    assert!((client.clock_offset() - 3600).abs() <= 2, "offset: {}", client.clock_offset());
+}
+
+#[test]
+fn test_score_keeps_parsed_value_and_raw_number() {
+    let info: sumsub_api::applicants::AmlMatchInfo =
+        serde_json::from_str(r#"{"matchTypes": ["NAME"], "matchStrength": 0.70}"#).unwrap();
+
+    assert_eq!(info.match_strength.value(), 0.7);
+    #[cfg(feature = "raw-numbers")]
+    assert_eq!(info.match_strength.raw(), "0.70");
+    #[cfg(not(feature = "raw-numbers"))]
+    assert_eq!(info.match_strength.raw(), "0.7");
+}
+
+#[tokio::test]
+async fn test_client_as_sumsub_api() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+    let api: std::sync::Arc<dyn SumsubApi> = std::sync::Arc::new(client);
+
+    let mock = server.mock("GET", "/resources/applicants/applicant-1/status")
+        .with_status(200)
+        .with_body(json!({
+            "createDate": "2024-01-01 00:00:00",
+            "reviewStatus": "completed",
+            "reviewResult": { "reviewAnswer": "GREEN" }
+        }).to_string())
+        .create_async().await;
+
+    let mock_tags = server.mock("POST", "/resources/kyt/txns/txn-1/tags")
+        .match_body(mockito::Matcher::Json(json!({ "tags": ["VIP"] })))
+        .with_status(200)
+        .create_async().await;
+
+    let status = api.get_applicant_status(&ApplicantId::from("applicant-1")).await.unwrap();
+    api.add_transaction_tags(&TxnId::from("txn-1"), vec!["VIP"]).await.unwrap();
+
+    mock.assert_async().await;
+    mock_tags.assert_async().await;
+    assert!(status.is_approved());
+}
+
+#[cfg(feature = "mockall")]
+#[tokio::test]
+async fn test_mock_sumsub_api() {
+    use sumsub_api::api::MockSumsubApi;
+
+    let mut api = MockSumsubApi::new();
+    api.expect_get_applicant_status()
+        .withf(|applicant_id| applicant_id == "applicant-1")
+        .returning(|_| Err(SumsubError::NotFound { message: "not found".to_string(), code: None, correlation_id: None }));
+
+    api.expect_rescore_transaction()
+        .withf(|txn_id| txn_id == "txn-1")
+        .returning(|_| Err(SumsubError::Validation("rescored too often".to_string())));
+
+    let result = api.get_applicant_status(&ApplicantId::from("applicant-1")).await;
+    assert!(matches!(result, Err(SumsubError::NotFound { .. })));
+    let result = api.rescore_transaction(&TxnId::from("txn-1")).await;
+    assert!(matches!(result, Err(SumsubError::Validation(_))));
+}
+
+#[cfg(feature = "raw-api")]
+#[tokio::test]
+async fn test_request_raw() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let mock = server.mock("POST", "/resources/newEndpoint?flag=true")
+        .match_header("x-app-token", "app_token")
+        .match_header("x-app-access-sig", mockito::Matcher::Regex("^[0-9a-f]{64}$".to_string()))
+        .match_header("content-type", "application/json")
+        .match_body(mockito::Matcher::Json(json!({ "key": "value" })))
+        .with_status(202)
+        .with_body("accepted")
+        .create_async().await;
+
+    let body: sumsub_api::json::Value = sumsub_api::json::json!({ "key": "value" });
+    let response = client
+        .request_raw(sumsub_api::http::Method::POST, "/resources/newEndpoint?flag=true", Some(body))
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.status(), sumsub_api::http::StatusCode::ACCEPTED);
+    assert_eq!(response.text().await.unwrap(), "accepted");
+
+    let invalid = client.request_raw(reqwest::Method::GET, "resources/x", None::<()>).await;
+    assert!(matches!(invalid, Err(SumsubError::Validation(_))));
+}
+
+#[test]
+fn test_client_from_env_and_credential_validation() {
+    std::env::set_var("SUMSUB_APP_TOKEN", "sbx:app-token");
+    std::env::set_var("SUMSUB_SECRET_KEY", "secret-key");
+    std::env::set_var("SUMSUB_BASE_URL", "http://127.0.0.1:1");
+    assert!(Client::from_env().is_ok());
+
+    std::env::set_var("SUMSUB_SECRET_KEY", "  ");
+    let result = Client::from_env();
+    assert!(matches!(result, Err(SumsubError::Configuration(ref message)) if message.contains("secret key")));
+
+    std::env::remove_var("SUMSUB_SECRET_KEY");
+    let result = Client::from_env();
+    assert!(matches!(result, Err(SumsubError::Configuration(ref message)) if message.contains("SUMSUB_SECRET_KEY")));
+
+    std::env::remove_var("SUMSUB_APP_TOKEN");
+    std::env::remove_var("SUMSUB_BASE_URL");
+
+    let result = Client::builder("app token", "secret_key").build();
+    assert!(matches!(result, Err(SumsubError::Configuration(_))));
+    let result = Client::builder("app_token", "secret_key").base_url("api.sumsub.com").build();
+    assert!(matches!(result, Err(SumsubError::Configuration(_))));
+}
+
+#[tokio::test]
+async fn test_request_options_timeout() {
+    use sumsub_api::options::RequestOptions;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let slow_mock = server.mock("GET", "/resources/applicants/slow_applicant/status")
+        .with_status(200)
+        .with_chunked_body(|w| {
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            w.write_all(b"{}")
+        })
+        .create_async().await;
+
+    let result = client
+        .with_options(RequestOptions::new().timeout(std::time::Duration::from_millis(100)))
+        .get_applicant_status(&ApplicantId::from("slow_applicant"))
+        .await;
+
+    slow_mock.assert_async().await;
+    assert!(matches!(result, Err(SumsubError::Reqwest(e)) if e.is_timeout()));
+}
+
+#[tokio::test]
+async fn test_environment_base_urls_and_sandbox_only_simulation() {
+    use sumsub_api::applicants::SimulateReviewRequest;
+    use sumsub_api::client::Environment;
+
+    assert_eq!(Environment::Production.base_url(), "https://api.sumsub.com");
+    assert_eq!(Environment::Custom("http://localhost:8080".to_string()).base_url(), "http://localhost:8080");
+
+    let review = || SimulateReviewRequest {
+        review_answer: "GREEN",
+        reject_labels: None,
+        review_reject_type: None,
+        client_comment: None,
+        moderation_comment: None,
+    };
+    let production = Client::new_with_environment("prd:app_token".to_string(), "secret_key".to_string(), Environment::Production);
+    let result = production.simulate_review_response(&ApplicantId::from("applicant-1"), review()).await;
+    assert!(matches!(result, Err(SumsubError::Configuration(_))));
+    let result = production
+        .create_sandbox_applicant(
+            CreateApplicantRequest { external_user_id: "user-1".into(), ..Default::default() },
+            "basic-kyc",
+            sumsub_api::sandbox::SandboxOutcome::Approved,
+        )
+        .await;
+    assert!(matches!(result, Err(SumsubError::Configuration(_))));
+
+    let mut server = mockito::Server::new_async().await;
+    let mock = server.mock("POST", "/resources/applicants/applicant-1/sandbox/status/testCompleted")
+        .with_status(200)
+        .create_async().await;
+    let custom = Client::builder("prd:app_token", "secret_key")
+        .environment(Environment::Custom(server.url()))
+        .build()
+        .unwrap();
+    custom.simulate_review_response(&ApplicantId::from("applicant-1"), review()).await.unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_client_pool_routes_by_key() {
+    use sumsub_api::pool::ClientPool;
+
+    let mut server = mockito::Server::new_async().await;
+    let mut pool = ClientPool::new();
+    pool.add("cards", Client::builder("cards_token", "cards_secret").base_url(server.url())).unwrap();
+    pool.add("crypto", Client::builder("crypto_token", "crypto_secret").base_url(server.url())).unwrap();
+
+    let mock = server.mock("GET", "/resources/applicants/applicant-1/status")
+        .match_header("x-app-token", "crypto_token")
+        .with_status(200)
+        .with_body(json!({ "createDate": "2024-01-01 00:00:00", "reviewStatus": "init" }).to_string())
+        .create_async().await;
+
+    pool.get("crypto").unwrap().get_applicant_status(&ApplicantId::from("applicant-1")).await.unwrap();
+
+    mock.assert_async().await;
+    let mut keys: Vec<_> = pool.keys().collect();
+    keys.sort();
+    assert_eq!(keys, ["cards", "crypto"]);
+    assert!(matches!(pool.get("unknown"), Err(SumsubError::Configuration(_))));
+}
+
+#[tokio::test]
+async fn test_get_applicant_action_questionnaires() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let mock = server.mock("GET", "/resources/applicantActions/action_id/one")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "id": "action_id",
+            "createdAt": "2024-01-01 00:00:00",
+            "clientId": "client",
+            "externalActionId": "payment/42",
+            "applicantId": "applicant_id",
+            "type": "paymentMethod",
+            "review": {
+                "reviewId": "review",
+                "attemptId": "attempt",
+                "attemptCnt": 0,
+                "levelName": "payment-level",
+                "createDate": "2024-01-01 00:00:00",
+                "reviewStatus": "completed"
+            },
+            "questionnaires": [{
+                "id": "source_of_funds",
+                "sections": {
+                    "funds": { "items": { "origin": { "value": "salary" } } }
+                }
+            }]
+        }).to_string())
+        .create_async().await;
+
+    let questionnaires = client.get_applicant_action_questionnaires(&ActionId::from("action_id")).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(questionnaires.len(), 1);
+    assert_eq!(questionnaires[0].id, "source_of_funds");
+    assert_eq!(questionnaires[0].sections["funds"].items["origin"].value.as_deref(), Some("salary"));
+}
+
+#[tokio::test]
+async fn test_token_manager_caches_until_expiry() {
+    use sumsub_api::tokens::TokenManager;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let mock_new = server.mock("POST", "/resources/accessTokens")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("levelName".into(), "basic-kyc".into()),
+            mockito::Matcher::UrlEncoded("externalUserId".into(), "user-1".into()),
+            mockito::Matcher::UrlEncoded("ttlInSecs".into(), "1800".into()),
+        ]))
+        .with_status(200)
+        .with_body(json!({ "token": "token-1", "userId": "user-1" }).to_string())
+        .expect(1)
+        .create_async().await;
+    let mock_existing = server.mock("POST", "/resources/applicants/applicant-1/accessTokens")
+        .match_query(mockito::Matcher::UrlEncoded("levelName".into(), "basic-kyc".into()))
+        .with_status(200)
+        .with_body(json!({ "token": "token-2", "userId": "user-2" }).to_string())
+        .expect(2)
+        .create_async().await;
+
+    let tokens = TokenManager::new(client.clone()).ttl(std::time::Duration::from_secs(1800));
+    assert_eq!(tokens.token_for_external_user(&ExternalUserId::from("user-1"), "basic-kyc").await.unwrap(), "token-1");
+    assert_eq!(tokens.token_for_external_user(&ExternalUserId::from("user-1"), "basic-kyc").await.unwrap(), "token-1");
+
+    // A refresh margin longer than the lifetime makes every token stale immediately.
+    let stale = TokenManager::new(client).refresh_margin(std::time::Duration::from_secs(3600));
+    assert_eq!(stale.token_for_applicant(&ApplicantId::from("applicant-1"), "basic-kyc").await.unwrap(), "token-2");
+    assert_eq!(stale.token_for_applicant(&ApplicantId::from("applicant-1"), "basic-kyc").await.unwrap(), "token-2");
+
+    mock_new.assert_async().await;
+    mock_existing.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_query_parameters_are_percent_encoded() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let mock = server.mock("POST", "/resources/accessTokens?levelName=basic%20kyc&externalUserId=user%2B1%26x")
+        .with_status(200)
+        .with_body(json!({ "token": "token", "userId": "user+1&x" }).to_string())
+        .create_async().await;
+
+    let response = client
+        .generate_token_for_new_applicant(Some("basic kyc"), Some(&ExternalUserId::from("user+1&x")), None)
+        .await
+        .unwrap();
+    assert_eq!(response.token, "token");
+    mock.assert_async().await;
+
+    let mock_applicant = server.mock("GET", "/resources/applicants/-;externalUserId=user%2B1%2Fx/one")
+        .with_status(200)
+        .with_body(json!({
+            "id": "applicant-1",
+            "createdAt": "2023-10-26T10:00:00Z",
+            "clientId": "client",
+            "inspectionId": "inspection-1",
+            "externalUserId": "user+1/x",
+            "review": { "reviewStatus": "init" },
+            "type": "individual"
+        }).to_string())
+        .create_async().await;
+    let applicant = client.get_applicant_data_by_external_user_id(&ExternalUserId::from("user+1/x")).await.unwrap();
+    assert_eq!(applicant.external_user_id, "user+1/x");
+    mock_applicant.assert_async().await;
+
+    let mock_consents = server.mock("GET", "/resources/sdkIntegrations/levels/basic%20kyc/consents")
+        .with_status(200)
+        .with_body(json!({ "consents": [] }).to_string())
+        .create_async().await;
+    let consents = client.get_applicant_facing_consents("basic kyc").await.unwrap();
+    assert!(consents.consents.is_empty());
+    mock_consents.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_operations_return_api_payloads() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let _recheck = server.mock("POST", "/resources/applicants/applicant-1/status/pending")
+        .with_status(200)
+        .with_body(json!({ "ok": 1 }).to_string())
+        .create_async().await;
+    let _aml = server.mock("POST", "/resources/applicants/applicant-1/aml")
+        .with_status(200)
+        .with_body(json!({ "ok": 1, "checkId": "check-1" }).to_string())
+        .create_async().await;
+    let _blocklist = server.mock("POST", "/resources/applicants/applicant-1/blacklist")
+        .with_status(200)
+        .with_body(json!({
+            "id": "applicant-1",
+            "createdAt": "2024-01-01 00:00:00",
+            "clientId": "client-1",
+            "inspectionId": "inspection-1",
+            "externalUserId": "user-1",
+            "review": { "reviewStatus": "onHold" },
+            "type": "individual"
+        }).to_string())
+        .create_async().await;
+
+    let _check = server.mock("POST", "/resources/checks")
+        .match_body(mockito::Matcher::Json(json!({ "applicantId": "applicant-1", "checkType": "POA" })))
+        .with_status(200)
+        .with_body(json!({ "ok": 1 }).to_string())
+        .create_async().await;
+
+    assert!(client.request_applicant_recheck(&ApplicantId::from("applicant-1")).await.unwrap().is_ok());
+    let check = client
+        .start_check(StartCheckRequest { applicant_id: &ApplicantId::from("applicant-1"), check_type: CheckType::Poa, address_info: None })
+        .await
+        .unwrap();
+    assert!(check.is_ok());
+    let aml = client.run_aml_check(&ApplicantId::from("applicant-1")).await.unwrap();
+    assert_eq!(aml.ok, Some(1));
+    assert_eq!(aml.extra["checkId"], "check-1");
+    let applicant = client.add_applicant_to_blocklist(&ApplicantId::from("applicant-1"), "fraud".to_string()).await.unwrap();
+    assert_eq!(applicant.id, "applicant-1");
+}
+
+#[tokio::test]
+async fn test_multipart_upload_is_signed_over_body() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let path = "/resources/applicantActions/action-1/images";
+    let mock = server.mock("POST", path)
+        .match_request(move |request| {
+            use hmac::{Hmac, Mac};
+            use sha2::Sha256;
+
+            let header = |name: &str| {
+                request.header(name).first().and_then(|value| value.to_str().ok()).unwrap_or_default().to_string()
+            };
+            let body = request.body().unwrap();
+            let mut mac = Hmac::<Sha256>::new_from_slice(b"secret_key").unwrap();
+            mac.update(header("X-App-Access-Ts").as_bytes());
+            mac.update(b"POST");
+            mac.update(path.as_bytes());
+            mac.update(body);
+            let body_text = String::from_utf8_lossy(body);
+            header("X-App-Access-Sig") == hex::encode(mac.finalize().into_bytes())
+                && body_text.contains("filename=\"selfie.jpg\"")
+                && body_text.contains("\u{1}\u{2}\u{3}")
+        })
+        .with_status(200)
+        .with_body("[]")
+        .create_async().await;
+
+    let images = client
+        .add_image_to_action(&ActionId::from("action-1"), None, vec![1, 2, 3], "selfie.jpg", "image/jpeg")
+        .await
+        .unwrap();
+    assert!(images.is_empty());
+    mock.assert_async().await;
+
+    let result = client.add_image_to_action(&ActionId::from("action-1"), None, vec![1], "selfie.jpg", "jpeg").await;
+    assert!(matches!(result, Err(SumsubError::MimeError(_))));
+}
+
+#[tokio::test]
+async fn test_multipart_boundary_is_derived_from_content() {
+    use std::sync::{Arc, Mutex};
+
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = requests.clone();
+    let mock = server.mock("POST", "/resources/applicantActions/action-1/images")
+        .match_request(move |request| {
+            let content_type = request.header("content-type")[0].to_str().unwrap().to_string();
+            recorded.lock().unwrap().push((content_type, request.body().unwrap().clone()));
+            true
+        })
+        .with_status(200)
+        .with_body("[]")
+        .expect(3)
+        .create_async().await;
+
+    let content = b"--sumsub-rs-form-boundary-5b1f0c7e9a3d4862\r\n--sumsub-rs-".to_vec();
+    for content in [content.clone(), content, b"other".to_vec()] {
+        client.add_image_to_action(&ActionId::from("action-1"), None, content, "selfie.jpg", "image/jpeg").await.unwrap();
+    }
+    mock.assert_async().await;
+
+    let requests = requests.lock().unwrap();
+    let boundaries: Vec<&str> = requests
+        .iter()
+        .map(|(content_type, _)| content_type.strip_prefix("multipart/form-data; boundary=").unwrap())
+        .collect();
+    assert_eq!(boundaries[0], boundaries[1]);
+    assert_ne!(boundaries[0], boundaries[2]);
+    for (boundary, (_, body)) in boundaries.iter().zip(requests.iter()) {
+        let body = String::from_utf8_lossy(body);
+        assert_eq!(body.matches(&format!("--{}", boundary)).count(), 2);
+        assert!(body.ends_with(&format!("--{}--\r\n", boundary)));
+    }
+}
+
+#[tokio::test]
+async fn test_validate_tags_rejects_unknown_tags() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(server.url())
+        .validate_tags(true)
+        .build()
+        .unwrap();
+
+    let dictionary = server.mock("GET", "/resources/applicants/-/tags")
+        .with_status(200)
+        .with_body(json!(["VIP", "Reviewed"]).to_string())
+        .expect(2)
+        .create_async().await;
+    let add = server.mock("POST", "/resources/applicants/applicant-1/tags")
+        .match_body(mockito::Matcher::Json(json!(["VIP"])))
+        .with_status(200)
+        .expect(1)
+        .create_async().await;
+
+    client.add_applicant_tags(&ApplicantId::from("applicant-1"), vec!["VIP"]).await.unwrap();
+    // The cached dictionary is refreshed once before the unknown tag is rejected.
+    let result = client.add_applicant_tags(&ApplicantId::from("applicant-1"), vec!["VIP", "Typo"]).await;
+    match result {
+        Err(SumsubError::Validation(message)) => assert!(message.contains("Typo")),
+        other => panic!("expected a validation error, got {:?}", other),
+    }
+
+    dictionary.assert_async().await;
+    add.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_token_issuance_uses_client_defaults() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(server.url())
+        .default_level_name("basic-kyc")
+        .default_token_ttl(std::time::Duration::from_secs(900))
+        .build()
+        .unwrap();
+
+    let token = server.mock("POST", "/resources/accessTokens?levelName=basic-kyc&externalUserId=user-1&ttlInSecs=900")
+        .with_status(200)
+        .with_body(json!({ "token": "token", "userId": "user-1" }).to_string())
+        .create_async().await;
+    let link = server.mock("POST", "/resources/accessTokens/-/websdkLink")
+        .match_body(mockito::Matcher::Json(json!({ "levelName": "enhanced-kyc", "ttlInSecs": 60 })))
+        .with_status(200)
+        .with_body(json!({ "url": "https://in.sumsub.com/websdk/p/abc" }).to_string())
+        .create_async().await;
+
+    client.generate_token_for_new_applicant(None, Some(&ExternalUserId::from("user-1")), None).await.unwrap();
+    client.generate_external_websdk_link(Some("enhanced-kyc"), None, Some(60)).await.unwrap();
+    token.assert_async().await;
+    link.assert_async().await;
+
+    let without_defaults = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+    let result = without_defaults.generate_token_for_new_applicant(None, None, None).await;
+    assert!(matches!(result, Err(SumsubError::Validation(_))));
+}
+
+#[tokio::test]
+async fn test_simulate_doc_set_rejection() {
+    use sumsub_api::applicants::IdDocSetType;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let default_labels = server.mock("POST", "/resources/applicants/applicant-1/sandbox/status/testCompleted")
+        .match_body(mockito::Matcher::Json(json!({
+            "reviewAnswer": "RED",
+            "rejectLabels": ["BAD_PROOF_OF_ADDRESS"],
+            "reviewRejectType": "RETRY",
+            "clientComment": "Simulated rejection of the PROOF_OF_RESIDENCE step"
+        })))
+        .with_status(200)
+        .create_async().await;
+    let custom_labels = server.mock("POST", "/resources/applicants/applicant-2/sandbox/status/testCompleted")
+        .match_body(mockito::Matcher::PartialJson(json!({ "rejectLabels": ["SCREENSHOTS"] })))
+        .with_status(200)
+        .create_async().await;
+
+    client.simulate_doc_set_rejection(&ApplicantId::from("applicant-1"), &IdDocSetType::ProofOfResidence, &[]).await.unwrap();
+    client.simulate_doc_set_rejection(&ApplicantId::from("applicant-2"), &IdDocSetType::Identity, &["SCREENSHOTS"]).await.unwrap();
+    default_labels.assert_async().await;
+    custom_labels.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_liveness_video_stream() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let video = vec![7u8; 64 * 1024];
+    let _ok = server.mock("GET", "/resources/applicants/applicant-1/info/facemap/video")
+        .with_status(200)
+        .with_body(&video)
+        .create_async().await;
+    let _missing = server.mock("GET", "/resources/applicants/applicant-2/info/facemap/video")
+        .with_status(404)
+        .with_body(json!({ "description": "Not found" }).to_string())
+        .create_async().await;
+
+    let chunks: Vec<_> = client.get_liveness_video_stream(&ApplicantId::from("applicant-1")).await.unwrap().try_collect().await.unwrap();
+    assert_eq!(chunks.concat(), video);
+
+    let result = client.get_liveness_video_stream(&ApplicantId::from("applicant-2")).await;
+    assert!(matches!(result, Err(SumsubError::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn test_batch_retries_and_keeps_order() {
+    use sumsub_api::batch::BatchOptions;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let _flaky = server.mock("GET", "/resources/applicants/applicant-1/requiredIdDocsStatus")
+        .with_status(503)
+        .expect(1)
+        .create_async().await;
+    let _recovered = server.mock("GET", "/resources/applicants/applicant-1/requiredIdDocsStatus")
+        .with_status(200)
+        .with_body(json!({}).to_string())
+        .create_async().await;
+    let _ok = server.mock("GET", "/resources/applicants/applicant-2/requiredIdDocsStatus")
+        .with_status(200)
+        .with_body(json!({}).to_string())
+        .create_async().await;
+    let _missing = server.mock("GET", "/resources/applicants/applicant-3/requiredIdDocsStatus")
+        .with_status(404)
+        .create_async().await;
+
+    assert_eq!(BatchOptions::default().max_retries, 0);
+    let options = BatchOptions {
+        concurrency: 2,
+        max_retries: 3,
+        retry_delay: std::time::Duration::from_millis(1),
+    };
+    let ids = ["applicant-1", "applicant-2", "applicant-3"].map(ApplicantId::from);
+    let outcomes = client
+        .batch(ids, options, |client, id| async move {
+            client.get_verification_steps_status(&id).await
+        })
+        .await;
+
+    let items: Vec<_> = outcomes.iter().map(|outcome| outcome.item.as_str()).collect();
+    assert_eq!(items, ["applicant-1", "applicant-2", "applicant-3"]);
+    assert_eq!(outcomes[0].attempts, 2);
+    assert!(outcomes[0].result.is_ok());
+    assert!(outcomes[1].result.is_ok());
+    assert!(matches!(outcomes[2].result, Err(SumsubError::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn test_resend_webhooks() {
+    use sumsub_api::webhooks::WebhookResendRequest;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let mock = server.mock("POST", "/resources/webhooks/-/resend")
+        .match_body(mockito::Matcher::Json(json!({
+            "createdAtFrom": "2024-05-01 00:00:00",
+            "createdAtTo": "2024-05-01 06:00:00",
+            "types": ["applicantReviewed"]
+        })))
+        .with_status(200)
+        .with_body(json!({ "ok": 1 }).to_string())
+        .expect(1)
+        .create_async().await;
+
+    let request = WebhookResendRequest {
+        since: Some("2024-05-01 00:00:00".to_string()),
+        until: Some("2024-05-01 06:00:00".to_string()),
+        types: vec!["applicantReviewed".to_string()],
+        ..Default::default()
+    };
+    assert!(client.resend_webhooks(&request).await.unwrap().is_ok());
+
+    let result = client.resend_webhooks(&WebhookResendRequest::default()).await;
+    assert!(matches!(result, Err(SumsubError::Validation(_))));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_export_data_subject_record() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let _applicant = server.mock("GET", "/resources/applicants/applicant-1/one")
+        .with_status(200)
+        .with_body(json!({ "id": "applicant-1", "review": { "levelName": "basic kyc" } }).to_string())
+        .create_async().await;
+    let _agreement = server.mock("GET", "/resources/applicants/applicant-1/agreement")
+        .with_status(404)
+        .create_async().await;
+    let _notes = server.mock("GET", "/resources/applicants/applicant-1/notes?offset=0&limit=100")
+        .with_status(200)
+        .with_body(json!([{
+            "id": "note-1",
+            "createdAt": "2024-01-01 00:00:00",
+            "applicantId": "applicant-1",
+            "agent": { "clientId": "client", "email": "agent@example.com" },
+            "note": "Called the applicant",
+            "attachments": []
+        }]).to_string())
+        .create_async().await;
+    let event = |n: usize, applicant_id: &str| json!({
+        "ts": "2024-01-01 00:00:00",
+        "clientId": "client",
+        "activity": "viewApplicant",
+        "subjectName": "agent@example.com",
+        "ip": "127.0.0.1",
+        "correlationId": format!("req-{}", n),
+        "applicantId": applicant_id,
+    });
+    // The first page is full, so a second page is requested.
+    let first_page: Vec<_> = (0..100)
+        .map(|n| event(n, if n == 0 { "applicant-1" } else { "applicant-10" }))
+        .collect();
+    let audit_first = server.mock("GET", "/resources/auditTrailEvents/?offset=0&limit=100")
+        .with_status(200)
+        .with_body(serde_json::Value::Array(first_page).to_string())
+        .expect(2)
+        .create_async().await;
+    let audit_second = server.mock("GET", "/resources/auditTrailEvents/?offset=100&limit=100")
+        .with_status(200)
+        .with_body(json!([event(100, "applicant-1")]).to_string())
+        .expect(1)
+        .create_async().await;
+    let _consents = server.mock("GET", "/resources/sdkIntegrations/levels/basic%20kyc/consents")
+        .with_status(200)
+        .with_body(json!({ "consents": [{
+            "id": "privacy",
+            "type": "privacyNotice",
+            "required": true,
+            "url": "https://example.com/privacy",
+            "orderIndex": 0,
+            "enabled": true,
+            "createdAt": "2024-01-01 00:00:00",
+            "updatedAt": "2024-01-01 00:00:00",
+            "levelName": "basic kyc",
+            "title": "Privacy notice",
+            "description": "How we process your data"
+        }] }).to_string())
+        .create_async().await;
+
+    let export = client.export_data_subject_record(&ApplicantId::from("applicant-1"), 10).await.unwrap();
+    // With a cap of one page, the export stops early and says so.
+    let capped = client.export_data_subject_record(&ApplicantId::from("applicant-1"), 1).await.unwrap();
+    audit_first.assert_async().await;
+    audit_second.assert_async().await;
+    assert!(export.audit_trail_complete);
+    assert!(!capped.audit_trail_complete);
+    assert_eq!(capped.audit_trail.len(), 1);
+    assert!(export.agreement.is_none());
+    assert_eq!(export.notes.len(), 1);
+    let correlation_ids: Vec<_> = export.audit_trail.iter().map(|e| e.correlation_id.as_str()).collect();
+    assert_eq!(correlation_ids, ["req-0", "req-100"]);
+    let json = serde_json::to_value(&export).unwrap();
+    assert_eq!(json["levelConsents"]["consents"][0]["id"], "privacy");
+    assert_eq!(json["applicant"]["id"], "applicant-1");
+}
+
+#[tokio::test]
+async fn test_get_applicant_data_with_diagnostics() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+
+    let _mock = server.mock("GET", "/resources/applicants/applicant-1/one")
+        .with_status(200)
+        .with_header("x-request-id", "req-123")
+        .with_header("x-correlation-id", "corr-456")
+        .with_header("x-ratelimit-limit", "100")
+        .with_header("x-ratelimit-remaining", "42")
+        .with_body(json!({
+            "id": "applicant-1",
+            "createdAt": "2024-01-01 00:00:00",
+            "clientId": "client-1",
+            "inspectionId": "inspection-1",
+            "externalUserId": "user-1",
+            "review": { "reviewStatus": "init" },
+            "type": "individual"
+        }).to_string())
+        .create_async().await;
+
+    let response = client.get_applicant_data_with_diagnostics(&ApplicantId::from("applicant-1")).await.unwrap();
+    assert_eq!(response.data.id, "applicant-1");
+    assert_eq!(response.request_id.as_deref(), Some("req-123"));
+    assert_eq!(response.correlation_id.as_deref(), Some("corr-456"));
+    assert_eq!(response.rate_limit.limit, Some(100));
+    assert_eq!(response.rate_limit.remaining, Some(42));
+    assert_eq!(response.rate_limit.reset_secs, None);
+}
+
+#[tokio::test]
+async fn test_signed_headers_match_client_signature() {
+    use sumsub_api::signing::{sign_request, SignedHeaders};
+
+    let signed = SignedHeaders::sign_at("app_token", "secret_key", 1_700_000_000, "POST", "/resources/applicants?levelName=basic", Some(b"{}"));
+    assert_eq!(signed.signature, sign_request("secret_key", 1_700_000_000, "POST", "/resources/applicants?levelName=basic", Some(b"{}")));
+    assert_eq!(signed.headers()[1], ("X-App-Access-Ts", "1700000000".to_string()));
+
+    // The client signs with the same scheme.
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+    let mock = server.mock("GET", "/resources/status/api")
+        .match_request(|request| {
+            let header = |name: &str| request.header(name)[0].to_str().unwrap().to_string();
+            let ts: u64 = header("X-App-Access-Ts").parse().unwrap();
+            let expected = SignedHeaders::sign_at("app_token", "secret_key", ts, "GET", "/resources/status/api", None);
+            header("X-App-Access-Sig") == expected.signature && header("X-App-Token") == expected.app_token
+        })
+        .with_status(200)
+        .with_body(json!({ "status": "ok" }).to_string())
+        .create_async().await;
+    client.get_api_health_status().await.unwrap();
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_html_error_page_maps_to_unavailable() {
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+    let page = format!("<html><head><title>502 Bad Gateway</title></head><body>{}</body></html>", "x".repeat(500));
+    let _error_mock = server.mock("GET", "/resources/applicants/applicant-1/one")
+        .with_status(502)
+        .with_header("content-type", "text/html; charset=utf-8")
+        .with_body(&page)
+        .expect_at_least(1)
+        .create_async().await;
+    let _maintenance_mock = server.mock("GET", "/resources/applicants/applicant-2/one")
+        .with_status(200)
+        .with_header("content-type", "text/html")
+        .with_body("<html><body>Down for maintenance</body></html>")
+        .create_async().await;
+
+    match client.get_applicant_data(&ApplicantId::from("applicant-1")).await {
+        Err(error @ SumsubError::Unavailable { .. }) => {
+            assert_eq!(error.status(), Some(502));
+            assert!(error.is_retryable());
+            let SumsubError::Unavailable { body_snippet, .. } = error else { unreachable!() };
+            assert!(body_snippet.starts_with("<html><head><title>502 Bad Gateway"));
+            assert_eq!(body_snippet.chars().count(), 200);
+        }
+        other => panic!("expected Unavailable, got {:?}", other),
+    }
+    match client.get_applicant_data(&ApplicantId::from("applicant-2")).await {
+        Err(SumsubError::Unavailable { status, body_snippet }) => {
+            assert_eq!(status, 200);
+            assert!(body_snippet.contains("Down for maintenance"));
+        }
+        other => panic!("expected Unavailable, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_lenient_decoding_captures_raw_body_and_path() {
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let body = json!({
+        "id": "applicant-1",
+        "createdAt": "2024-01-01 00:00:00",
+        "clientId": "client-1",
+        "inspectionId": "inspection-1",
+        "externalUserId": "user-1",
+        "review": { "reviewStatus": 42 },
+        "type": "individual"
+    }).to_string();
+    let _mock = server.mock("GET", "/resources/applicants/applicant-1/one")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(&body)
+        .expect(2)
+        .create_async().await;
+
+    let strict = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), url.clone());
+    assert!(matches!(strict.get_applicant_data(&ApplicantId::from("applicant-1")).await, Err(SumsubError::Serde(_))));
+
+    let lenient = Client::builder("app_token", "secret_key")
+        .base_url(url)
+        .lenient_decoding(true)
+        .build()
+        .unwrap();
+    match lenient.get_applicant_data(&ApplicantId::from("applicant-1")).await {
+        Err(SumsubError::Decode { raw_body, source }) => {
+            assert_eq!(raw_body, body);
+            assert_eq!(source.path().to_string(), "review.reviewStatus");
+        }
+        other => panic!("expected Decode, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_latency_budget_reports_slow_calls() {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+    use sumsub_api::metrics::{MetricsSink, RequestMetrics};
+
+    struct SlowCalls(Arc<Mutex<Vec<(String, Duration)>>>);
+
+    impl MetricsSink for SlowCalls {
+        fn record(&self, _metrics: &RequestMetrics) {}
+
+        fn record_slow_call(&self, metrics: &RequestMetrics, budget: Duration) {
+            assert!(metrics.latency > budget);
+            self.0.lock().unwrap().push((metrics.endpoint.clone(), budget));
+        }
+    }
+
+    let mut server = mockito::Server::new_async().await;
+    let slow_calls = Arc::new(Mutex::new(Vec::new()));
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(server.url())
+        .policy(EndpointClass::Read, EndpointPolicy::new().latency_budget(Duration::ZERO))
+        .metrics(SlowCalls(slow_calls.clone()))
+        .build()
+        .unwrap();
+
+    let _status_mock = server.mock("GET", "/resources/applicants/applicant-1/status")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "createDate": "2024-01-01 00:00:00", "reviewStatus": "init" }).to_string())
+        .create_async().await;
+    let _reset_mock = server.mock("POST", "/resources/applicants/applicant-1/reset")
+        .with_status(200)
+        .with_body(json!({ "ok": 1 }).to_string())
+        .create_async().await;
+
+    client.get_applicant_status(&ApplicantId::from("applicant-1")).await.unwrap();
+    client.reset_applicant(&ApplicantId::from("applicant-1")).await.unwrap();
+
+    // Only the read has a budget.
+    assert_eq!(
+        *slow_calls.lock().unwrap(),
+        vec![("/resources/applicants/{id}/status".to_string(), Duration::ZERO)]
+    );
+}
+
+#[tokio::test]
+async fn test_send_verification_email_with_template() {
+    use sumsub_api::misc::{LanguageCode, SendVerificationMessageRequest};
+
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+    let mock = server.mock("POST", "/resources/applicants/applicant-1/info/email/send")
+        .match_body(mockito::Matcher::Json(json!({
+            "lang": "pt-BR",
+            "templateName": "kyc-reminder",
+            "variables": { "firstName": "Ana", "deadline": "2024-02-01" }
+        })))
+        .with_status(200)
+        .create_async().await;
+
+    let request = SendVerificationMessageRequest::new("pt-BR".parse().unwrap())
+        .template("kyc-reminder")
+        .variable("firstName", "Ana")
+        .variable("deadline", "2024-02-01");
+    client.send_verification_email(&ApplicantId::from("applicant-1"), request).await.unwrap();
+    mock.assert_async().await;
+
+    assert_eq!(LanguageCode::new("en").unwrap().as_str(), "en");
+    for invalid in ["", "EN", "eng", "pt-br", "en_US"] {
+        assert!(matches!(LanguageCode::new(invalid), Err(SumsubError::Validation(_))), "{}", invalid);
+    }
+}
+
+#[tokio::test]
+async fn test_client_pool_resolves_credentials_from_provider() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use sumsub_api::pool::ClientPool;
+
+    let mut server = mockito::Server::new_async().await;
+    let url = server.url();
+    let lookups = Arc::new(AtomicUsize::new(0));
+    let counter = lookups.clone();
+    let pool = ClientPool::new().with_provider(move |tenant_id: &str| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        tenant_id
+            .strip_prefix("tenant-")
+            .map(|n| Client::builder(format!("token_{}", n), format!("secret_{}", n)).base_url(url.clone()))
+    });
+
+    let mock = server.mock("GET", "/resources/applicants/applicant-1/status")
+        .match_header("x-app-token", "token_7")
+        .with_status(200)
+        .with_body(json!({ "createDate": "2024-01-01 00:00:00", "reviewStatus": "init" }).to_string())
+        .expect(2)
+        .create_async().await;
+
+    pool.client("tenant-7").unwrap().get_applicant_status(&ApplicantId::from("applicant-1")).await.unwrap();
+    pool.clone().client("tenant-7").unwrap().get_applicant_status(&ApplicantId::from("applicant-1")).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(lookups.load(Ordering::SeqCst), 1);
+    assert!(matches!(pool.client("unknown"), Err(SumsubError::Configuration(_))));
+}
+
+#[tokio::test]
+async fn test_circuit_breaker_fails_fast_and_recovers() {
+    use std::time::Duration;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(server.url())
+        .circuit_breaker(2, Duration::from_millis(200))
+        .build()
+        .unwrap();
+
+    let failing = server.mock("GET", "/resources/applicants/applicant-1/status")
+        .with_status(503)
+        .with_body("unavailable")
+        .expect(2)
+        .create_async().await;
+
+    for _ in 0..2 {
+        assert!(matches!(client.get_applicant_status(&ApplicantId::from("applicant-1")).await, Err(SumsubError::ApiError { status: 503, .. })));
+    }
+    // The circuit is open: the request is not sent.
+    let error = client.get_applicant_status(&ApplicantId::from("applicant-1")).await.unwrap_err();
+    assert!(matches!(error, SumsubError::CircuitOpen { .. }));
+    assert!(error.retry_after().unwrap() <= Duration::from_millis(200));
+    assert!(!error.is_retryable());
+    failing.assert_async().await;
+    failing.remove_async().await;
+
+    let healthy = server.mock("GET", "/resources/applicants/applicant-1/status")
+        .with_status(200)
+        .with_body(json!({ "createDate": "2024-01-01 00:00:00", "reviewStatus": "init" }).to_string())
+        .expect(2)
+        .create_async().await;
+
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    // The trial request succeeds and closes the circuit.
+    client.get_applicant_status(&ApplicantId::from("applicant-1")).await.unwrap();
+    client.get_applicant_status(&ApplicantId::from("applicant-1")).await.unwrap();
+    healthy.assert_async().await;
+
+    assert!(matches!(
+        Client::builder("app_token", "secret_key").circuit_breaker(0, Duration::from_secs(1)).build(),
+        Err(SumsubError::Validation(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_list_travel_rule_transfers() {
+    use sumsub_api::travel_rule::TransferFilter;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+    let mock = server.mock("GET", "/resources/kyt/travelRule/transfers")
+        .match_query(mockito::Matcher::AllOf(vec![
+            mockito::Matcher::UrlEncoded("offset".into(), "0".into()),
+            mockito::Matcher::UrlEncoded("limit".into(), "100".into()),
+            mockito::Matcher::UrlEncoded("status".into(), "awaitingCounterparty".into()),
+            mockito::Matcher::UrlEncoded("counterpartyVaspId".into(), "vasp-1".into()),
+            mockito::Matcher::UrlEncoded("createdAtFrom".into(), "2024-01-01 00:00:00".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({
+            "items": [{
+                "id": "transfer-1",
+                "txnId": "txn-1",
+                "status": "awaitingCounterparty",
+                "direction": "out",
+                "counterpartyVaspId": "vasp-1",
+                "createdAt": "2024-01-02 10:00:00",
+                "amount": 0.5
+            }],
+            "totalItems": 1
+        }).to_string())
+        .create_async().await;
+
+    let filter = TransferFilter {
+        status: Some("awaitingCounterparty".to_string()),
+        counterparty_vasp_id: Some("vasp-1".to_string()),
+        since: Some("2024-01-01 00:00:00".to_string()),
+        ..Default::default()
+    };
+    let transfers = client.list_all_travel_rule_transfers(&filter).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(transfers.len(), 1);
+    assert_eq!(transfers[0].txn_id.as_deref(), Some("txn-1"));
+    assert_eq!(transfers[0].counterparty_vasp_id.as_deref(), Some("vasp-1"));
+    assert_eq!(transfers[0].extra["amount"], 0.5);
+}
+
+#[tokio::test]
+async fn test_debug_logging_redacts_secrets_and_pii() {
+    use std::sync::{Arc, Mutex};
+
+    let mut server = mockito::Server::new_async().await;
+    let lines = Arc::new(Mutex::new(Vec::new()));
+    let sink = lines.clone();
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url())
+        .with_debug_logging(move |line| sink.lock().unwrap().push(line.to_string()));
+
+    let _mock = server.mock("PATCH", "/resources/applicants/applicant-1/fixedInfo")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "id": "applicant-1" }).to_string())
+        .create_async().await;
+
+    let fixed_info = FixedInfo {
+        first_name: Some("Jane".to_string()),
+        dob: Some("1990-01-01".to_string()),
+        country: Some("DEU".to_string()),
+        ..Default::default()
+    };
+    client.update_applicant_fixed_info(&ApplicantId::from("applicant-1"), fixed_info).await.unwrap();
+
+    {
+        let lines = lines.lock().unwrap();
+        assert_eq!(lines.len(), 2);
+        let request = &lines[0];
+        assert!(request.starts_with("--> PATCH "));
+        assert!(request.contains("x-app-token: [REDACTED]"));
+        assert!(request.contains("x-app-access-sig: [REDACTED]"));
+        assert!(request.contains(r#""firstName":"[REDACTED]""#));
+        assert!(request.contains(r#""dob":"[REDACTED]""#));
+        assert!(request.contains(r#""country":"DEU""#));
+        assert!(!request.contains("app_token") && !request.contains("Jane") && !request.contains("1990-01-01"));
+        assert!(lines[1].starts_with("<-- 200 OK PATCH "));
+    }
+
+    let _by_external_id = server.mock("GET", "/resources/applicants/-;externalUserId=jane%40example.com/one")
+        .with_status(404)
+        .create_async().await;
+    let _ = client.get_applicant_data_by_external_user_id(&ExternalUserId::from("jane@example.com")).await;
+    let lines = lines.lock().unwrap();
+    assert!(lines.iter().any(|line| line.contains("/resources/applicants/-;externalUserId=[REDACTED]/one")));
+    assert!(!lines.iter().any(|line| line.contains("jane%40example.com")));
+}
+
+#[tokio::test]
+async fn test_get_document_image_verified() {
+    use sha2::{Digest, Sha256};
+    use sumsub_api::integrity::IntegrityCheck;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+    let content = b"jpeg bytes".to_vec();
+    let sha256 = hex::encode(Sha256::digest(&content));
+
+    let _info_mock = server.mock("GET", "/resources/applicants/applicant-1/info/images")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!([
+            {
+                "imageId": "image-1",
+                "inspectionId": "inspection-1",
+                "idDocType": "PASSPORT",
+                "addedAt": "2024-01-01 00:00:00",
+                "fileMetadata": { "fileName": "passport.jpg", "fileSize": content.len(), "sha256": sha256.to_uppercase() }
+            },
+            {
+                "imageId": "image-2",
+                "inspectionId": "inspection-1",
+                "idDocType": "SELFIE",
+                "addedAt": "2024-01-01 00:00:00",
+                "fileMetadata": { "fileSize": 4096 }
+            }
+        ]).to_string())
+        .create_async().await;
+    let _image_mock = server.mock("GET", mockito::Matcher::Regex(r"^/resources/applicants/applicant-1/images/inspection-1/image-\d$".to_string()))
+        .with_status(200)
+        .with_header("content-type", "image/jpeg")
+        .with_body(&content)
+        .expect(2)
+        .create_async().await;
+
+    let applicant_id = ApplicantId::from("applicant-1");
+    let images = client.get_document_images_info(&ApplicantId::from("applicant-1")).await.unwrap();
+
+    let (downloaded, report) = client.get_document_image_verified(&applicant_id, &images[0]).await.unwrap();
+    assert_eq!(downloaded, content);
+    assert_eq!(report.sha256, sha256);
+    assert_eq!(report.size_check, IntegrityCheck::Passed);
+    assert_eq!(report.hash_check, IntegrityCheck::Passed);
+    assert!(report.is_verified());
+
+    let (_, report) = client.get_document_image_verified(&applicant_id, &images[1]).await.unwrap();
+    assert_eq!(report.image_id, "image-2");
+    assert_eq!(report.size_check, IntegrityCheck::Failed { expected: "4096".to_string(), actual: content.len().to_string() });
+    assert_eq!(report.hash_check, IntegrityCheck::NotAvailable);
+    assert!(!report.is_intact());
+}
+
+#[tokio::test]
+async fn test_injected_clock_pins_signature() {
+    use std::time::{Duration, UNIX_EPOCH};
+    use sumsub_api::signing::{sign_request, FixedClock};
+
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(server.url())
+        .clock(FixedClock::from_unix_secs(1_700_000_000))
+        .build()
+        .unwrap();
+
+    let mock = server.mock("GET", "/resources/status/api")
+        .match_header("X-App-Access-Ts", "1700000000")
+        .match_header("X-App-Access-Sig", sign_request("secret_key", 1_700_000_000, "GET", "/resources/status/api", None).as_str())
+        .with_status(200)
+        .with_body(json!({ "status": "ok" }).to_string())
+        .create_async().await;
+    client.get_api_health_status().await.unwrap();
+    mock.assert_async().await;
+
+    // A clock before the Unix epoch is an error, not a panic.
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(server.url())
+        .clock(|| UNIX_EPOCH - Duration::from_secs(60))
+        .build()
+        .unwrap();
+    assert!(matches!(client.get_api_health_status().await, Err(SumsubError::Configuration(_))));
+}
+
+#[cfg(feature = "zip")]
+#[tokio::test]
+async fn test_read_verification_zip_report_entries() {
+    use std::io::Write;
+    use sumsub_api::archive::{ReportEntries, ReportEntry};
+
+    let mut zip = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default();
+    zip.add_directory("images/", options).unwrap();
+    zip.start_file("applicant.json", options).unwrap();
+    zip.write_all(json!({ "id": "applicant-1" }).to_string().as_bytes()).unwrap();
+    zip.start_file("images/passport.JPG", options).unwrap();
+    zip.write_all(&[0xFF, 0xD8, 0xFF]).unwrap();
+    zip.start_file("report.pdf", options).unwrap();
+    zip.write_all(b"%PDF-1.4").unwrap();
+    let report = zip.finish().unwrap().into_inner();
+
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+    let _mock = server.mock("GET", "/resources/applicants/applicant-1/requiredIdDocsStatus.zip")
+        .with_status(200)
+        .with_header("content-type", "application/zip")
+        .with_body(&report)
+        .create_async().await;
+
+    let stream = client.get_verification_zip_report_stream(&ApplicantId::from("applicant-1")).await.unwrap();
+    let entries: Vec<_> = ReportEntries::from_stream(stream).await.unwrap().collect::<Result<_, _>>().unwrap();
+
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0], ReportEntry::Json { name: "applicant.json".to_string(), value: json!({ "id": "applicant-1" }) });
+    assert_eq!(
+        entries[1],
+        ReportEntry::Image { name: "images/passport.JPG".to_string(), mime_type: "image/jpeg", content: vec![0xFF, 0xD8, 0xFF] }
+    );
+    assert_eq!(entries[2].name(), "report.pdf");
+    assert!(matches!(&entries[2], ReportEntry::Pdf { content, .. } if content == b"%PDF-1.4"));
+
+    assert!(matches!(ReportEntries::from_bytes(b"not a zip".to_vec()), Err(SumsubError::Archive(_))));
+}
+
+#[tokio::test]
+async fn test_idempotency_key_is_reused_across_retries() {
+    use std::sync::{Arc, Mutex};
+    use sumsub_api::options::RequestOptions;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(server.url())
+        .policy(EndpointClass::Write, EndpointPolicy::new().max_retries(1).retry_delay(std::time::Duration::from_millis(1)))
+        .idempotency_keys(true)
+        .build()
+        .unwrap();
+
+    let keys = Arc::new(Mutex::new(Vec::new()));
+    let recorded = keys.clone();
+    let applicant = json!({
+        "id": "applicant-1",
+        "createdAt": "2024-01-01 00:00:00",
+        "clientId": "client-1",
+        "inspectionId": "inspection-1",
+        "externalUserId": "user-1",
+        "review": { "reviewStatus": "init" },
+        "type": "individual"
+    });
+    let failing = server.mock("POST", "/resources/applicants?levelName=basic")
+        .match_request(move |request| {
+            let key = request.header("idempotency-key").first().map(|value| value.to_str().unwrap().to_string());
+            recorded.lock().unwrap().push(key);
+            true
+        })
+        .with_status(503)
+        .expect(2)
+        .create_async().await;
+
+    let request = || CreateApplicantRequest { external_user_id: "user-1".into(), ..Default::default() };
+    let result = client.create_applicant(request(), "basic").await;
+    assert!(matches!(result, Err(SumsubError::ApiError { status: 503, .. })));
+    failing.assert_async().await;
+    failing.remove_async().await;
+
+    // The retry after the 503 sent the same generated key.
+    let keys = keys.lock().unwrap().clone();
+    assert_eq!(keys.len(), 2);
+    let first_key = keys[0].clone().expect("an idempotency key is generated");
+    assert_eq!(first_key.len(), 36);
+    assert_eq!(keys[1].as_ref(), Some(&first_key));
+
+    // An explicit key is used as is, and only by the mutating call.
+    let pinned = client.with_options(RequestOptions::new().idempotency_key("txn-import-42"));
+    let create_mock = server.mock("POST", "/resources/applicants?levelName=basic")
+        .match_header("idempotency-key", "txn-import-42")
+        .with_status(200)
+        .with_body(applicant.to_string())
+        .create_async().await;
+    let read_mock = server.mock("GET", "/resources/applicants/applicant-1/one")
+        .match_header("idempotency-key", mockito::Matcher::Missing)
+        .with_status(200)
+        .with_body(applicant.to_string())
+        .create_async().await;
+    pinned.create_applicant(request(), "basic").await.unwrap();
+    pinned.get_applicant_data(&ApplicantId::from("applicant-1")).await.unwrap();
+    create_mock.assert_async().await;
+    read_mock.assert_async().await;
+    create_mock.remove_async().await;
+
+    // The key belongs to the scoped client and its clones only: options it
+    // was cloned from and the original client keep generating keys.
+    let options = RequestOptions::new();
+    let keyed = client.with_options(options.clone().idempotency_key("txn-import-43"));
+    let keyed_clone = keyed.clone();
+    let keyed_mock = server.mock("POST", "/resources/applicants?levelName=basic")
+        .match_header("idempotency-key", "txn-import-43")
+        .with_status(200)
+        .with_body(applicant.to_string())
+        .expect(2)
+        .create_async().await;
+    keyed_clone.create_applicant(request(), "basic").await.unwrap();
+    keyed.create_applicant(request(), "basic").await.unwrap();
+    keyed_mock.assert_async().await;
+    keyed_mock.remove_async().await;
+
+    let generated_mock = server.mock("POST", "/resources/applicants?levelName=basic")
+        .match_header("idempotency-key", mockito::Matcher::Regex("^[0-9a-f-]{36}$".to_string()))
+        .with_status(200)
+        .with_body(applicant.to_string())
+        .expect(2)
+        .create_async().await;
+    client.with_options(options).create_applicant(request(), "basic").await.unwrap();
+    client.create_applicant(request(), "basic").await.unwrap();
+    generated_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_multipart_upload_is_retried_with_the_same_signed_body() {
+    use std::sync::{Arc, Mutex};
+    use sumsub_api::applicants::AddDocumentMetadata;
+    use sumsub_api::signing::sign_request;
+
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::builder("app_token", "secret_key")
+        .base_url(server.url())
+        .policy(EndpointClass::Write, EndpointPolicy::new().max_retries(2).retry_delay(std::time::Duration::from_millis(1)))
+        .build()
+        .unwrap();
+
+    let path = "/resources/applicants/applicant-1/docsets/-";
+    let bodies = Arc::new(Mutex::new(Vec::new()));
+    let recorded = bodies.clone();
+    let mock = server.mock("POST", path)
+        .match_request(move |request| {
+            let header = |name: &str| request.header(name)[0].to_str().unwrap().to_string();
+            let body = request.body().unwrap().clone();
+            let ts: u64 = header("X-App-Access-Ts").parse().unwrap();
+            assert_eq!(header("X-App-Access-Sig"), sign_request("secret_key", ts, "POST", path, Some(&body)));
+            recorded.lock().unwrap().push(body);
+            true
+        })
+        .with_status(503)
+        .expect(3)
+        .create_async().await;
+
+    let metadata = AddDocumentMetadata {
+        id_doc_type: "PASSPORT",
+        country: "GBR",
+        first_name: None,
+        middle_name: None,
+        last_name: None,
+        dob: None,
+        place_of_birth: None,
+        issued_date: None,
+        valid_until: None,
+        number: None,
+        sub_type: None,
+        id_doc_sub_type: None,
+    };
+    let result = client
+        .add_verification_document(&ApplicantId::from("applicant-1"), metadata, vec![0xFF, 0xD8, 0xFF], "passport.jpg", "image/jpeg")
+        .await;
+    assert!(matches!(result, Err(SumsubError::ApiError { status: 503, .. })));
+    mock.assert_async().await;
+
+    let bodies = bodies.lock().unwrap();
+    assert_eq!(bodies.len(), 3);
+    assert!(bodies.iter().all(|body| body == &bodies[0]));
+    assert!(String::from_utf8_lossy(&bodies[0]).contains("filename=\"passport.jpg\""));
+}
+
+#[cfg(feature = "tracing")]
+#[tokio::test]
+async fn test_request_spans_record_the_operation_name() {
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id};
+    use tracing_subscriber::layer::{Context, SubscriberExt};
+
+    struct OperationRecorder(Arc<Mutex<Vec<String>>>);
+
+    impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for OperationRecorder {
+        fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+            struct Operation(Option<String>);
+            impl Visit for Operation {
+                fn record_str(&mut self, field: &Field, value: &str) {
+                    if field.name() == "operation" {
+                        self.0 = Some(value.to_string());
+                    }
+                }
+                fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+            }
+
+            if attrs.metadata().name() == "sumsub.request" {
+                let mut operation = Operation(None);
+                attrs.record(&mut operation);
+                self.0.lock().unwrap().push(operation.0.unwrap_or_default());
+            }
+        }
+    }
+
+    let operations = Arc::new(Mutex::new(Vec::new()));
+    let subscriber = tracing_subscriber::registry().with(OperationRecorder(operations.clone()));
+    let _guard = tracing::subscriber::set_default(subscriber);
+
+    let mut server = mockito::Server::new_async().await;
+    let client = Client::new_with_base_url("app_token".to_string(), "secret_key".to_string(), server.url());
+    let _mock = server.mock("GET", "/resources/applicants/applicant-1/one")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "id": "applicant-1" }).to_string())
+        .create_async().await;
+
+    let _ = client.get_applicant_data(&ApplicantId::from("applicant-1")).await;
+
+    assert_eq!(*operations.lock().unwrap(), vec!["get_applicant_data".to_string()]);
+}